@@ -0,0 +1,212 @@
+//! Bitfield extract and insert.
+//!
+//! ACLE does not define intrinsics for the A64 `UBFX`, `SBFX` and `BFI`
+//! instructions since compilers reliably select them for the equivalent
+//! shift-and-mask sequences. These functions spell out those sequences with
+//! the field position and width as immediates, so that the field is checked
+//! at compile time to fit in the operand.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Extracts the `WIDTH`-bit unsigned field of `a` starting at bit `LSB`.
+///
+/// `LSB + WIDTH` must not exceed 32 and `WIDTH` must be at least 1.
+#[inline]
+#[cfg_attr(test, assert_instr(ubfx, LSB = 4, WIDTH = 8))]
+#[rustc_legacy_const_generics(1, 2)]
+#[unstable(feature = "stdarch_aarch64_bitfield", issue = "none")]
+pub unsafe fn _ubfx_u32<const LSB: i32, const WIDTH: i32>(a: u32) -> u32 {
+    static_assert!(LSB >= 0 && WIDTH >= 1 && LSB + WIDTH <= 32);
+    (a >> LSB) & (u32::MAX >> (32 - WIDTH))
+}
+
+/// Extracts the `WIDTH`-bit unsigned field of `a` starting at bit `LSB`.
+///
+/// `LSB + WIDTH` must not exceed 64 and `WIDTH` must be at least 1.
+#[inline]
+#[cfg_attr(test, assert_instr(ubfx, LSB = 4, WIDTH = 8))]
+#[rustc_legacy_const_generics(1, 2)]
+#[unstable(feature = "stdarch_aarch64_bitfield", issue = "none")]
+pub unsafe fn _ubfx_u64<const LSB: i32, const WIDTH: i32>(a: u64) -> u64 {
+    static_assert!(LSB >= 0 && WIDTH >= 1 && LSB + WIDTH <= 64);
+    (a >> LSB) & (u64::MAX >> (64 - WIDTH))
+}
+
+/// Extracts the `WIDTH`-bit signed field of `a` starting at bit `LSB` and
+/// sign-extends it.
+///
+/// `LSB + WIDTH` must not exceed 32 and `WIDTH` must be at least 1.
+#[inline]
+#[cfg_attr(test, assert_instr(sbfx, LSB = 4, WIDTH = 8))]
+#[rustc_legacy_const_generics(1, 2)]
+#[unstable(feature = "stdarch_aarch64_bitfield", issue = "none")]
+pub unsafe fn _sbfx_i32<const LSB: i32, const WIDTH: i32>(a: i32) -> i32 {
+    static_assert!(LSB >= 0 && WIDTH >= 1 && LSB + WIDTH <= 32);
+    (a << (32 - LSB - WIDTH)) >> (32 - WIDTH)
+}
+
+/// Extracts the `WIDTH`-bit signed field of `a` starting at bit `LSB` and
+/// sign-extends it.
+///
+/// `LSB + WIDTH` must not exceed 64 and `WIDTH` must be at least 1.
+#[inline]
+#[cfg_attr(test, assert_instr(sbfx, LSB = 4, WIDTH = 8))]
+#[rustc_legacy_const_generics(1, 2)]
+#[unstable(feature = "stdarch_aarch64_bitfield", issue = "none")]
+pub unsafe fn _sbfx_i64<const LSB: i32, const WIDTH: i32>(a: i64) -> i64 {
+    static_assert!(LSB >= 0 && WIDTH >= 1 && LSB + WIDTH <= 64);
+    (a << (64 - LSB - WIDTH)) >> (64 - WIDTH)
+}
+
+/// Replaces the `WIDTH`-bit field of `a` starting at bit `LSB` with the low
+/// `WIDTH` bits of `b`.
+///
+/// `LSB + WIDTH` must not exceed 32 and `WIDTH` must be at least 1.
+#[inline]
+#[cfg_attr(test, assert_instr(bfi, LSB = 4, WIDTH = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+#[unstable(feature = "stdarch_aarch64_bitfield", issue = "none")]
+pub unsafe fn _bfi_u32<const LSB: i32, const WIDTH: i32>(a: u32, b: u32) -> u32 {
+    static_assert!(LSB >= 0 && WIDTH >= 1 && LSB + WIDTH <= 32);
+    let mask = (u32::MAX >> (32 - WIDTH)) << LSB;
+    (a & !mask) | ((b << LSB) & mask)
+}
+
+/// Replaces the `WIDTH`-bit field of `a` starting at bit `LSB` with the low
+/// `WIDTH` bits of `b`.
+///
+/// `LSB + WIDTH` must not exceed 64 and `WIDTH` must be at least 1.
+#[inline]
+#[cfg_attr(test, assert_instr(bfi, LSB = 4, WIDTH = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+#[unstable(feature = "stdarch_aarch64_bitfield", issue = "none")]
+pub unsafe fn _bfi_u64<const LSB: i32, const WIDTH: i32>(a: u64, b: u64) -> u64 {
+    static_assert!(LSB >= 0 && WIDTH >= 1 && LSB + WIDTH <= 64);
+    let mask = (u64::MAX >> (64 - WIDTH)) << LSB;
+    (a & !mask) | ((b << LSB) & mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+
+    fn ubfx_ref(a: u64, lsb: u32, width: u32) -> u64 {
+        let mut r = 0;
+        for i in 0..width {
+            r |= ((a >> (lsb + i)) & 1) << i;
+        }
+        r
+    }
+
+    fn sbfx_ref(a: u64, lsb: u32, width: u32) -> i64 {
+        let r = ubfx_ref(a, lsb, width);
+        if (r >> (width - 1)) & 1 == 1 && width < 64 {
+            (r | (u64::MAX << width)) as i64
+        } else {
+            r as i64
+        }
+    }
+
+    fn bfi_ref(a: u64, b: u64, lsb: u32, width: u32) -> u64 {
+        let mut r = a;
+        for i in 0..width {
+            r &= !(1 << (lsb + i));
+            r |= ((b >> i) & 1) << (lsb + i);
+        }
+        r
+    }
+
+    const INPUTS: [u64; 4] = [0, u64::MAX, 0x0123_4567_89ab_cdef, 0xf0e1_d2c3_b4a5_9687];
+
+    #[test]
+    fn test_ubfx_u32() {
+        for a in INPUTS {
+            let a = a as u32;
+            unsafe {
+                assert_eq!(_ubfx_u32::<0, 1>(a) as u64, ubfx_ref(a as u64, 0, 1));
+                assert_eq!(_ubfx_u32::<4, 8>(a) as u64, ubfx_ref(a as u64, 4, 8));
+                assert_eq!(_ubfx_u32::<20, 12>(a) as u64, ubfx_ref(a as u64, 20, 12));
+                assert_eq!(_ubfx_u32::<0, 32>(a), a);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ubfx_u64() {
+        for a in INPUTS {
+            unsafe {
+                assert_eq!(_ubfx_u64::<0, 1>(a), ubfx_ref(a, 0, 1));
+                assert_eq!(_ubfx_u64::<4, 8>(a), ubfx_ref(a, 4, 8));
+                assert_eq!(_ubfx_u64::<40, 24>(a), ubfx_ref(a, 40, 24));
+                assert_eq!(_ubfx_u64::<0, 64>(a), a);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sbfx_i32() {
+        for a in INPUTS {
+            let a = a as u32;
+            unsafe {
+                assert_eq!(_sbfx_i32::<0, 1>(a as i32), sbfx_ref(a as u64, 0, 1) as i32);
+                assert_eq!(_sbfx_i32::<4, 8>(a as i32), sbfx_ref(a as u64, 4, 8) as i32);
+                assert_eq!(
+                    _sbfx_i32::<20, 12>(a as i32),
+                    sbfx_ref(a as u64, 20, 12) as i32
+                );
+                assert_eq!(_sbfx_i32::<0, 32>(a as i32), a as i32);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sbfx_i64() {
+        for a in INPUTS {
+            unsafe {
+                assert_eq!(_sbfx_i64::<0, 1>(a as i64), sbfx_ref(a, 0, 1));
+                assert_eq!(_sbfx_i64::<4, 8>(a as i64), sbfx_ref(a, 4, 8));
+                assert_eq!(_sbfx_i64::<40, 24>(a as i64), sbfx_ref(a, 40, 24));
+                assert_eq!(_sbfx_i64::<0, 64>(a as i64), a as i64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bfi_u32() {
+        for a in INPUTS {
+            for b in INPUTS {
+                let (a, b) = (a as u32, b as u32);
+                unsafe {
+                    assert_eq!(
+                        _bfi_u32::<0, 1>(a, b) as u64,
+                        bfi_ref(a as u64, b as u64, 0, 1)
+                    );
+                    assert_eq!(
+                        _bfi_u32::<4, 8>(a, b) as u64,
+                        bfi_ref(a as u64, b as u64, 4, 8)
+                    );
+                    assert_eq!(
+                        _bfi_u32::<20, 12>(a, b) as u64,
+                        bfi_ref(a as u64, b as u64, 20, 12)
+                    );
+                    assert_eq!(_bfi_u32::<0, 32>(a, b), b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bfi_u64() {
+        for a in INPUTS {
+            for b in INPUTS {
+                unsafe {
+                    assert_eq!(_bfi_u64::<0, 1>(a, b), bfi_ref(a, b, 0, 1));
+                    assert_eq!(_bfi_u64::<4, 8>(a, b), bfi_ref(a, b, 4, 8));
+                    assert_eq!(_bfi_u64::<40, 24>(a, b), bfi_ref(a, b, 40, 24));
+                    assert_eq!(_bfi_u64::<0, 64>(a, b), b);
+                }
+            }
+        }
+    }
+}
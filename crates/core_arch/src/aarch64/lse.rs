@@ -0,0 +1,286 @@
+//! Large System Extension (LSE) atomic memory operations.
+//!
+//! ACLE does not provide intrinsics for the Armv8.1-A atomic instructions, as
+//! `core::sync::atomic` already compiles to them when the `lse` target
+//! feature is enabled. These functions expose the individual instructions
+//! directly, for code that needs a specific instruction and ordering.
+//!
+//! Every operation comes in four orderings, selected by the suffix of its
+//! name:
+//!
+//! * no suffix: no ordering constraints, like `Ordering::Relaxed`.
+//! * `a`: the load has acquire semantics, like `Ordering::Acquire`.
+//! * `l`: the store has release semantics, like `Ordering::Release`.
+//! * `al`: both acquire and release semantics, like `Ordering::AcqRel`.
+//!
+//! All functions take a raw pointer that must be valid for reads and writes
+//! and naturally aligned, and return the value that was in memory before the
+//! operation.
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// Defines the four orderings of a load-and-operate or swap instruction, each
+// for `u32` and `u64`.
+macro_rules! ld_op {
+    (
+        $doc:literal,
+        $relaxed:ident => $relaxed_u32:ident, $relaxed_u64:ident,
+        $acquire:ident => $acquire_u32:ident, $acquire_u64:ident,
+        $release:ident => $release_u32:ident, $release_u64:ident,
+        $acq_rel:ident => $acq_rel_u32:ident, $acq_rel_u64:ident $(,)?
+    ) => {
+        ld_op!(@fn $doc, "with no ordering constraints", $relaxed, $relaxed_u32, u32, ":w");
+        ld_op!(@fn $doc, "with no ordering constraints", $relaxed, $relaxed_u64, u64, "");
+        ld_op!(@fn $doc, "with acquire semantics", $acquire, $acquire_u32, u32, ":w");
+        ld_op!(@fn $doc, "with acquire semantics", $acquire, $acquire_u64, u64, "");
+        ld_op!(@fn $doc, "with release semantics", $release, $release_u32, u32, ":w");
+        ld_op!(@fn $doc, "with release semantics", $release, $release_u64, u64, "");
+        ld_op!(@fn $doc, "with acquire and release semantics", $acq_rel, $acq_rel_u32, u32, ":w");
+        ld_op!(@fn $doc, "with acquire and release semantics", $acq_rel, $acq_rel_u64, u64, "");
+    };
+    (@fn $doc:literal, $ordering:literal, $instr:ident, $name:ident, $ty:ty, $w:literal) => {
+        #[doc = concat!($doc, ", ", $ordering, ".")]
+        ///
+        /// Returns the previous value at `ptr`.
+        #[inline]
+        #[target_feature(enable = "lse")]
+        #[cfg_attr(test, assert_instr($instr))]
+        #[unstable(feature = "stdarch_aarch64_lse", issue = "none")]
+        pub unsafe fn $name(ptr: *mut $ty, val: $ty) -> $ty {
+            let old: $ty;
+            asm!(
+                concat!(stringify!($instr), " {val", $w, "}, {old", $w, "}, [{ptr}]"),
+                ptr = in(reg) ptr,
+                val = in(reg) val,
+                old = out(reg) old,
+                options(nostack, preserves_flags)
+            );
+            old
+        }
+    };
+}
+
+// Like `ld_op!`, for the compare-and-swap instructions.
+macro_rules! cas_op {
+    (
+        $doc:literal,
+        $relaxed:ident => $relaxed_u32:ident, $relaxed_u64:ident,
+        $acquire:ident => $acquire_u32:ident, $acquire_u64:ident,
+        $release:ident => $release_u32:ident, $release_u64:ident,
+        $acq_rel:ident => $acq_rel_u32:ident, $acq_rel_u64:ident $(,)?
+    ) => {
+        cas_op!(@fn $doc, "with no ordering constraints", $relaxed, $relaxed_u32, u32, ":w");
+        cas_op!(@fn $doc, "with no ordering constraints", $relaxed, $relaxed_u64, u64, "");
+        cas_op!(@fn $doc, "with acquire semantics", $acquire, $acquire_u32, u32, ":w");
+        cas_op!(@fn $doc, "with acquire semantics", $acquire, $acquire_u64, u64, "");
+        cas_op!(@fn $doc, "with release semantics", $release, $release_u32, u32, ":w");
+        cas_op!(@fn $doc, "with release semantics", $release, $release_u64, u64, "");
+        cas_op!(@fn $doc, "with acquire and release semantics", $acq_rel, $acq_rel_u32, u32, ":w");
+        cas_op!(@fn $doc, "with acquire and release semantics", $acq_rel, $acq_rel_u64, u64, "");
+    };
+    (@fn $doc:literal, $ordering:literal, $instr:ident, $name:ident, $ty:ty, $w:literal) => {
+        #[doc = concat!($doc, ", ", $ordering, ".")]
+        ///
+        /// Returns the previous value at `ptr`.
+        #[inline]
+        #[target_feature(enable = "lse")]
+        #[cfg_attr(test, assert_instr($instr))]
+        #[unstable(feature = "stdarch_aarch64_lse", issue = "none")]
+        pub unsafe fn $name(ptr: *mut $ty, expected: $ty, new: $ty) -> $ty {
+            let old: $ty;
+            asm!(
+                concat!(stringify!($instr), " {old", $w, "}, {new", $w, "}, [{ptr}]"),
+                ptr = in(reg) ptr,
+                new = in(reg) new,
+                old = inout(reg) expected => old,
+                options(nostack, preserves_flags)
+            );
+            old
+        }
+    };
+}
+
+ld_op! {
+    "Atomically adds `val` to the value at `ptr`",
+    ldadd => __ldadd_u32, __ldadd_u64,
+    ldadda => __ldadda_u32, __ldadda_u64,
+    ldaddl => __ldaddl_u32, __ldaddl_u64,
+    ldaddal => __ldaddal_u32, __ldaddal_u64,
+}
+
+ld_op! {
+    "Atomically clears the bits set in `val` from the value at `ptr`",
+    ldclr => __ldclr_u32, __ldclr_u64,
+    ldclra => __ldclra_u32, __ldclra_u64,
+    ldclrl => __ldclrl_u32, __ldclrl_u64,
+    ldclral => __ldclral_u32, __ldclral_u64,
+}
+
+ld_op! {
+    "Atomically XORs `val` into the value at `ptr`",
+    ldeor => __ldeor_u32, __ldeor_u64,
+    ldeora => __ldeora_u32, __ldeora_u64,
+    ldeorl => __ldeorl_u32, __ldeorl_u64,
+    ldeoral => __ldeoral_u32, __ldeoral_u64,
+}
+
+ld_op! {
+    "Atomically sets the bits set in `val` in the value at `ptr`",
+    ldset => __ldset_u32, __ldset_u64,
+    ldseta => __ldseta_u32, __ldseta_u64,
+    ldsetl => __ldsetl_u32, __ldsetl_u64,
+    ldsetal => __ldsetal_u32, __ldsetal_u64,
+}
+
+ld_op! {
+    "Atomically replaces the value at `ptr` with `val`",
+    swp => __swp_u32, __swp_u64,
+    swpa => __swpa_u32, __swpa_u64,
+    swpl => __swpl_u32, __swpl_u64,
+    swpal => __swpal_u32, __swpal_u64,
+}
+
+cas_op! {
+    "Atomically compares the value at `ptr` with `expected` and, if they are equal, replaces it with `new`",
+    cas => __cas_u32, __cas_u64,
+    casa => __casa_u32, __casa_u64,
+    casl => __casl_u32, __casl_u64,
+    casal => __casal_u32, __casal_u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+    use std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    };
+    use std::thread;
+    use stdarch_test::simd_test;
+
+    // Tests that `$name` returns the old value and stores `$expected`,
+    // computed from the old value `$a` and the operand `$b`.
+    macro_rules! test_ld_op {
+        (|$a:ident, $b:ident| $expected:expr; $($test:ident: $name:ident, $ty:ty;)*) => {$(
+            #[simd_test(enable = "lse")]
+            unsafe fn $test() {
+                // 0x0f0f...0f0f and 0x00ff...00ff.
+                let $a = <$ty>::MAX / 0xff * 0x0f;
+                let $b = <$ty>::MAX / 0xffff * 0xff;
+                let mut x = $a;
+                assert_eq!($name(&mut x, $b), $a);
+                assert_eq!(x, $expected);
+            }
+        )*};
+    }
+
+    test_ld_op! {
+        |a, b| a.wrapping_add(b);
+        test_ldadd_u32: __ldadd_u32, u32;
+        test_ldadd_u64: __ldadd_u64, u64;
+        test_ldadda_u32: __ldadda_u32, u32;
+        test_ldadda_u64: __ldadda_u64, u64;
+        test_ldaddl_u32: __ldaddl_u32, u32;
+        test_ldaddl_u64: __ldaddl_u64, u64;
+        test_ldaddal_u32: __ldaddal_u32, u32;
+        test_ldaddal_u64: __ldaddal_u64, u64;
+    }
+
+    test_ld_op! {
+        |a, b| a & !b;
+        test_ldclr_u32: __ldclr_u32, u32;
+        test_ldclr_u64: __ldclr_u64, u64;
+        test_ldclra_u32: __ldclra_u32, u32;
+        test_ldclra_u64: __ldclra_u64, u64;
+        test_ldclrl_u32: __ldclrl_u32, u32;
+        test_ldclrl_u64: __ldclrl_u64, u64;
+        test_ldclral_u32: __ldclral_u32, u32;
+        test_ldclral_u64: __ldclral_u64, u64;
+    }
+
+    test_ld_op! {
+        |a, b| a ^ b;
+        test_ldeor_u32: __ldeor_u32, u32;
+        test_ldeor_u64: __ldeor_u64, u64;
+        test_ldeora_u32: __ldeora_u32, u32;
+        test_ldeora_u64: __ldeora_u64, u64;
+        test_ldeorl_u32: __ldeorl_u32, u32;
+        test_ldeorl_u64: __ldeorl_u64, u64;
+        test_ldeoral_u32: __ldeoral_u32, u32;
+        test_ldeoral_u64: __ldeoral_u64, u64;
+    }
+
+    test_ld_op! {
+        |a, b| a | b;
+        test_ldset_u32: __ldset_u32, u32;
+        test_ldset_u64: __ldset_u64, u64;
+        test_ldseta_u32: __ldseta_u32, u32;
+        test_ldseta_u64: __ldseta_u64, u64;
+        test_ldsetl_u32: __ldsetl_u32, u32;
+        test_ldsetl_u64: __ldsetl_u64, u64;
+        test_ldsetal_u32: __ldsetal_u32, u32;
+        test_ldsetal_u64: __ldsetal_u64, u64;
+    }
+
+    test_ld_op! {
+        |_a, b| b;
+        test_swp_u32: __swp_u32, u32;
+        test_swp_u64: __swp_u64, u64;
+        test_swpa_u32: __swpa_u32, u32;
+        test_swpa_u64: __swpa_u64, u64;
+        test_swpl_u32: __swpl_u32, u32;
+        test_swpl_u64: __swpl_u64, u64;
+        test_swpal_u32: __swpal_u32, u32;
+        test_swpal_u64: __swpal_u64, u64;
+    }
+
+    // Tests that `$name` only stores `new` if the value at `ptr` equals
+    // `expected`, and returns the old value either way.
+    macro_rules! test_cas_op {
+        ($($test:ident: $name:ident, $ty:ty;)*) => {$(
+            #[simd_test(enable = "lse")]
+            unsafe fn $test() {
+                let mut x: $ty = 1;
+                assert_eq!($name(&mut x, 2, 3), 1);
+                assert_eq!(x, 1);
+                assert_eq!($name(&mut x, 1, 3), 1);
+                assert_eq!(x, 3);
+            }
+        )*};
+    }
+
+    test_cas_op! {
+        test_cas_u32: __cas_u32, u32;
+        test_cas_u64: __cas_u64, u64;
+        test_casa_u32: __casa_u32, u32;
+        test_casa_u64: __casa_u64, u64;
+        test_casl_u32: __casl_u32, u32;
+        test_casl_u64: __casl_u64, u64;
+        test_casal_u32: __casal_u32, u32;
+        test_casal_u64: __casal_u64, u64;
+    }
+
+    #[simd_test(enable = "lse")]
+    unsafe fn test_ldadd_u64_concurrent() {
+        const THREADS: u64 = 4;
+        const ITERS: u64 = 10_000;
+        let counter = Arc::new(AtomicU64::new(0));
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let counter = counter.clone();
+                thread::spawn(move || {
+                    for _ in 0..ITERS {
+                        unsafe { __ldaddal_u64(counter.as_ptr(), 1) };
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert_eq!(counter.load(Ordering::SeqCst), THREADS * ITERS);
+    }
+}
@@ -21,6 +21,26 @@ mod prefetch;
 #[unstable(feature = "stdarch_aarch64_prefetch", issue = "117217")]
 pub use self::prefetch::*;
 
+mod bitfield;
+#[unstable(feature = "stdarch_aarch64_bitfield", issue = "none")]
+pub use self::bitfield::*;
+
+mod sve;
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub use self::sve::*;
+
+mod lse;
+#[unstable(feature = "stdarch_aarch64_lse", issue = "none")]
+pub use self::lse::*;
+
+mod pauth;
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub use self::pauth::*;
+
+mod rand;
+#[unstable(feature = "stdarch_aarch64_rand", issue = "none")]
+pub use self::rand::*;
+
 #[stable(feature = "neon_intrinsics", since = "1.59.0")]
 pub use super::arm_shared::*;
 
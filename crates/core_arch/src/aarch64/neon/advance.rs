@@ -0,0 +1,113 @@
+//! Loads and stores that also return the advanced pointer.
+//!
+//! Loops over a buffer usually load or store one vector and then move the
+//! pointer past it. AArch64 can do both in one instruction with post-index
+//! addressing, e.g. `ld1 {v0.16b}, [x0], #16`. These functions express that
+//! pattern directly:
+//!
+//! ```ignore
+//! let (v, p) = vld1q_u8_advance(p);
+//! ```
+//!
+//! They are a convenience and don't guarantee post-index addressing: they are
+//! equivalent to a `vld1q`/`vst1q` followed by `ptr.add`, and the compiler is
+//! free to generate the same code for both.
+
+use crate::{core_arch::aarch64::*, mem};
+
+macro_rules! advance {
+    ($($load:ident, $store:ident = $vld1:ident, $vst1:ident: $t:ty, $v:ty;)*) => {$(
+        #[doc = concat!(
+            "Loads a vector with [`", stringify!($vld1), "`] and returns it ",
+            "together with `ptr` advanced past the loaded elements."
+        )]
+        ///
+        /// `ptr` needs to be valid for the same reads as for the load.
+        #[inline]
+        #[target_feature(enable = "neon")]
+        #[unstable(feature = "stdarch_neon_advance", issue = "none")]
+        pub unsafe fn $load(ptr: *const $t) -> ($v, *const $t) {
+            ($vld1(ptr), ptr.add(mem::size_of::<$v>() / mem::size_of::<$t>()))
+        }
+
+        #[doc = concat!(
+            "Stores a vector with [`", stringify!($vst1), "`] and returns ",
+            "`ptr` advanced past the stored elements."
+        )]
+        ///
+        /// `ptr` needs to be valid for the same writes as for the store.
+        #[inline]
+        #[target_feature(enable = "neon")]
+        #[unstable(feature = "stdarch_neon_advance", issue = "none")]
+        pub unsafe fn $store(ptr: *mut $t, a: $v) -> *mut $t {
+            $vst1(ptr, a);
+            ptr.add(mem::size_of::<$v>() / mem::size_of::<$t>())
+        }
+    )*};
+}
+
+advance! {
+    vld1q_s8_advance, vst1q_s8_advance = vld1q_s8, vst1q_s8: i8, int8x16_t;
+    vld1q_u8_advance, vst1q_u8_advance = vld1q_u8, vst1q_u8: u8, uint8x16_t;
+    vld1q_s16_advance, vst1q_s16_advance = vld1q_s16, vst1q_s16: i16, int16x8_t;
+    vld1q_u16_advance, vst1q_u16_advance = vld1q_u16, vst1q_u16: u16, uint16x8_t;
+    vld1q_s32_advance, vst1q_s32_advance = vld1q_s32, vst1q_s32: i32, int32x4_t;
+    vld1q_u32_advance, vst1q_u32_advance = vld1q_u32, vst1q_u32: u32, uint32x4_t;
+    vld1q_s64_advance, vst1q_s64_advance = vld1q_s64, vst1q_s64: i64, int64x2_t;
+    vld1q_u64_advance, vst1q_u64_advance = vld1q_u64, vst1q_u64: u64, uint64x2_t;
+    vld1q_f32_advance, vst1q_f32_advance = vld1q_f32, vst1q_f32: f32, float32x4_t;
+    vld1q_f64_advance, vst1q_f64_advance = vld1q_f64, vst1q_f64: f64, float64x2_t;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_advance_round_trip() {
+        macro_rules! check {
+            ($($load:ident, $store:ident: $t:ty, $n:literal;)*) => {$(
+                let src: [$t; 3 * $n] = core::array::from_fn(|i| i as $t + 1 as $t);
+                let mut dst = [0 as $t; 3 * $n];
+                let (mut p, mut q) = (src.as_ptr(), dst.as_mut_ptr());
+                for i in 1..=3 {
+                    let v;
+                    (v, p) = $load(p);
+                    q = $store(q, v);
+                    // Both pointers moved by one vector of elements.
+                    assert_eq!(p, src.as_ptr().add(i * $n), stringify!($load));
+                    assert_eq!(q, dst.as_mut_ptr().add(i * $n), stringify!($store));
+                }
+                assert_eq!(src, dst, stringify!($load));
+            )*};
+        }
+        check! {
+            vld1q_s8_advance, vst1q_s8_advance: i8, 16;
+            vld1q_u8_advance, vst1q_u8_advance: u8, 16;
+            vld1q_s16_advance, vst1q_s16_advance: i16, 8;
+            vld1q_u16_advance, vst1q_u16_advance: u16, 8;
+            vld1q_s32_advance, vst1q_s32_advance: i32, 4;
+            vld1q_u32_advance, vst1q_u32_advance: u32, 4;
+            vld1q_s64_advance, vst1q_s64_advance: i64, 2;
+            vld1q_u64_advance, vst1q_u64_advance: u64, 2;
+            vld1q_f32_advance, vst1q_f32_advance: f32, 4;
+            vld1q_f64_advance, vst1q_f64_advance: f64, 2;
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_advance_loop() {
+        // Sum 64 bytes one vector at a time, the pattern these are made for.
+        let bytes: [u8; 64] = core::array::from_fn(|i| i as u8);
+        let end = bytes.as_ptr().add(bytes.len());
+        let (mut p, mut acc) = (bytes.as_ptr(), vdupq_n_u16(0));
+        while p < end {
+            let v;
+            (v, p) = vld1q_u8_advance(p);
+            acc = vpadalq_u8(acc, v);
+        }
+        assert_eq!(p, end);
+        assert_eq!(vaddvq_u16(acc), (0..64).sum::<u16>());
+    }
+}
@@ -8,6 +8,10 @@ mod generated;
 #[stable(feature = "neon_intrinsics", since = "1.59.0")]
 pub use self::generated::*;
 
+mod advance;
+#[unstable(feature = "stdarch_neon_advance", issue = "none")]
+pub use self::advance::*;
+
 // FIXME: replace neon with asimd
 
 use crate::{
@@ -3527,7 +3531,7 @@ mod tests {
     use crate::core_arch::arm_shared::test_support::*;
     use crate::core_arch::{aarch64::neon::*, aarch64::*, simd::*};
     use std::mem::transmute;
-    use stdarch_test::simd_test;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     #[simd_test(enable = "neon")]
     unsafe fn test_vuqadd_s8() {
@@ -3693,6 +3697,348 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcombine_vget_low_high_f64_round_trip() {
+        let v = f64x2::new(1.5, -2.25);
+        let r: f64x2 = transmute(vcombine_f64(
+            vget_low_f64(transmute(v)),
+            vget_high_f64(transmute(v)),
+        ));
+        assert_eq!(r, v);
+        let r: f64x2 = transmute(vcombine_f64(
+            vcreate_f64(1.5f64.to_bits()),
+            vcreate_f64((-2.25f64).to_bits()),
+        ));
+        assert_eq!(r, v);
+    }
+
+    #[simd_test(enable = "neon,aes")]
+    unsafe fn test_vcombine_vget_low_high_p64_round_trip() {
+        let v = u64x2::new(0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210);
+        let r: u64x2 = transmute(vcombine_p64(
+            vget_low_p64(transmute(v)),
+            vget_high_p64(transmute(v)),
+        ));
+        assert_eq!(r, v);
+        let r: u64x2 = transmute(vcombine_p64(
+            vcreate_p64(0x0123_4567_89ab_cdef),
+            vcreate_p64(0xfedc_ba98_7654_3210),
+        ));
+        assert_eq!(r, v);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vrecpeq_vrsqrteq_f64_newton_raphson() {
+        // The estimates are accurate to about 8 bits, and each step doubles
+        // that, so three steps are needed for double precision.
+        let xs = [0.3, 7.5];
+        let x = vld1q_f64(xs.as_ptr());
+        let mut recp = vrecpeq_f64(x);
+        let mut rsqrt = vrsqrteq_f64(x);
+        for _ in 0..3 {
+            recp = vmulq_f64(recp, vrecpsq_f64(x, recp));
+            rsqrt = vmulq_f64(rsqrt, vrsqrtsq_f64(vmulq_f64(x, rsqrt), rsqrt));
+        }
+        let recp: [f64; 2] = transmute(recp);
+        let rsqrt: [f64; 2] = transmute(rsqrt);
+        for ((x, recp), rsqrt) in xs.iter().zip(recp).zip(rsqrt) {
+            let e = 1.0 / x;
+            assert!(((recp - e) / e).abs() < 1.0e-14, "1 / {x}: {recp} != {e}");
+            let e = 1.0 / x.sqrt();
+            assert!(
+                ((rsqrt - e) / e).abs() < 1.0e-14,
+                "1 / sqrt({x}): {rsqrt} != {e}"
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vmlaq_vmlsq_f32_not_fused() {
+        // b * c = 1 + 2^-11 + 2^-24 is a tie in single precision and rounds
+        // to 1 + 2^-11, which cancels with a exactly. A fused multiply-add
+        // keeps the 2^-24 term.
+        let b = vdupq_n_f32(1.0 + 2.0f32.powi(-12));
+        let p = 1.0 + 2.0f32.powi(-11);
+        let tiny = 2.0f32.powi(-24);
+
+        let a = vdupq_n_f32(-p);
+        let r: [f32; 4] = transmute(vmlaq_f32(a, b, b));
+        assert_eq!(r, [0.0; 4]);
+        let r: [f32; 4] = transmute(vmlaq_laneq_f32::<3>(a, b, b));
+        assert_eq!(r, [0.0; 4]);
+        let r: [f32; 4] = transmute(vfmaq_f32(a, b, b));
+        assert_eq!(r, [tiny; 4]);
+
+        let a = vdupq_n_f32(p);
+        let r: [f32; 4] = transmute(vmlsq_f32(a, b, b));
+        assert_eq!(r, [0.0; 4]);
+        let r: [f32; 4] = transmute(vmlsq_n_f32(a, b, 1.0 + 2.0f32.powi(-12)));
+        assert_eq!(r, [0.0; 4]);
+        let r: [f32; 4] = transmute(vfmsq_f32(a, b, b));
+        assert_eq!(r, [-tiny; 4]);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vmla_vmls_f64_not_fused() {
+        // b * c = 1 + 2^-26 + 2^-54 rounds to 1 + 2^-26 in double precision.
+        let b = vdup_n_f64(1.0 + 2.0f64.powi(-27));
+        let p = 1.0 + 2.0f64.powi(-26);
+        let tiny = 2.0f64.powi(-54);
+
+        let a = vdup_n_f64(-p);
+        assert_eq!(transmute::<_, f64>(vmla_f64(a, b, b)), 0.0);
+        assert_eq!(transmute::<_, f64>(vfma_f64(a, b, b)), tiny);
+
+        let a = vdup_n_f64(p);
+        assert_eq!(transmute::<_, f64>(vmls_f64(a, b, b)), 0.0);
+        assert_eq!(transmute::<_, f64>(vfms_f64(a, b, b)), -tiny);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vmull_high_s16() {
+        fuzz_intrinsic!(
+            vmull_high_s16(a: [i16; 8], b: [i16; 8]) -> [i32; 4],
+            |a, b| core::array::from_fn(|i| a[i + 4] as i32 * b[i + 4] as i32),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vmull_high_u32() {
+        fuzz_intrinsic!(
+            vmull_high_u32(a: [u32; 4], b: [u32; 4]) -> [u64; 2],
+            |a, b| core::array::from_fn(|i| a[i + 2] as u64 * b[i + 2] as u64),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vmlal_high_s8() {
+        fuzz_intrinsic!(
+            vmlal_high_s8(a: [i16; 8], b: [i8; 16], c: [i8; 16]) -> [i16; 8],
+            |a, b, c| {
+                core::array::from_fn(|i| a[i].wrapping_add(b[i + 8] as i16 * c[i + 8] as i16))
+            },
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vmlsl_high_u16() {
+        fuzz_intrinsic!(
+            vmlsl_high_u16(a: [u32; 4], b: [u16; 8], c: [u16; 8]) -> [u32; 4],
+            |a, b, c| {
+                core::array::from_fn(|i| a[i].wrapping_sub(b[i + 4] as u32 * c[i + 4] as u32))
+            },
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vmlsl_high_lane_s16_every_lane() {
+        let a = [1, -2, 3, i32::MIN];
+        let b = [0, 0, 0, 0, i16::MIN, -1, 2, i16::MAX];
+        let c = [-3, i16::MIN, 5, i16::MAX];
+        macro_rules! check {
+            ($($lane:literal),*) => {$(
+                let r: [i32; 4] = transmute(vmlsl_high_lane_s16::<$lane>(
+                    transmute(a),
+                    transmute(b),
+                    transmute(c),
+                ));
+                let e: [i32; 4] =
+                    core::array::from_fn(|i| a[i].wrapping_sub(b[i + 4] as i32 * c[$lane] as i32));
+                assert_eq!(r, e, "vmlsl_high_lane_s16::<{}>", $lane);
+            )*};
+        }
+        check!(0, 1, 2, 3);
+    }
+
+    // The `_high` widening forms must match widening the `vget_high` halves
+    // with the 64-bit form.
+    macro_rules! fuzz_high_long {
+        ($($high:ident, $low:ident, $get:ident: [$t:ty; $n:literal] -> [$w:ty; $m:literal];)*) => {$(
+            fuzz_intrinsic!(
+                $high(a: [$t; $n], b: [$t; $n]) -> [$w; $m],
+                |a, b| transmute($low($get(transmute(a)), $get(transmute(b)))),
+            );
+        )*};
+    }
+
+    macro_rules! fuzz_high_wide {
+        ($($high:ident, $low:ident, $get:ident: [$w:ty; $m:literal], [$t:ty; $n:literal];)*) => {$(
+            fuzz_intrinsic!(
+                $high(a: [$w; $m], b: [$t; $n]) -> [$w; $m],
+                |a, b| transmute($low(transmute(a), $get(transmute(b)))),
+            );
+        )*};
+    }
+
+    macro_rules! fuzz_high_accumulate {
+        ($($high:ident, $low:ident, $get:ident: [$w:ty; $m:literal], [$t:ty; $n:literal];)*) => {$(
+            fuzz_intrinsic!(
+                $high(a: [$w; $m], b: [$t; $n], c: [$t; $n]) -> [$w; $m],
+                |a, b, c| transmute($low(transmute(a), $get(transmute(b)), $get(transmute(c)))),
+            );
+        )*};
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vaddl_vsubl_vmull_high_matches_vget_high() {
+        fuzz_high_long! {
+            vaddl_high_s8, vaddl_s8, vget_high_s8: [i8; 16] -> [i16; 8];
+            vaddl_high_s16, vaddl_s16, vget_high_s16: [i16; 8] -> [i32; 4];
+            vaddl_high_s32, vaddl_s32, vget_high_s32: [i32; 4] -> [i64; 2];
+            vaddl_high_u8, vaddl_u8, vget_high_u8: [u8; 16] -> [u16; 8];
+            vaddl_high_u16, vaddl_u16, vget_high_u16: [u16; 8] -> [u32; 4];
+            vaddl_high_u32, vaddl_u32, vget_high_u32: [u32; 4] -> [u64; 2];
+            vsubl_high_s8, vsubl_s8, vget_high_s8: [i8; 16] -> [i16; 8];
+            vsubl_high_s16, vsubl_s16, vget_high_s16: [i16; 8] -> [i32; 4];
+            vsubl_high_s32, vsubl_s32, vget_high_s32: [i32; 4] -> [i64; 2];
+            vsubl_high_u8, vsubl_u8, vget_high_u8: [u8; 16] -> [u16; 8];
+            vsubl_high_u16, vsubl_u16, vget_high_u16: [u16; 8] -> [u32; 4];
+            vsubl_high_u32, vsubl_u32, vget_high_u32: [u32; 4] -> [u64; 2];
+            vmull_high_s8, vmull_s8, vget_high_s8: [i8; 16] -> [i16; 8];
+            vmull_high_s16, vmull_s16, vget_high_s16: [i16; 8] -> [i32; 4];
+            vmull_high_s32, vmull_s32, vget_high_s32: [i32; 4] -> [i64; 2];
+            vmull_high_u8, vmull_u8, vget_high_u8: [u8; 16] -> [u16; 8];
+            vmull_high_u16, vmull_u16, vget_high_u16: [u16; 8] -> [u32; 4];
+            vmull_high_u32, vmull_u32, vget_high_u32: [u32; 4] -> [u64; 2];
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vaddw_vsubw_high_matches_vget_high() {
+        fuzz_high_wide! {
+            vaddw_high_s8, vaddw_s8, vget_high_s8: [i16; 8], [i8; 16];
+            vaddw_high_s16, vaddw_s16, vget_high_s16: [i32; 4], [i16; 8];
+            vaddw_high_s32, vaddw_s32, vget_high_s32: [i64; 2], [i32; 4];
+            vaddw_high_u8, vaddw_u8, vget_high_u8: [u16; 8], [u8; 16];
+            vaddw_high_u16, vaddw_u16, vget_high_u16: [u32; 4], [u16; 8];
+            vaddw_high_u32, vaddw_u32, vget_high_u32: [u64; 2], [u32; 4];
+            vsubw_high_s8, vsubw_s8, vget_high_s8: [i16; 8], [i8; 16];
+            vsubw_high_s16, vsubw_s16, vget_high_s16: [i32; 4], [i16; 8];
+            vsubw_high_s32, vsubw_s32, vget_high_s32: [i64; 2], [i32; 4];
+            vsubw_high_u8, vsubw_u8, vget_high_u8: [u16; 8], [u8; 16];
+            vsubw_high_u16, vsubw_u16, vget_high_u16: [u32; 4], [u16; 8];
+            vsubw_high_u32, vsubw_u32, vget_high_u32: [u64; 2], [u32; 4];
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vmlal_vmlsl_high_matches_vget_high() {
+        fuzz_high_accumulate! {
+            vmlal_high_s8, vmlal_s8, vget_high_s8: [i16; 8], [i8; 16];
+            vmlal_high_s16, vmlal_s16, vget_high_s16: [i32; 4], [i16; 8];
+            vmlal_high_s32, vmlal_s32, vget_high_s32: [i64; 2], [i32; 4];
+            vmlal_high_u8, vmlal_u8, vget_high_u8: [u16; 8], [u8; 16];
+            vmlal_high_u16, vmlal_u16, vget_high_u16: [u32; 4], [u16; 8];
+            vmlal_high_u32, vmlal_u32, vget_high_u32: [u64; 2], [u32; 4];
+            vmlsl_high_s8, vmlsl_s8, vget_high_s8: [i16; 8], [i8; 16];
+            vmlsl_high_s16, vmlsl_s16, vget_high_s16: [i32; 4], [i16; 8];
+            vmlsl_high_s32, vmlsl_s32, vget_high_s32: [i64; 2], [i32; 4];
+            vmlsl_high_u8, vmlsl_u8, vget_high_u8: [u16; 8], [u8; 16];
+            vmlsl_high_u16, vmlsl_u16, vget_high_u16: [u32; 4], [u16; 8];
+            vmlsl_high_u32, vmlsl_u32, vget_high_u32: [u64; 2], [u32; 4];
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vzipq_vuzpq_vtrnq_match_single_sided() {
+        // The pair forms return the results of the `1` and `2` instructions.
+        macro_rules! check {
+            ($($pair:ident, $lo:ident, $hi:ident: $v:ty, [$t:ty; $n:literal];)*) => {$(
+                let single = |a: $v, b: $v| [$lo(a, b), $hi(a, b)];
+                fuzz_intrinsic!(
+                    $pair(a: [$t; $n], b: [$t; $n]) -> [[$t; $n]; 2],
+                    |a, b| transmute(single(transmute(a), transmute(b))),
+                );
+            )*};
+        }
+        check! {
+            vzipq_s8, vzip1q_s8, vzip2q_s8: int8x16_t, [i8; 16];
+            vzipq_u16, vzip1q_u16, vzip2q_u16: uint16x8_t, [u16; 8];
+            vzipq_f32, vzip1q_f32, vzip2q_f32: float32x4_t, [f32; 4];
+            vuzpq_u8, vuzp1q_u8, vuzp2q_u8: uint8x16_t, [u8; 16];
+            vuzpq_s16, vuzp1q_s16, vuzp2q_s16: int16x8_t, [i16; 8];
+            vuzpq_u32, vuzp1q_u32, vuzp2q_u32: uint32x4_t, [u32; 4];
+            vtrnq_p8, vtrn1q_p8, vtrn2q_p8: poly8x16_t, [u8; 16];
+            vtrnq_p16, vtrn1q_p16, vtrn2q_p16: poly16x8_t, [u16; 8];
+            vtrnq_s32, vtrn1q_s32, vtrn2q_s32: int32x4_t, [i32; 4];
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vtst_64() {
+        use stdarch_test::fuzz::Rng;
+        // As in the shared `fuzz_vtst`, lanes drawn from `0`, bit 0, bit 63
+        // and both make `a & b == 0` as likely as not. The scalar forms are
+        // tested as a single lane.
+        macro_rules! check {
+            ($($f:ident: [$t:ty; $n:literal];)*) => {$(
+                let reference = |a: [$t; $n], b: [$t; $n]| -> [u64; $n] {
+                    core::array::from_fn(|i| if a[i] & b[i] != 0 { u64::MAX } else { 0 })
+                };
+                let sparse = |rng: &mut Rng| -> [$t; $n] {
+                    rng.gen::<[u8; $n]>().map(|x| (x & 1) as $t | ((x >> 1 & 1) as $t) << 63)
+                };
+                fuzz_intrinsic!($f(a: [$t; $n], b: [$t; $n]) -> [u64; $n], reference);
+                fuzz_intrinsic!(
+                    $f(a: [$t; $n] = sparse, b: [$t; $n] = sparse) -> [u64; $n],
+                    reference,
+                );
+            )*};
+        }
+        check! {
+            vtst_s64: [i64; 1];
+            vtstq_s64: [i64; 2];
+            vtst_u64: [u64; 1];
+            vtstq_u64: [u64; 2];
+            vtst_p64: [u64; 1];
+            vtstq_p64: [u64; 2];
+            vtstd_s64: [i64; 1];
+            vtstd_u64: [u64; 1];
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vuqaddq_s16_saturation_boundary() {
+        // The unsigned operand may exceed the signed range without saturating.
+        let a = i16x8::new(i16::MIN, i16::MIN, 0, 1, 2, -1, i16::MAX, -2);
+        let b = u16x8::new(u16::MAX, u16::MAX - 1, 32767, 32766, 32767, 0, 0, 1);
+        let e = i16x8::new(
+            i16::MAX,
+            32766,
+            i16::MAX,
+            i16::MAX,
+            i16::MAX,
+            -1,
+            i16::MAX,
+            -1,
+        );
+        let r: i16x8 = transmute(vuqaddq_s16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vsqaddq_u16_saturation_boundary() {
+        let a = u16x8::new(32768, 32767, 32768, 32769, 0, u16::MAX, 1, 0);
+        let b = i16x8::new(i16::MIN, i16::MIN, i16::MAX, i16::MAX, i16::MAX, -1, -1, -1);
+        let e = u16x8::new(0, 0, u16::MAX, u16::MAX, 32767, u16::MAX - 1, 0, 0);
+        let r: u16x8 = transmute(vsqaddq_u16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vuqaddb_vsqaddb_exhaustive() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let sum = a as i8 as i32 + b as i32;
+                let e = sum.clamp(i8::MIN as i32, i8::MAX as i32) as i8;
+                assert_eq!(vuqaddb_s8(a as i8, b), e, "{} + {b}", a as i8);
+                let sum = a as i32 + b as i8 as i32;
+                let e = sum.clamp(0, u8::MAX as i32) as u8;
+                assert_eq!(vsqaddb_u8(a, b as i8), e, "{a} + {}", b as i8);
+            }
+        }
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vpaddq_s16() {
         let a = i16x8::new(1, 2, 3, 4, 5, 6, 7, 8);
@@ -4372,6 +4718,20 @@ mod tests {
         assert_eq!(r, 0.0);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vset_lane_vget_lane_f64_round_trip() {
+        let v = vset_lane_f64::<0>(-1.5, vdup_n_f64(1.0));
+        assert_eq!(vget_lane_f64::<0>(v), -1.5);
+
+        let a = vdupq_n_f64(1.0);
+        let r = vsetq_lane_f64::<0>(-1.5, a);
+        assert_eq!(vgetq_lane_f64::<0>(r), -1.5);
+        assert_eq!(vgetq_lane_f64::<1>(r), 1.0);
+        let r = vsetq_lane_f64::<1>(-1.5, a);
+        assert_eq!(vgetq_lane_f64::<0>(r), 1.0);
+        assert_eq!(vgetq_lane_f64::<1>(r), -1.5);
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vcopy_lane_s64() {
         let a: i64x1 = i64x1::new(1);
@@ -4444,6 +4804,205 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcopy_lane_only_changes_destination_lane() {
+        macro_rules! check {
+            ($f:ident: [$t:ty; $n:literal] <- [$n2:literal], [$($l1:literal),*], $l2:tt) => {$(
+                check!(@lane $f, $t, $n, $n2, $l1, $l2);
+            )*};
+            (@lane $f:ident, $t:ty, $n:literal, $n2:literal, $l1:literal, [$($l2:literal),*]) => {$(
+                let a: [$t; $n] = core::array::from_fn(|i| i as $t);
+                let b: [$t; $n2] = core::array::from_fn(|i| (100 + i) as $t);
+                let r: [$t; $n] = transmute($f::<$l1, $l2>(transmute(a), transmute(b)));
+                let mut e = a;
+                e[$l1] = b[$l2];
+                assert_eq!(r, e, "{}::<{}, {}>", stringify!($f), $l1, $l2);
+            )*};
+        }
+        check!(vcopy_lane_u16: [u16; 4] <- [4], [0, 1, 2, 3], [0, 1, 2, 3]);
+        check!(vcopy_lane_f32: [f32; 2] <- [2], [0, 1], [0, 1]);
+        check!(vcopy_laneq_s8: [i8; 8] <- [16], [0, 3, 7], [0, 8, 15]);
+        check!(vcopy_laneq_f32: [f32; 2] <- [4], [0, 1], [0, 1, 2, 3]);
+        check!(vcopyq_lane_s32: [i32; 4] <- [2], [0, 1, 2, 3], [0, 1]);
+        check!(vcopyq_lane_s64: [i64; 2] <- [1], [0, 1], [0]);
+        check!(vcopyq_laneq_u8: [u8; 16] <- [16], [0, 5, 15], [0, 10, 15]);
+        check!(vcopyq_laneq_f64: [f64; 2] <- [2], [0, 1], [0, 1]);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vrnd_halfway_cases() {
+        macro_rules! check {
+            ($t:ty, $n:literal, $inputs:expr, $($f:ident => $reference:expr),*) => {
+                for a in $inputs.chunks($n) {
+                    let a: [$t; $n] = a.try_into().unwrap();
+                    $(
+                        // Compare the bits, so that the sign of zeros is checked too.
+                        let r: [$t; $n] = transmute($f(transmute(a)));
+                        let e = a.map($reference);
+                        assert_eq!(
+                            r.map(<$t>::to_bits),
+                            e.map(<$t>::to_bits),
+                            "{}({:?})",
+                            stringify!($f),
+                            a
+                        );
+                    )*
+                }
+            };
+        }
+        // vrndi and vrndx round in the current rounding mode, which is to
+        // nearest, ties to even, by default.
+        let inputs: [f32; 12] = [
+            -2.5, -1.5, -0.5, 0.5, 1.5, 2.5, 0.49999997, -1.5000001, 8388607.5, -0., 1e30, 1e-30,
+        ];
+        check!(
+            f32, 4, inputs,
+            vrndq_f32 => f32::trunc,
+            vrndnq_f32 => f32::round_ties_even,
+            vrndmq_f32 => f32::floor,
+            vrndpq_f32 => f32::ceil,
+            vrndaq_f32 => f32::round,
+            vrndiq_f32 => f32::round_ties_even,
+            vrndxq_f32 => f32::round_ties_even
+        );
+        #[rustfmt::skip]
+        let inputs: [f64; 12] = [
+            -2.5, -1.5, -0.5, 0.5, 1.5, 2.5, 0.49999999999999994, -1.5000000000000002,
+            4503599627370495.5, -0., 1e300, 1e-300,
+        ];
+        check!(
+            f64, 2, inputs,
+            vrndq_f64 => f64::trunc,
+            vrndnq_f64 => f64::round_ties_even,
+            vrndmq_f64 => f64::floor,
+            vrndpq_f64 => f64::ceil,
+            vrndaq_f64 => f64::round,
+            vrndiq_f64 => f64::round_ties_even,
+            vrndxq_f64 => f64::round_ties_even
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqabs_vqneg_s64_saturate_at_min() {
+        let a = [i64::MIN, i64::MIN + 1];
+        let r: [i64; 1] = transmute(vqabs_s64(transmute([i64::MIN])));
+        assert_eq!(r, [i64::MAX]);
+        let r: [i64; 1] = transmute(vqneg_s64(transmute([i64::MIN])));
+        assert_eq!(r, [i64::MAX]);
+        let r: [i64; 2] = transmute(vqabsq_s64(transmute(a)));
+        assert_eq!(r, [i64::MAX, i64::MAX]);
+        let r: [i64; 2] = transmute(vqnegq_s64(transmute(a)));
+        assert_eq!(r, [i64::MAX, i64::MAX]);
+
+        // The scalar forms saturate the same way.
+        macro_rules! check {
+            ($($abs:ident, $neg:ident: $t:ty;)*) => {$(
+                for a in [<$t>::MIN, <$t>::MIN + 1, -1, 0, <$t>::MAX] {
+                    assert_eq!($abs(a), a.saturating_abs(), "{}({})", stringify!($abs), a);
+                    assert_eq!($neg(a), a.saturating_neg(), "{}({})", stringify!($neg), a);
+                }
+                assert_eq!($abs(<$t>::MIN), <$t>::MAX);
+                assert_eq!($neg(<$t>::MIN), <$t>::MAX);
+            )*};
+        }
+        check! {
+            vqabsb_s8, vqnegb_s8: i8;
+            vqabsh_s16, vqnegh_s16: i16;
+            vqabss_s32, vqnegs_s32: i32;
+            vqabsd_s64, vqnegd_s64: i64;
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vqnegq_s64() {
+        fuzz_intrinsic!(
+            vqnegq_s64(a: [i64; 2]) -> [i64; 2],
+            |a| core::array::from_fn(|i| a[i].saturating_neg()),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqmovn_high_saturation_boundaries() {
+        // The narrowed lanes go into the upper half, after the lanes of `a`.
+        let a: [i8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let b: [i16; 8] = [i16::MIN, -129, -128, -1, 0, 127, 128, i16::MAX];
+        let r: [i8; 16] = transmute(vqmovn_high_s16(transmute(a), transmute(b)));
+        let e: [i8; 16] = [
+            1, 2, 3, 4, 5, 6, 7, 8, -128, -128, -128, -1, 0, 127, 127, 127,
+        ];
+        assert_eq!(r, e);
+        let r: [u8; 16] = transmute(vqmovun_high_s16(transmute(a), transmute(b)));
+        let e: [u8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 127, 128, 255];
+        assert_eq!(r, e);
+
+        let a: [i16; 4] = [-1, -2, -3, -4];
+        let b: [i32; 4] = [-32769, -32768, 32767, 32768];
+        let r: [i16; 8] = transmute(vqmovn_high_s32(transmute(a), transmute(b)));
+        assert_eq!(r, [-1, -2, -3, -4, -32768, -32768, 32767, 32767]);
+        let r: [u16; 8] = transmute(vqmovun_high_s32(transmute(a), transmute(b)));
+        assert_eq!(r, [0xffff, 0xfffe, 0xfffd, 0xfffc, 0, 0, 32767, 32768]);
+
+        let a: [u32; 2] = [7, 8];
+        let b: [u64; 2] = [u32::MAX.into(), 1 << 32];
+        let r: [u32; 4] = transmute(vqmovn_high_u64(transmute(a), transmute(b)));
+        assert_eq!(r, [7, 8, u32::MAX, u32::MAX]);
+        let b: [i64; 2] = [-1, 1 << 32];
+        let r: [u32; 4] = transmute(vqmovun_high_s64(transmute(a), transmute(b)));
+        assert_eq!(r, [7, 8, 0, u32::MAX]);
+
+        // The scalar forms saturate the same way.
+        assert_eq!(vqmovnh_s16(-129), -128);
+        assert_eq!(vqmovnh_u16(256), 255);
+        assert_eq!(vqmovns_s32(32768), 32767);
+        assert_eq!(vqmovnd_s64(i64::MIN), i32::MIN);
+        assert_eq!(vqmovnd_u64(1 << 32), u32::MAX);
+        assert_eq!(vqmovunh_s16(-1), 0);
+        assert_eq!(vqmovuns_s32(65536), 65535);
+        assert_eq!(vqmovund_s64(1 << 32), u32::MAX);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqdmull_high_saturate_at_min() {
+        // Only the upper half of the inputs is used. `2 * MIN * MIN` saturates
+        // to the maximum, and the accumulate forms saturate that product before
+        // saturating the addition or subtraction.
+        let b: [i16; 8] = [1, 2, 3, 4, i16::MIN, i16::MIN, i16::MAX, -1];
+        let c: [i16; 8] = [5, 6, 7, 8, i16::MIN, i16::MAX, i16::MIN, i16::MIN];
+        let r: [i32; 4] = transmute(vqdmull_high_s16(transmute(b), transmute(c)));
+        assert_eq!(r, [i32::MAX, -0x7fff_0000, -0x7fff_0000, 0x10000]);
+        let r: [i32; 4] = transmute(vqdmull_high_n_s16(transmute(b), i16::MIN));
+        assert_eq!(r, [i32::MAX, i32::MAX, -0x7fff_0000, 0x10000]);
+        let l: [i16; 4] = [0, 0, 0, i16::MIN];
+        let r: [i32; 4] = transmute(vqdmull_high_lane_s16::<3>(transmute(b), transmute(l)));
+        assert_eq!(r, [i32::MAX, i32::MAX, -0x7fff_0000, 0x10000]);
+        let a: [i32; 4] = [-1; 4];
+        let r: [i32; 4] = transmute(vqdmlal_high_s16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, [i32::MAX - 1, -0x7fff_0001, -0x7fff_0001, 0xffff]);
+        let a: [i32; 4] = [0; 4];
+        let r: [i32; 4] = transmute(vqdmlsl_high_s16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, [i32::MIN + 1, 0x7fff_0000, 0x7fff_0000, -0x10000]);
+
+        let b: [i32; 4] = [1, 2, i32::MIN, i32::MIN];
+        let c: [i32; 4] = [3, 4, i32::MIN, i32::MAX];
+        let r: [i64; 2] = transmute(vqdmull_high_s32(transmute(b), transmute(c)));
+        assert_eq!(r, [i64::MAX, -0x7fff_ffff_0000_0000]);
+        let a: [i64; 2] = [-1; 2];
+        let r: [i64; 2] = transmute(vqdmlal_high_s32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, [i64::MAX - 1, -0x7fff_ffff_0000_0001]);
+        let a: [i64; 2] = [0; 2];
+        let r: [i64; 2] = transmute(vqdmlsl_high_s32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, [i64::MIN + 1, 0x7fff_ffff_0000_0000]);
+
+        // The scalar forms saturate the same way.
+        assert_eq!(vqdmullh_s16(i16::MIN, i16::MIN), i32::MAX);
+        assert_eq!(vqdmulls_s32(i32::MIN, i32::MIN), i64::MAX);
+        assert_eq!(vqdmullh_lane_s16::<3>(i16::MIN, transmute(l)), i32::MAX);
+        assert_eq!(vqdmlalh_s16(-1, i16::MIN, i16::MIN), i32::MAX - 1);
+        assert_eq!(vqdmlslh_s16(0, i16::MIN, i16::MIN), i32::MIN + 1);
+        assert_eq!(vqdmlals_s32(-1, i32::MIN, i32::MIN), i64::MAX - 1);
+        assert_eq!(vqdmlsls_s32(0, i32::MIN, i32::MIN), i64::MIN + 1);
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vceq_u64() {
         test_cmp_u64(
@@ -5118,6 +5677,15 @@ mod tests {
         assert_eq!(r, e)
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vld1q_dup_f64_reads_one_element() {
+        // The neighbours of the element must not end up in either lane.
+        let a: [f64; 3] = [1., 42., 3.];
+        let e = f64x2::new(42., 42.);
+        let r: f64x2 = transmute(vld1q_dup_f64(a[1..].as_ptr()));
+        assert_eq!(r, e)
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vld1_lane_f64() {
         let a = f64x1::new(0.);
@@ -0,0 +1,312 @@
+//! Pointer authentication (FEAT_PAuth).
+//!
+//! Pointer authentication stores a cryptographic signature of a pointer, its
+//! *pointer authentication code* (PAC), in the otherwise unused upper bits of
+//! the pointer. The PAC is computed from the pointer, a 64-bit modifier, and
+//! one of five secret keys managed by the operating system: two for
+//! instruction addresses (`IA` and `IB`), two for data addresses (`DA` and
+//! `DB`), and a generic one.
+//!
+//! These functions expose the individual sign, authenticate and strip
+//! instructions, for runtimes that sign pointers they store in memory.
+//! They are not a replacement for the return address signing done by the
+//! compiler with `-Z branch-protection=pac-ret`, which this does not affect.
+//! A signed pointer must not be dereferenced or passed to code that doesn't
+//! expect it before it has been authenticated or stripped, and is only
+//! meaningful within the process that signed it, since the keys are per
+//! process.
+//!
+//! Authenticating a pointer with the wrong key or modifier doesn't return an
+//! error. Without FEAT_FPAC it returns the pointer with an invalid address in
+//! its upper bits, so that dereferencing it faults; with FEAT_FPAC the
+//! authentication instruction faults itself.
+//!
+//! The reference is the [Arm Architecture Reference Manual for A-profile
+//! architecture][arm_ref].
+//!
+//! [arm_ref]: https://developer.arm.com/documentation/ddi0487/latest
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Signs the instruction address `ptr` with the `IA` key and `modifier`.
+///
+/// Returns `ptr` with its PAC inserted in the upper bits.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(pacia))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __pacia(ptr: *const u8, modifier: u64) -> *const u8 {
+    let mut ptr = ptr;
+    asm!(
+        "pacia {ptr}, {modifier}",
+        ptr = inout(reg) ptr,
+        modifier = in(reg) modifier,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    ptr
+}
+
+/// Signs the instruction address `ptr` with the `IB` key and `modifier`.
+///
+/// Returns `ptr` with its PAC inserted in the upper bits.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(pacib))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __pacib(ptr: *const u8, modifier: u64) -> *const u8 {
+    let mut ptr = ptr;
+    asm!(
+        "pacib {ptr}, {modifier}",
+        ptr = inout(reg) ptr,
+        modifier = in(reg) modifier,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    ptr
+}
+
+/// Signs the data address `ptr` with the `DA` key and `modifier`.
+///
+/// Returns `ptr` with its PAC inserted in the upper bits.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(pacda))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __pacda(ptr: *const u8, modifier: u64) -> *const u8 {
+    let mut ptr = ptr;
+    asm!(
+        "pacda {ptr}, {modifier}",
+        ptr = inout(reg) ptr,
+        modifier = in(reg) modifier,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    ptr
+}
+
+/// Signs the data address `ptr` with the `DB` key and `modifier`.
+///
+/// Returns `ptr` with its PAC inserted in the upper bits.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(pacdb))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __pacdb(ptr: *const u8, modifier: u64) -> *const u8 {
+    let mut ptr = ptr;
+    asm!(
+        "pacdb {ptr}, {modifier}",
+        ptr = inout(reg) ptr,
+        modifier = in(reg) modifier,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    ptr
+}
+
+/// Authenticates the instruction address `ptr` signed with the `IA` key and
+/// `modifier`.
+///
+/// Returns `ptr` with its PAC removed if authentication succeeds. Otherwise
+/// returns a pointer with an invalid address that faults when dereferenced,
+/// or faults itself if FEAT_FPAC is implemented.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(autia))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __autia(ptr: *const u8, modifier: u64) -> *const u8 {
+    let mut ptr = ptr;
+    // Authentication can fault, so this isn't `pure`: the compiler must
+    // neither remove an unused authentication nor merge two of them.
+    asm!(
+        "autia {ptr}, {modifier}",
+        ptr = inout(reg) ptr,
+        modifier = in(reg) modifier,
+        options(nostack, preserves_flags)
+    );
+    ptr
+}
+
+/// Authenticates the instruction address `ptr` signed with the `IB` key and
+/// `modifier`.
+///
+/// Returns `ptr` with its PAC removed if authentication succeeds. Otherwise
+/// returns a pointer with an invalid address that faults when dereferenced,
+/// or faults itself if FEAT_FPAC is implemented.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(autib))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __autib(ptr: *const u8, modifier: u64) -> *const u8 {
+    let mut ptr = ptr;
+    // Not `pure`, like in `__autia`.
+    asm!(
+        "autib {ptr}, {modifier}",
+        ptr = inout(reg) ptr,
+        modifier = in(reg) modifier,
+        options(nostack, preserves_flags)
+    );
+    ptr
+}
+
+/// Authenticates the data address `ptr` signed with the `DA` key and
+/// `modifier`.
+///
+/// Returns `ptr` with its PAC removed if authentication succeeds. Otherwise
+/// returns a pointer with an invalid address that faults when dereferenced,
+/// or faults itself if FEAT_FPAC is implemented.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(autda))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __autda(ptr: *const u8, modifier: u64) -> *const u8 {
+    let mut ptr = ptr;
+    // Not `pure`, like in `__autia`.
+    asm!(
+        "autda {ptr}, {modifier}",
+        ptr = inout(reg) ptr,
+        modifier = in(reg) modifier,
+        options(nostack, preserves_flags)
+    );
+    ptr
+}
+
+/// Authenticates the data address `ptr` signed with the `DB` key and
+/// `modifier`.
+///
+/// Returns `ptr` with its PAC removed if authentication succeeds. Otherwise
+/// returns a pointer with an invalid address that faults when dereferenced,
+/// or faults itself if FEAT_FPAC is implemented.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(autdb))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __autdb(ptr: *const u8, modifier: u64) -> *const u8 {
+    let mut ptr = ptr;
+    // Not `pure`, like in `__autia`.
+    asm!(
+        "autdb {ptr}, {modifier}",
+        ptr = inout(reg) ptr,
+        modifier = in(reg) modifier,
+        options(nostack, preserves_flags)
+    );
+    ptr
+}
+
+/// Removes the PAC from the instruction address `ptr` without authenticating it.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(xpaci))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __xpaci(ptr: *const u8) -> *const u8 {
+    let mut ptr = ptr;
+    asm!(
+        "xpaci {ptr}",
+        ptr = inout(reg) ptr,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    ptr
+}
+
+/// Removes the PAC from the data address `ptr` without authenticating it.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(xpacd))]
+#[unstable(feature = "stdarch_aarch64_pauth", issue = "none")]
+pub unsafe fn __xpacd(ptr: *const u8) -> *const u8 {
+    let mut ptr = ptr;
+    asm!(
+        "xpacd {ptr}",
+        ptr = inout(reg) ptr,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    ptr
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+    use stdarch_test::simd_test;
+
+    // Signing and then authenticating with the same key and modifier gives
+    // back the original pointer, and so does stripping the signed pointer.
+    macro_rules! round_trip {
+        ($pac:ident, $aut:ident, $xpac:ident) => {{
+            let x = 0x0123_4567_89ab_cdef_u64;
+            let ptr = &x as *const u64 as *const u8;
+            let signed = $pac(ptr, 42);
+            let authenticated = $aut(signed, 42);
+            assert_eq!(authenticated, ptr);
+            assert_eq!(*(authenticated as *const u64), x);
+            assert_eq!($xpac(signed), ptr);
+            // Signing is deterministic.
+            assert_eq!($pac(ptr, 42), signed);
+        }};
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_pacia() {
+        round_trip!(__pacia, __autia, __xpaci);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_pacib() {
+        round_trip!(__pacib, __autib, __xpaci);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_pacda() {
+        round_trip!(__pacda, __autda, __xpacd);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_pacdb() {
+        round_trip!(__pacdb, __autdb, __xpacd);
+    }
+
+    // Authenticating the same pointer twice gives the same result both times.
+    macro_rules! authenticate_twice {
+        ($pac:ident, $aut:ident) => {{
+            let x = 0_u8;
+            let signed = $pac(&x, 7);
+            assert_eq!($aut(signed, 7), &x as *const u8);
+            assert_eq!($aut(signed, 7), &x as *const u8);
+        }};
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_autia() {
+        authenticate_twice!(__pacia, __autia);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_autib() {
+        authenticate_twice!(__pacib, __autib);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_autda() {
+        authenticate_twice!(__pacda, __autda);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_autdb() {
+        authenticate_twice!(__pacdb, __autdb);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_xpaci() {
+        // Stripping a pointer that doesn't have a PAC leaves it unchanged.
+        let x = 0_u8;
+        assert_eq!(__xpaci(&x), &x as *const u8);
+        assert_eq!(__xpaci(__pacia(&x, 1)), &x as *const u8);
+        assert_eq!(__xpaci(__pacib(&x, 1)), &x as *const u8);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_xpacd() {
+        let x = 0_u8;
+        assert_eq!(__xpacd(&x), &x as *const u8);
+        assert_eq!(__xpacd(__pacda(&x, 1)), &x as *const u8);
+        assert_eq!(__xpacd(__pacdb(&x, 1)), &x as *const u8);
+    }
+}
@@ -0,0 +1,88 @@
+//! Random number generation (FEAT_RNG).
+//!
+//! The `RNDR` and `RNDRRS` system registers return random numbers from a
+//! hardware random number generator. Reading `RNDR` returns a number from a
+//! deterministic generator that is periodically reseeded from a true random
+//! number source, while reading `RNDRRS` forces a reseed first.
+//!
+//! Unlike the x86 `_rdrand64_step`, these functions follow ACLE and return `0`
+//! on success.
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {
+    #[link_name = "llvm.aarch64.rndr"]
+    fn aarch64_rndr() -> (u64, bool);
+    #[link_name = "llvm.aarch64.rndrrs"]
+    fn aarch64_rndrrs() -> (u64, bool);
+}
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Reads a 64-bit random number from the `RNDR` register and stores it in
+/// `val`.
+///
+/// Returns `0` on success. If no random number could be generated within a
+/// reasonable time, stores `0` in `val` and returns a non-zero value, in
+/// which case the read may be retried.
+#[inline]
+#[target_feature(enable = "rand")]
+#[cfg_attr(test, assert_instr(mrs))]
+#[unstable(feature = "stdarch_aarch64_rand", issue = "none")]
+pub unsafe fn __rndr(val: &mut u64) -> i32 {
+    let (v, failed) = aarch64_rndr();
+    *val = v;
+    failed as i32
+}
+
+/// Reseeds the random number generator and reads a 64-bit random number from
+/// the `RNDRRS` register, storing it in `val`.
+///
+/// Returns `0` on success. If no random number could be generated within a
+/// reasonable time, stores `0` in `val` and returns a non-zero value, in
+/// which case the read may be retried.
+#[inline]
+#[target_feature(enable = "rand")]
+#[cfg_attr(test, assert_instr(mrs))]
+#[unstable(feature = "stdarch_aarch64_rand", issue = "none")]
+pub unsafe fn __rndrrs(val: &mut u64) -> i32 {
+    let (v, failed) = aarch64_rndrrs();
+    *val = v;
+    failed as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+    use stdarch_test::simd_test;
+
+    /// Reads two random numbers with `f`, retrying a few times on failure.
+    unsafe fn read_two(f: unsafe fn(&mut u64) -> i32) -> [u64; 2] {
+        let mut r = [0; 2];
+        for v in &mut r {
+            let mut ok = false;
+            for _ in 0..10 {
+                if f(v) == 0 {
+                    ok = true;
+                    break;
+                }
+                assert_eq!(*v, 0);
+            }
+            assert!(ok, "no random number after 10 attempts");
+        }
+        r
+    }
+
+    #[simd_test(enable = "rand")]
+    unsafe fn test_rndr() {
+        let [a, b] = read_two(__rndr);
+        // Two equal 64-bit random numbers are vanishingly unlikely.
+        assert_ne!(a, b);
+    }
+
+    #[simd_test(enable = "rand")]
+    unsafe fn test_rndrrs() {
+        let [a, b] = read_two(__rndrrs);
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,93 @@
+//! Scalable Vector Extension (SVE) intrinsics.
+//!
+//! Only the intrinsics that take and return scalars are provided for now.
+//! The SVE vector and predicate types (`svint8_t`, `svbool_t`, ...) are
+//! sizeless, and Rust cannot yet express them, so the bulk of the SVE ACLE
+//! cannot be exposed.
+//!
+//! The reference is the [Arm C Language Extensions for SVE][acle_sve].
+//!
+//! [acle_sve]: https://developer.arm.com/documentation/100987/latest
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// `svpattern` value selecting all elements, `SV_ALL` in ACLE.
+const SV_ALL: i32 = 31;
+
+extern "unadjusted" {
+    #[link_name = "llvm.aarch64.sve.cntb"]
+    fn sve_cntb(pattern: i32) -> i64;
+    #[link_name = "llvm.aarch64.sve.cnth"]
+    fn sve_cnth(pattern: i32) -> i64;
+    #[link_name = "llvm.aarch64.sve.cntw"]
+    fn sve_cntw(pattern: i32) -> i64;
+    #[link_name = "llvm.aarch64.sve.cntd"]
+    fn sve_cntd(pattern: i32) -> i64;
+}
+
+/// Count the number of 8-bit elements in a vector.
+///
+/// The result is always a multiple of 16 between 16 and 256.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(rdvl))]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcntb() -> u64 {
+    sve_cntb(SV_ALL) as u64
+}
+
+/// Count the number of 16-bit elements in a vector.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cnth))]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcnth() -> u64 {
+    sve_cnth(SV_ALL) as u64
+}
+
+/// Count the number of 32-bit elements in a vector.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntw))]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcntw() -> u64 {
+    sve_cntw(SV_ALL) as u64
+}
+
+/// Count the number of 64-bit elements in a vector.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntd))]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcntd() -> u64 {
+    sve_cntd(SV_ALL) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntb() {
+        let n = svcntb();
+        assert!((16..=256).contains(&n));
+        assert_eq!(n % 16, 0);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcnth() {
+        assert_eq!(svcnth(), svcntb() / 2);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntw() {
+        assert_eq!(svcntw(), svcntb() / 4);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntd() {
+        assert_eq!(svcntd(), svcntb() / 8);
+    }
+}
@@ -183,3 +183,64 @@ mod arg {
     pub const OSHST: i32 = 2;
     pub const OSHLD: i32 = 1;
 }
+
+#[cfg(test)]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+mod tests {
+    use crate::core_arch::arm_shared::*;
+    use stdarch_test::assert_instr;
+
+    // The barriers are generic over their argument, which `assert_instr`
+    // doesn't support, so check the instructions through these wrappers.
+    #[assert_instr(dmb)]
+    unsafe fn dmb_sy() {
+        __dmb(SY)
+    }
+
+    #[assert_instr(dmb)]
+    unsafe fn dmb_ish() {
+        __dmb(ISH)
+    }
+
+    #[assert_instr(dsb)]
+    unsafe fn dsb_sy() {
+        __dsb(SY)
+    }
+
+    #[assert_instr(dsb)]
+    unsafe fn dsb_ish() {
+        __dsb(ISH)
+    }
+
+    #[assert_instr(isb)]
+    unsafe fn isb_sy() {
+        __isb(SY)
+    }
+
+    #[test]
+    fn test_dmb() {
+        unsafe {
+            dmb_sy();
+            dmb_ish();
+            __dmb(LD);
+            __dmb(ST);
+        }
+    }
+
+    #[test]
+    fn test_dsb() {
+        unsafe {
+            dsb_sy();
+            dsb_ish();
+            __dsb(LD);
+            __dsb(ST);
+        }
+    }
+
+    #[test]
+    fn test_isb() {
+        unsafe {
+            isb_sy();
+        }
+    }
+}
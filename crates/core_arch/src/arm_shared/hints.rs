@@ -3,10 +3,17 @@
 // - Section 7.4 "Hints" of ACLE
 // - Section 7.7 "NOP" of ACLE
 
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
 /// Generates a WFI (wait for interrupt) hint instruction, or nothing.
 ///
 /// The WFI instruction allows (but does not require) the processor to enter a
 /// low-power state until one of a number of asynchronous events occurs.
+///
+/// WFI is meant for privileged code such as kernels, which wait for the next
+/// interrupt in their idle loop. The operating system may trap it when it is
+/// executed in user mode, e.g. on AArch64 if `SCTLR_EL1.nTWI` is clear.
 // Section 10.1 of ACLE says that the supported arches are: 8, 6K, 6-M
 // LLVM says "instruction requires: armv6k"
 #[cfg(any(
@@ -16,6 +23,7 @@
     doc
 ))]
 #[inline(always)]
+#[cfg_attr(test, assert_instr(wfi))]
 #[unstable(feature = "stdarch_arm_hints", issue = "117218")]
 pub unsafe fn __wfi() {
     hint(HINT_WFI);
@@ -26,6 +34,12 @@ pub unsafe fn __wfi() {
 /// The WFE instruction allows (but does not require) the processor to enter a
 /// low-power state until some event occurs such as a SEV being issued by
 /// another processor.
+///
+/// In user mode, WFE is meant for spin loops waiting on memory written by
+/// another processor that executes [`__sev`] afterwards. The wait may end
+/// early for other reasons, so the condition has to be checked again after
+/// it. As with WFI, the operating system may trap WFE in user mode, e.g. on
+/// AArch64 if `SCTLR_EL1.nTWE` is clear.
 // Section 10.1 of ACLE says that the supported arches are: 8, 6K, 6-M
 // LLVM says "instruction requires: armv6k"
 #[cfg(any(
@@ -35,6 +49,7 @@ pub unsafe fn __wfi() {
     doc
 ))]
 #[inline(always)]
+#[cfg_attr(test, assert_instr(wfe))]
 #[unstable(feature = "stdarch_arm_hints", issue = "117218")]
 pub unsafe fn __wfe() {
     hint(HINT_WFE);
@@ -53,6 +68,7 @@ pub unsafe fn __wfe() {
     doc
 ))]
 #[inline(always)]
+#[cfg_attr(test, assert_instr(sev))]
 #[unstable(feature = "stdarch_arm_hints", issue = "117218")]
 pub unsafe fn __sev() {
     hint(HINT_SEV);
@@ -63,6 +79,9 @@ pub unsafe fn __sev() {
 /// This causes an event to be signaled to only the processor executing this
 /// instruction. In a multiprocessor system, it is not required to affect the
 /// other processors.
+///
+/// An event that is already signaled makes the next [`__wfe`] return at once,
+/// so a SEVL before a spin loop starting with WFE skips the first wait.
 // LLVM says "instruction requires: armv8"
 #[cfg(any(
     target_feature = "v8", // 32-bit ARMv8
@@ -71,6 +90,7 @@ pub unsafe fn __sev() {
     doc,
 ))]
 #[inline(always)]
+#[cfg_attr(test, assert_instr(sevl))]
 #[unstable(feature = "stdarch_arm_hints", issue = "117218")]
 pub unsafe fn __sevl() {
     hint(HINT_SEVL);
@@ -90,6 +110,7 @@ pub unsafe fn __sevl() {
     doc
 ))]
 #[inline(always)]
+#[cfg_attr(test, assert_instr(yield))]
 #[unstable(feature = "stdarch_arm_hints", issue = "117218")]
 pub unsafe fn __yield() {
     hint(HINT_YIELD);
@@ -102,6 +123,7 @@ pub unsafe fn __yield() {
 /// another instruction. It is not guaranteed that inserting this instruction
 /// will increase execution time.
 #[inline(always)]
+#[cfg_attr(test, assert_instr(nop))]
 #[unstable(feature = "stdarch_arm_hints", issue = "117218")]
 pub unsafe fn __nop() {
     crate::arch::asm!("nop", options(nomem, nostack, preserves_flags));
@@ -123,3 +145,39 @@ const HINT_WFE: i32 = 2;
 const HINT_WFI: i32 = 3;
 const HINT_SEV: i32 = 4;
 const HINT_SEVL: i32 = 5;
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::arm_shared::*;
+
+    // Only the hints that can't make the thread wait, or that are preceded by
+    // an event, are executed. `__wfi` and a bare `__wfe` are only covered by
+    // `assert_instr`.
+    #[test]
+    #[cfg(any(
+        target_feature = "v6",
+        target_arch = "aarch64",
+        target_arch = "arm64ec"
+    ))]
+    fn test_hints() {
+        unsafe {
+            __nop();
+            __yield();
+            __sev();
+        }
+    }
+
+    #[test]
+    #[cfg(any(
+        target_feature = "v8",
+        target_arch = "aarch64",
+        target_arch = "arm64ec"
+    ))]
+    fn test_sevl_wfe() {
+        // The local event makes the wait return immediately.
+        unsafe {
+            __sevl();
+            __wfe();
+        }
+    }
+}
@@ -10209,8 +10209,8 @@ mod tests {
     use crate::core_arch::arm::*;
     use crate::core_arch::arm_shared::test_support::*;
     use crate::core_arch::simd::*;
-    use std::{i16, i32, i8, mem::transmute, u16, u32, u8, vec::Vec};
-    use stdarch_test::simd_test;
+    use std::{hint::black_box, i16, i32, i8, mem::transmute, u16, u32, u8, vec::Vec};
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     #[simd_test(enable = "neon")]
     unsafe fn test_vld1_lane_s8() {
@@ -10626,6 +10626,100 @@ mod tests {
         assert_eq!(r, e)
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vld1_dup_reads_one_element() {
+        // The neighbours of the element must not end up in any lane.
+        macro_rules! check {
+            ($($f:ident: [$t:ty; $n:literal],)*) => {$(
+                let a: [$t; 3] = [1 as $t, 2 as $t, 3 as $t];
+                let r: [$t; $n] = transmute($f(black_box(a.as_ptr().add(1))));
+                assert_eq!(r, [2 as $t; $n], stringify!($f));
+            )*};
+        }
+        check!(
+            vld1_dup_s8: [i8; 8],
+            vld1_dup_s16: [i16; 4],
+            vld1_dup_s32: [i32; 2],
+            vld1_dup_s64: [i64; 1],
+            vld1_dup_u8: [u8; 8],
+            vld1_dup_u16: [u16; 4],
+            vld1_dup_u32: [u32; 2],
+            vld1_dup_u64: [u64; 1],
+            vld1_dup_p8: [u8; 8],
+            vld1_dup_p16: [u16; 4],
+            vld1_dup_f32: [f32; 2],
+            vld1q_dup_s8: [i8; 16],
+            vld1q_dup_s16: [i16; 8],
+            vld1q_dup_s32: [i32; 4],
+            vld1q_dup_s64: [i64; 2],
+            vld1q_dup_u8: [u8; 16],
+            vld1q_dup_u16: [u16; 8],
+            vld1q_dup_u32: [u32; 4],
+            vld1q_dup_u64: [u64; 2],
+            vld1q_dup_p8: [u8; 16],
+            vld1q_dup_p16: [u16; 8],
+            vld1q_dup_f32: [f32; 4],
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vdup_n_vmov_n_every_lane() {
+        // Hide the value from the optimizer so that the broadcast happens at
+        // run time.
+        macro_rules! check {
+            ($($f:ident: [$t:ty; $n:literal],)*) => {$(
+                let r: [$t; $n] = transmute($f(black_box(90 as $t)));
+                assert_eq!(r, [90 as $t; $n], stringify!($f));
+            )*};
+        }
+        check!(
+            vdup_n_s8: [i8; 8],
+            vdup_n_s16: [i16; 4],
+            vdup_n_s32: [i32; 2],
+            vdup_n_s64: [i64; 1],
+            vdup_n_u8: [u8; 8],
+            vdup_n_u16: [u16; 4],
+            vdup_n_u32: [u32; 2],
+            vdup_n_u64: [u64; 1],
+            vdup_n_p8: [u8; 8],
+            vdup_n_p16: [u16; 4],
+            vdup_n_f32: [f32; 2],
+            vdupq_n_s8: [i8; 16],
+            vdupq_n_s16: [i16; 8],
+            vdupq_n_s32: [i32; 4],
+            vdupq_n_s64: [i64; 2],
+            vdupq_n_u8: [u8; 16],
+            vdupq_n_u16: [u16; 8],
+            vdupq_n_u32: [u32; 4],
+            vdupq_n_u64: [u64; 2],
+            vdupq_n_p8: [u8; 16],
+            vdupq_n_p16: [u16; 8],
+            vdupq_n_f32: [f32; 4],
+            vmov_n_s8: [i8; 8],
+            vmov_n_s16: [i16; 4],
+            vmov_n_s32: [i32; 2],
+            vmov_n_s64: [i64; 1],
+            vmov_n_u8: [u8; 8],
+            vmov_n_u16: [u16; 4],
+            vmov_n_u32: [u32; 2],
+            vmov_n_u64: [u64; 1],
+            vmov_n_p8: [u8; 8],
+            vmov_n_p16: [u16; 4],
+            vmov_n_f32: [f32; 2],
+            vmovq_n_s8: [i8; 16],
+            vmovq_n_s16: [i16; 8],
+            vmovq_n_s32: [i32; 4],
+            vmovq_n_s64: [i64; 2],
+            vmovq_n_u8: [u8; 16],
+            vmovq_n_u16: [u16; 8],
+            vmovq_n_u32: [u32; 4],
+            vmovq_n_u64: [u64; 2],
+            vmovq_n_p8: [u8; 16],
+            vmovq_n_p16: [u16; 8],
+            vmovq_n_f32: [f32; 4],
+        );
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vget_lane_u8() {
         let v = i8x8::new(1, 2, 3, 4, 5, 6, 7, 8);
@@ -10870,6 +10964,778 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vreinterpretq_round_trip() {
+        // Includes NaN bit patterns when viewed as f32, which must survive unchanged.
+        let a = u8x16::new(
+            0x01, 0x00, 0xc0, 0x7f, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00, 0x12, 0x34,
+            0x56, 0x78,
+        );
+        let r = vreinterpretq_u32_u8(transmute(a));
+        let r = vreinterpretq_f32_u32(r);
+        let r = vreinterpretq_s16_f32(r);
+        let r = vreinterpretq_p8_s16(r);
+        let r = vreinterpretq_s64_p8(r);
+        let r: u8x16 = transmute(vreinterpretq_u8_s64(r));
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqshlq_s32_signed_shift() {
+        // Negative shift amounts shift right, truncating towards minus infinity.
+        let a = i32x4::new(1, -8, 0x4000_0000, -7);
+        let b = i32x4::new(3, -2, 1, -1);
+        let e = i32x4::new(8, -2, i32::MAX, -4);
+        let r: i32x4 = transmute(vqshlq_s32(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+        let a = i32x4::new(i32::MIN, -5, 5, -1);
+        let b = i32x4::new(1, -32, -32, 31);
+        let e = i32x4::new(i32::MIN, -1, 0, i32::MIN);
+        let r: i32x4 = transmute(vqshlq_s32(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqrshlq_s32_signed_shift() {
+        // Right shifts round to nearest, with ties towards plus infinity.
+        let a = i32x4::new(1, -8, 0x4000_0000, -7);
+        let b = i32x4::new(3, -2, 1, -1);
+        let e = i32x4::new(8, -2, i32::MAX, -3);
+        let r: i32x4 = transmute(vqrshlq_s32(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+        let a = i32x4::new(i32::MIN, -5, 5, -1);
+        let b = i32x4::new(1, -32, -2, 31);
+        let e = i32x4::new(i32::MIN, 0, 1, i32::MIN);
+        let r: i32x4 = transmute(vqrshlq_s32(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqshlq_u16_signed_shift() {
+        let a = u16x8::new(1, 0x8000, 7, 0xffff, 5, 0x0100, 3, 0);
+        let b = i16x8::new(4, 1, -1, -16, -2, 8, -1, 15);
+        let e = u16x8::new(16, 0xffff, 3, 0, 1, 0xffff, 1, 0);
+        let r: u16x8 = transmute(vqshlq_u16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqrshlq_u16_signed_shift() {
+        let a = u16x8::new(1, 0x8000, 7, 0xffff, 5, 0x0100, 3, 0);
+        let b = i16x8::new(4, 1, -1, -16, -2, 8, -1, 15);
+        let e = u16x8::new(16, 0xffff, 4, 1, 1, 0xffff, 2, 0);
+        let r: u16x8 = transmute(vqrshlq_u16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vrecpeq_f32_newton_raphson() {
+        let xs = [0.3, 1.0, 7.5, 1.0e-3];
+        let x = vld1q_f32(xs.as_ptr());
+        // Each step computes `r * (2 - x * r)`.
+        let mut r = vrecpeq_f32(x);
+        for _ in 0..2 {
+            r = vmulq_f32(r, vrecpsq_f32(x, r));
+        }
+        let r: [f32; 4] = transmute(r);
+        for (x, r) in xs.iter().zip(r) {
+            let e = 1.0 / x;
+            assert!(((r - e) / e).abs() < 1.0e-6, "1 / {x}: {r} != {e}");
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vrsqrteq_f32_newton_raphson() {
+        let xs = [0.3, 1.0, 7.5, 1.0e-3];
+        let x = vld1q_f32(xs.as_ptr());
+        // Each step computes `r * (3 - x * r * r) / 2`.
+        let mut r = vrsqrteq_f32(x);
+        for _ in 0..2 {
+            r = vmulq_f32(r, vrsqrtsq_f32(vmulq_f32(x, r), r));
+        }
+        let r: [f32; 4] = transmute(r);
+        for (x, r) in xs.iter().zip(r) {
+            let e = 1.0 / x.sqrt();
+            assert!(((r - e) / e).abs() < 1.0e-6, "1 / sqrt({x}): {r} != {e}");
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vaddq_f32() {
+        fuzz_intrinsic!(
+            vaddq_f32(a: [f32; 4], b: [f32; 4]) -> [f32; 4],
+            |a, b| core::array::from_fn(|i| a[i] + b[i]),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vaddq_s32() {
+        fuzz_intrinsic!(
+            vaddq_s32(a: [i32; 4], b: [i32; 4]) -> [i32; 4],
+            |a, b| core::array::from_fn(|i| a[i].wrapping_add(b[i])),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vqaddq_s16() {
+        fuzz_intrinsic!(
+            vqaddq_s16(a: [i16; 8], b: [i16; 8]) -> [i16; 8],
+            |a, b| core::array::from_fn(|i| a[i].saturating_add(b[i])),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vhaddq_u8() {
+        fuzz_intrinsic!(
+            vhaddq_u8(a: [u8; 16], b: [u8; 16]) -> [u8; 16],
+            |a, b| core::array::from_fn(|i| ((a[i] as u16 + b[i] as u16) >> 1) as u8),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcls_s8_sign_runs() {
+        // The sign bit itself isn't counted, so the result is one less than
+        // the length of the leading run of bits equal to it.
+        let a = i8x8::new(0, -1, 1, -2, 0x3f, -0x40, 0x40, i8::MIN);
+        let e = i8x8::new(7, 7, 6, 6, 1, 1, 0, 0);
+        let r: i8x8 = transmute(vcls_s8(transmute(a)));
+        assert_eq!(r, e);
+        // The unsigned forms look at the same bits.
+        let r: i8x8 = transmute(vcls_u8(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vcls_s8() {
+        fuzz_intrinsic!(
+            vcls_s8(a: [i8; 8]) -> [i8; 8],
+            |a| core::array::from_fn(|i| ((a[i] ^ (a[i] >> 7)) as u8).leading_zeros() as i8 - 1),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vclsq_s16() {
+        fuzz_intrinsic!(
+            vclsq_s16(a: [i16; 8]) -> [i16; 8],
+            |a| core::array::from_fn(|i| ((a[i] ^ (a[i] >> 15)) as u16).leading_zeros() as i16 - 1),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vcls_u32() {
+        fuzz_intrinsic!(
+            vcls_u32(a: [u32; 2]) -> [i32; 2],
+            |a| core::array::from_fn(|i| {
+                let a = a[i] as i32;
+                ((a ^ (a >> 31)) as u32).leading_zeros() as i32 - 1
+            }),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vclzq_s8() {
+        fuzz_intrinsic!(
+            vclzq_s8(a: [i8; 16]) -> [i8; 16],
+            |a| core::array::from_fn(|i| a[i].leading_zeros() as i8),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vclz_u16() {
+        fuzz_intrinsic!(
+            vclz_u16(a: [u16; 4]) -> [u16; 4],
+            |a| core::array::from_fn(|i| a[i].leading_zeros() as u16),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vclzq_s32() {
+        fuzz_intrinsic!(
+            vclzq_s32(a: [i32; 4]) -> [i32; 4],
+            |a| core::array::from_fn(|i| a[i].leading_zeros() as i32),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vcntq_u8() {
+        fuzz_intrinsic!(
+            vcntq_u8(a: [u8; 16]) -> [u8; 16],
+            |a| core::array::from_fn(|i| a[i].count_ones() as u8),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vmull_s16() {
+        fuzz_intrinsic!(
+            vmull_s16(a: [i16; 4], b: [i16; 4]) -> [i32; 4],
+            |a, b| core::array::from_fn(|i| a[i] as i32 * b[i] as i32),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vmull_u8() {
+        fuzz_intrinsic!(
+            vmull_u8(a: [u8; 8], b: [u8; 8]) -> [u16; 8],
+            |a, b| core::array::from_fn(|i| a[i] as u16 * b[i] as u16),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vmlal_s32() {
+        fuzz_intrinsic!(
+            vmlal_s32(a: [i64; 2], b: [i32; 2], c: [i32; 2]) -> [i64; 2],
+            |a, b, c| core::array::from_fn(|i| a[i].wrapping_add(b[i] as i64 * c[i] as i64)),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vmlsl_u16() {
+        fuzz_intrinsic!(
+            vmlsl_u16(a: [u32; 4], b: [u16; 4], c: [u16; 4]) -> [u32; 4],
+            |a, b, c| core::array::from_fn(|i| a[i].wrapping_sub(b[i] as u32 * c[i] as u32)),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vabdq_s8() {
+        fuzz_intrinsic!(
+            vabdq_s8(a: [i8; 16], b: [i8; 16]) -> [i8; 16],
+            // The difference can be up to 255, which wraps around.
+            |a, b| core::array::from_fn(|i| (a[i] as i16 - b[i] as i16).abs() as i8),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vabd_u16() {
+        fuzz_intrinsic!(
+            vabd_u16(a: [u16; 4], b: [u16; 4]) -> [u16; 4],
+            |a, b| core::array::from_fn(|i| a[i].abs_diff(b[i])),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vabdq_f32() {
+        fuzz_intrinsic!(
+            vabdq_f32(a: [f32; 4], b: [f32; 4]) -> [f32; 4],
+            |a, b| core::array::from_fn(|i| (a[i] - b[i]).abs()),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vabdl_s16() {
+        fuzz_intrinsic!(
+            vabdl_s16(a: [i16; 4], b: [i16; 4]) -> [i32; 4],
+            |a, b| core::array::from_fn(|i| (a[i] as i32 - b[i] as i32).abs()),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vabaq_u8() {
+        fuzz_intrinsic!(
+            vabaq_u8(a: [u8; 16], b: [u8; 16], c: [u8; 16]) -> [u8; 16],
+            |a, b, c| core::array::from_fn(|i| a[i].wrapping_add(b[i].abs_diff(c[i]))),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vabal_s8() {
+        fuzz_intrinsic!(
+            vabal_s8(a: [i16; 8], b: [i8; 8], c: [i8; 8]) -> [i16; 8],
+            |a, b, c| core::array::from_fn(|i| a[i].wrapping_add(b[i].abs_diff(c[i]) as i16)),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vabal_u8_block_sad() {
+        // The sum of absolute differences between a block of the current
+        // frame and a block of the reference frame, as done in motion
+        // estimation.
+        const STRIDE: usize = 32;
+        let cur: [u8; STRIDE * 8] = core::array::from_fn(|i| (i * 37 + 11) as u8);
+        let prev: [u8; STRIDE * 8] = core::array::from_fn(|i| (i * 101 + 3) as u8);
+        let sad = |x: usize, width: usize| -> u32 {
+            (0..8)
+                .flat_map(|y| (x..x + width).map(move |x| y * STRIDE + x))
+                .map(|i| cur[i].abs_diff(prev[i]) as u32)
+                .sum()
+        };
+
+        // 8x8 blocks, accumulating a row at a time with vabal.
+        for x in [0, 5, 24] {
+            let mut acc = vdupq_n_u16(0);
+            for y in 0..8 {
+                let i = y * STRIDE + x;
+                acc = vabal_u8(acc, vld1_u8(cur[i..].as_ptr()), vld1_u8(prev[i..].as_ptr()));
+            }
+            let r: [u16; 8] = transmute(acc);
+            assert_eq!(r.iter().map(|&s| s as u32).sum::<u32>(), sad(x, 8));
+        }
+
+        // 16x8 blocks, with vabd and a pairwise widening accumulate.
+        for x in [0, 7, 16] {
+            let mut acc = vdupq_n_u16(0);
+            for y in 0..8 {
+                let i = y * STRIDE + x;
+                let d = vabdq_u8(vld1q_u8(cur[i..].as_ptr()), vld1q_u8(prev[i..].as_ptr()));
+                acc = vpadalq_u8(acc, d);
+            }
+            let r: [u16; 8] = transmute(acc);
+            assert_eq!(r.iter().map(|&s| s as u32).sum::<u32>(), sad(x, 16));
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqdmull_vqdmlal_vqdmlsl_saturate_at_min() {
+        // `2 * MIN * MIN` is one past the maximum of the wide type, so the
+        // doubled product saturates. The accumulating forms saturate that
+        // product first and then saturate again when adding or subtracting
+        // it, which differs from a single fused saturation.
+        macro_rules! check {
+            ($($mull:ident, $mull_n:ident, $mull_lane:ident, $mlal:ident, $mlsl:ident:
+                [$t:ty; $n:literal] -> $w:ty;)*) => {$(
+                let qdmull = |b: $t, c: $t| {
+                    let r = 2 * i128::from(b) * i128::from(c);
+                    r.clamp(<$w>::MIN.into(), <$w>::MAX.into()) as $w
+                };
+                let b: [$t; $n] =
+                    core::array::from_fn(|i| [<$t>::MIN, <$t>::MIN, <$t>::MAX, -1][i % 4]);
+                let c: [$t; $n] =
+                    core::array::from_fn(|i| [<$t>::MIN, <$t>::MAX, <$t>::MIN, <$t>::MIN][i % 4]);
+
+                let r: [$w; $n] = transmute($mull(transmute(b), transmute(c)));
+                let p: [$w; $n] = core::array::from_fn(|i| qdmull(b[i], c[i]));
+                assert_eq!(r, p, stringify!($mull));
+                assert_eq!(r[0], <$w>::MAX, stringify!($mull));
+                let r: [$w; $n] = transmute($mull_n(transmute(b), <$t>::MIN));
+                assert_eq!(r, b.map(|b| qdmull(b, <$t>::MIN)), stringify!($mull_n));
+                let r: [$w; $n] = transmute($mull_lane::<0>(transmute(b), transmute(c)));
+                assert_eq!(r, b.map(|b| qdmull(b, <$t>::MIN)), stringify!($mull_lane));
+
+                for acc in [<$w>::MIN, -1, 0, 1, <$w>::MAX] {
+                    let a = [acc; $n];
+                    let r: [$w; $n] = transmute($mlal(transmute(a), transmute(b), transmute(c)));
+                    let e: [$w; $n] = core::array::from_fn(|i| acc.saturating_add(p[i]));
+                    assert_eq!(r, e, "{}({})", stringify!($mlal), acc);
+                    let r: [$w; $n] = transmute($mlsl(transmute(a), transmute(b), transmute(c)));
+                    let e: [$w; $n] = core::array::from_fn(|i| acc.saturating_sub(p[i]));
+                    assert_eq!(r, e, "{}({})", stringify!($mlsl), acc);
+                }
+                let a: [$w; $n] = [-1; $n];
+                let r: [$w; $n] = transmute($mlal(transmute(a), transmute(b), transmute(c)));
+                assert_eq!(r[0], <$w>::MAX - 1, stringify!($mlal));
+                let a: [$w; $n] = [0; $n];
+                let r: [$w; $n] = transmute($mlsl(transmute(a), transmute(b), transmute(c)));
+                assert_eq!(r[0], <$w>::MIN + 1, stringify!($mlsl));
+            )*};
+        }
+        check! {
+            vqdmull_s16, vqdmull_n_s16, vqdmull_lane_s16, vqdmlal_s16, vqdmlsl_s16:
+                [i16; 4] -> i32;
+            vqdmull_s32, vqdmull_n_s32, vqdmull_lane_s32, vqdmlal_s32, vqdmlsl_s32:
+                [i32; 2] -> i64;
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vqdmlal_s16() {
+        fuzz_intrinsic!(
+            vqdmlal_s16(a: [i32; 4], b: [i16; 4], c: [i16; 4]) -> [i32; 4],
+            |a, b, c| core::array::from_fn(|i| {
+                let p = (2 * i64::from(b[i]) * i64::from(c[i])).min(i32::MAX.into()) as i32;
+                a[i].saturating_add(p)
+            }),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqabs_vqneg_saturate_at_min() {
+        // `abs` and `-` of the minimum value overflow, and saturate to the
+        // maximum value instead of wrapping around to the minimum.
+        macro_rules! check {
+            ($($abs:ident, $neg:ident: [$t:ty; $n:literal];)*) => {$(
+                let a: [$t; $n] = core::array::from_fn(|i| match i % 4 {
+                    0 => <$t>::MIN,
+                    1 => <$t>::MIN + 1,
+                    2 => <$t>::MAX,
+                    _ => -1,
+                });
+                let r: [$t; $n] = transmute($abs(transmute(a)));
+                let e: [$t; $n] = core::array::from_fn(|i| a[i].saturating_abs());
+                assert_eq!(r, e, stringify!($abs));
+                assert_eq!(r[0], <$t>::MAX, stringify!($abs));
+                let r: [$t; $n] = transmute($neg(transmute(a)));
+                let e: [$t; $n] = core::array::from_fn(|i| a[i].saturating_neg());
+                assert_eq!(r, e, stringify!($neg));
+                assert_eq!(r[0], <$t>::MAX, stringify!($neg));
+            )*};
+        }
+        check! {
+            vqabs_s8, vqneg_s8: [i8; 8];
+            vqabsq_s8, vqnegq_s8: [i8; 16];
+            vqabs_s16, vqneg_s16: [i16; 4];
+            vqabsq_s16, vqnegq_s16: [i16; 8];
+            vqabs_s32, vqneg_s32: [i32; 2];
+            vqabsq_s32, vqnegq_s32: [i32; 4];
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vqabsq_s8() {
+        fuzz_intrinsic!(
+            vqabsq_s8(a: [i8; 16]) -> [i8; 16],
+            |a| core::array::from_fn(|i| a[i].saturating_abs()),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vqnegq_s16() {
+        fuzz_intrinsic!(
+            vqnegq_s16(a: [i16; 8]) -> [i16; 8],
+            |a| core::array::from_fn(|i| a[i].saturating_neg()),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vqabs_s32() {
+        fuzz_intrinsic!(
+            vqabs_s32(a: [i32; 2]) -> [i32; 2],
+            |a| core::array::from_fn(|i| a[i].saturating_abs()),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vqmovn_vqmovun_saturation_boundaries() {
+        // Values on both sides of the limits of the narrow type. The source
+        // lanes are filled from `inputs` a vector at a time.
+        macro_rules! check {
+            ($($f:ident: [$s:ty; $n:literal] -> $d:ty, $reference:expr;)*) => {$(
+                let inputs: [i64; 16] = [
+                    i64::MIN, -(1 << 31) - 1, -(1 << 31), -32769, -32768, -129, -128, -1,
+                    0, 127, 128, 255, 256, 32767, 65535, 65536,
+                ];
+                let inputs = inputs.map(|x| x as $s);
+                let reference: fn($s) -> $d = $reference;
+                for a in inputs.chunks($n) {
+                    let a: [$s; $n] = a.try_into().unwrap();
+                    let r: [$d; $n] = transmute($f(transmute(a)));
+                    assert_eq!(r, a.map(reference), "{}({:?})", stringify!($f), a);
+                }
+            )*};
+        }
+        check! {
+            vqmovn_s16: [i16; 8] -> i8, |x| x.clamp(i8::MIN.into(), i8::MAX.into()) as i8;
+            vqmovn_s32: [i32; 4] -> i16, |x| x.clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+            vqmovn_s64: [i64; 2] -> i32, |x| x.clamp(i32::MIN.into(), i32::MAX.into()) as i32;
+            vqmovn_u16: [u16; 8] -> u8, |x| x.min(u8::MAX.into()) as u8;
+            vqmovn_u32: [u32; 4] -> u16, |x| x.min(u16::MAX.into()) as u16;
+            vqmovn_u64: [u64; 2] -> u32, |x| x.min(u32::MAX.into()) as u32;
+            vqmovun_s16: [i16; 8] -> u8, |x| x.clamp(0, u8::MAX.into()) as u8;
+            vqmovun_s32: [i32; 4] -> u16, |x| x.clamp(0, u16::MAX.into()) as u16;
+            vqmovun_s64: [i64; 2] -> u32, |x| x.clamp(0, u32::MAX.into()) as u32;
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vqmovn_s32() {
+        fuzz_intrinsic!(
+            vqmovn_s32(a: [i32; 4]) -> [i16; 4],
+            |a| a.map(|x| x.clamp(i16::MIN.into(), i16::MAX.into()) as i16),
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vqmovun_s16() {
+        fuzz_intrinsic!(
+            vqmovun_s16(a: [i16; 8]) -> [u8; 8],
+            |a| a.map(|x| x.clamp(0, u8::MAX.into()) as u8),
+        );
+    }
+
+    macro_rules! fuzz_zip_uzp_trn {
+        ($($zip:ident, $uzp:ident, $trn:ident: $v:ty, [$t:ty; $n:literal];)*) => {$(
+            fuzz_intrinsic!(
+                $zip(a: [$t; $n], b: [$t; $n]) -> [[$t; $n]; 2],
+                |a, b| core::array::from_fn(|h| core::array::from_fn(|i| {
+                    let j = h * $n / 2 + i / 2;
+                    if i % 2 == 0 { a[j] } else { b[j] }
+                })),
+            );
+            fuzz_intrinsic!(
+                $uzp(a: [$t; $n], b: [$t; $n]) -> [[$t; $n]; 2],
+                |a, b| core::array::from_fn(|h| core::array::from_fn(|i| {
+                    let j = 2 * i + h;
+                    if j < $n { a[j] } else { b[j - $n] }
+                })),
+            );
+            fuzz_intrinsic!(
+                $trn(a: [$t; $n], b: [$t; $n]) -> [[$t; $n]; 2],
+                |a, b| core::array::from_fn(|h| core::array::from_fn(|i| {
+                    let j = i / 2 * 2 + h;
+                    if i % 2 == 0 { a[j] } else { b[j] }
+                })),
+            );
+            // Unzipping the two halves of a zip gives back the inputs.
+            let zip_uzp = |a: $v, b: $v| {
+                let r = $zip(a, b);
+                $uzp(r.0, r.1)
+            };
+            fuzz_intrinsic!(zip_uzp(a: [$t; $n], b: [$t; $n]) -> [[$t; $n]; 2], |a, b| [a, b]);
+        )*};
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vzip_vuzp_vtrn() {
+        fuzz_zip_uzp_trn! {
+            vzip_s8, vuzp_s8, vtrn_s8: int8x8_t, [i8; 8];
+            vzip_s16, vuzp_s16, vtrn_s16: int16x4_t, [i16; 4];
+            vzip_s32, vuzp_s32, vtrn_s32: int32x2_t, [i32; 2];
+            vzip_u8, vuzp_u8, vtrn_u8: uint8x8_t, [u8; 8];
+            vzip_u16, vuzp_u16, vtrn_u16: uint16x4_t, [u16; 4];
+            vzip_u32, vuzp_u32, vtrn_u32: uint32x2_t, [u32; 2];
+            vzip_p8, vuzp_p8, vtrn_p8: poly8x8_t, [u8; 8];
+            vzip_p16, vuzp_p16, vtrn_p16: poly16x4_t, [u16; 4];
+            vzip_f32, vuzp_f32, vtrn_f32: float32x2_t, [f32; 2];
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vzipq_vuzpq_vtrnq() {
+        fuzz_zip_uzp_trn! {
+            vzipq_s8, vuzpq_s8, vtrnq_s8: int8x16_t, [i8; 16];
+            vzipq_s16, vuzpq_s16, vtrnq_s16: int16x8_t, [i16; 8];
+            vzipq_s32, vuzpq_s32, vtrnq_s32: int32x4_t, [i32; 4];
+            vzipq_u8, vuzpq_u8, vtrnq_u8: uint8x16_t, [u8; 16];
+            vzipq_u16, vuzpq_u16, vtrnq_u16: uint16x8_t, [u16; 8];
+            vzipq_u32, vuzpq_u32, vtrnq_u32: uint32x4_t, [u32; 4];
+            vzipq_p8, vuzpq_p8, vtrnq_p8: poly8x16_t, [u8; 16];
+            vzipq_p16, vuzpq_p16, vtrnq_p16: poly16x8_t, [u16; 8];
+            vzipq_f32, vuzpq_f32, vtrnq_f32: float32x4_t, [f32; 4];
+        }
+    }
+
+    // A lane of the result is all ones if `a & b` is nonzero in that lane, and
+    // zero otherwise. Random lanes almost always share a bit, so the lanes are
+    // also drawn from `0`, the lowest bit, the highest bit and both, which
+    // gives zero and nonzero `a & b` about as often.
+    macro_rules! fuzz_vtst {
+        ($($f:ident: [$t:ty; $n:literal] -> $u:ty;)*) => {$(
+            let reference = |a: [$t; $n], b: [$t; $n]| -> [$u; $n] {
+                core::array::from_fn(|i| if a[i] & b[i] != 0 { <$u>::MAX } else { 0 })
+            };
+            let sparse = |rng: &mut Rng| -> [$t; $n] {
+                rng.gen::<[u8; $n]>().map(|x| {
+                    (x & 1) as $t | ((x >> 1 & 1) as $t) << (<$t>::BITS - 1)
+                })
+            };
+            fuzz_intrinsic!($f(a: [$t; $n], b: [$t; $n]) -> [$u; $n], reference);
+            fuzz_intrinsic!(
+                $f(a: [$t; $n] = sparse, b: [$t; $n] = sparse) -> [$u; $n],
+                reference,
+            );
+        )*};
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn fuzz_vtst() {
+        use stdarch_test::fuzz::Rng;
+        fuzz_vtst! {
+            vtst_s8: [i8; 8] -> u8;
+            vtstq_s8: [i8; 16] -> u8;
+            vtst_s16: [i16; 4] -> u16;
+            vtstq_s16: [i16; 8] -> u16;
+            vtst_s32: [i32; 2] -> u32;
+            vtstq_s32: [i32; 4] -> u32;
+            vtst_u8: [u8; 8] -> u8;
+            vtstq_u8: [u8; 16] -> u8;
+            vtst_u16: [u16; 4] -> u16;
+            vtstq_u16: [u16; 8] -> u16;
+            vtst_u32: [u32; 2] -> u32;
+            vtstq_u32: [u32; 4] -> u32;
+            vtst_p8: [u8; 8] -> u8;
+            vtstq_p8: [u8; 16] -> u8;
+            vtst_p16: [u16; 4] -> u16;
+            vtstq_p16: [u16; 8] -> u16;
+        }
+    }
+
+    // Setting a lane only changes that lane, and getting it back returns the
+    // value that was set.
+    macro_rules! test_set_get_lane {
+        ($($set:ident, $get:ident: [$t:ty; $n:literal] = $x:expr => $($lane:literal)*;)*) => {$(
+            let a: [$t; $n] = core::array::from_fn(|i| (i + 1) as $t);
+            $(
+                let r = $set::<$lane>($x, transmute(a));
+                assert_eq!($get::<$lane>(r), $x, "{}::<{}>", stringify!($get), $lane);
+                let r: [$t; $n] = transmute(r);
+                let mut e = a;
+                e[$lane] = $x;
+                assert_eq!(r, e, "{}::<{}>", stringify!($set), $lane);
+            )*
+        )*};
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vset_lane_vget_lane_round_trip() {
+        test_set_get_lane! {
+            vset_lane_s8, vget_lane_s8: [i8; 8] = i8::MIN => 0 1 2 3 4 5 6 7;
+            vset_lane_s16, vget_lane_s16: [i16; 4] = i16::MIN => 0 1 2 3;
+            vset_lane_s32, vget_lane_s32: [i32; 2] = i32::MIN => 0 1;
+            vset_lane_s64, vget_lane_s64: [i64; 1] = i64::MIN => 0;
+            vset_lane_u8, vget_lane_u8: [u8; 8] = u8::MAX => 0 1 2 3 4 5 6 7;
+            vset_lane_u16, vget_lane_u16: [u16; 4] = u16::MAX => 0 1 2 3;
+            vset_lane_u32, vget_lane_u32: [u32; 2] = u32::MAX => 0 1;
+            vset_lane_u64, vget_lane_u64: [u64; 1] = u64::MAX => 0;
+            vset_lane_p8, vget_lane_p8: [u8; 8] = 0x81 => 0 1 2 3 4 5 6 7;
+            vset_lane_p16, vget_lane_p16: [u16; 4] = 0x8001 => 0 1 2 3;
+            vset_lane_f32, vget_lane_f32: [f32; 2] = -1.5 => 0 1;
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vsetq_lane_vgetq_lane_round_trip() {
+        test_set_get_lane! {
+            vsetq_lane_s8, vgetq_lane_s8: [i8; 16] = i8::MIN
+                => 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15;
+            vsetq_lane_s16, vgetq_lane_s16: [i16; 8] = i16::MIN => 0 1 2 3 4 5 6 7;
+            vsetq_lane_s32, vgetq_lane_s32: [i32; 4] = i32::MIN => 0 1 2 3;
+            vsetq_lane_s64, vgetq_lane_s64: [i64; 2] = i64::MIN => 0 1;
+            vsetq_lane_u8, vgetq_lane_u8: [u8; 16] = u8::MAX
+                => 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15;
+            vsetq_lane_u16, vgetq_lane_u16: [u16; 8] = u16::MAX => 0 1 2 3 4 5 6 7;
+            vsetq_lane_u32, vgetq_lane_u32: [u32; 4] = u32::MAX => 0 1 2 3;
+            vsetq_lane_u64, vgetq_lane_u64: [u64; 2] = u64::MAX => 0 1;
+            vsetq_lane_p8, vgetq_lane_p8: [u8; 16] = 0x81
+                => 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15;
+            vsetq_lane_p16, vgetq_lane_p16: [u16; 8] = 0x8001 => 0 1 2 3 4 5 6 7;
+            vsetq_lane_f32, vgetq_lane_f32: [f32; 4] = -1.5 => 0 1 2 3;
+        }
+    }
+
+    #[simd_test(enable = "neon,aes")]
+    unsafe fn test_vset_lane_vget_lane_p64_round_trip() {
+        test_set_get_lane! {
+            vset_lane_p64, vget_lane_p64: [u64; 1] = 1 << 63 => 0;
+            vsetq_lane_p64, vgetq_lane_p64: [u64; 2] = 1 << 63 => 0 1;
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vmlal_vmlsl_lane_s16_every_lane() {
+        let a = [1, -2, 3, i32::MIN];
+        let b = [i16::MIN, -1, 2, i16::MAX];
+        let c = [-3, i16::MIN, 5, i16::MAX];
+        macro_rules! check {
+            ($($lane:literal),*) => {$(
+                let r: [i32; 4] =
+                    transmute(vmlal_lane_s16::<$lane>(transmute(a), transmute(b), transmute(c)));
+                let e: [i32; 4] =
+                    core::array::from_fn(|i| a[i].wrapping_add(b[i] as i32 * c[$lane] as i32));
+                assert_eq!(r, e, "vmlal_lane_s16::<{}>", $lane);
+                let r: [i32; 4] =
+                    transmute(vmlsl_lane_s16::<$lane>(transmute(a), transmute(b), transmute(c)));
+                let e: [i32; 4] =
+                    core::array::from_fn(|i| a[i].wrapping_sub(b[i] as i32 * c[$lane] as i32));
+                assert_eq!(r, e, "vmlsl_lane_s16::<{}>", $lane);
+            )*};
+        }
+        check!(0, 1, 2, 3);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcombine_vget_low_high_round_trip() {
+        let bytes = u8x16::new(
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        );
+        let low = 0x7766_5544_3322_1100_u64;
+        let high = 0xffee_ddcc_bbaa_9988_u64;
+        macro_rules! check {
+            ($($t:ty: $get_low:ident, $get_high:ident, $combine:ident, $create:ident;)*) => {$(
+                let v: $t = transmute(bytes);
+                let r: u8x16 = transmute($combine($get_low(v), $get_high(v)));
+                assert_eq!(r, bytes, stringify!($combine));
+                let r: u64 = transmute($get_low(v));
+                assert_eq!(r, low, stringify!($get_low));
+                let r: u64 = transmute($get_high(v));
+                assert_eq!(r, high, stringify!($get_high));
+                let r: u8x16 = transmute($combine($create(low), $create(high)));
+                assert_eq!(r, bytes, stringify!($create));
+            )*};
+        }
+        check!(
+            int8x16_t: vget_low_s8, vget_high_s8, vcombine_s8, vcreate_s8;
+            int16x8_t: vget_low_s16, vget_high_s16, vcombine_s16, vcreate_s16;
+            int32x4_t: vget_low_s32, vget_high_s32, vcombine_s32, vcreate_s32;
+            int64x2_t: vget_low_s64, vget_high_s64, vcombine_s64, vcreate_s64;
+            uint8x16_t: vget_low_u8, vget_high_u8, vcombine_u8, vcreate_u8;
+            uint16x8_t: vget_low_u16, vget_high_u16, vcombine_u16, vcreate_u16;
+            uint32x4_t: vget_low_u32, vget_high_u32, vcombine_u32, vcreate_u32;
+            uint64x2_t: vget_low_u64, vget_high_u64, vcombine_u64, vcreate_u64;
+            float32x4_t: vget_low_f32, vget_high_f32, vcombine_f32, vcreate_f32;
+            poly8x16_t: vget_low_p8, vget_high_p8, vcombine_p8, vcreate_p8;
+            poly16x8_t: vget_low_p16, vget_high_p16, vcombine_p16, vcreate_p16;
+        );
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vdupq_laneq_u8_every_lane() {
+        let a = u8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        macro_rules! check {
+            ($($n:literal),*) => {$(
+                let r: u8x16 = transmute(vdupq_laneq_u8::<$n>(transmute(a)));
+                assert_eq!(r, u8x16::splat($n));
+            )*};
+        }
+        check!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vdup_lane_every_lane() {
+        // 64-bit to 64-bit, 64-bit to 128-bit and 128-bit to 64-bit broadcasts.
+        let a = i16x4::new(10, 11, 12, 13);
+        let b = f32x4::new(0.5, 1.5, 2.5, 3.5);
+        macro_rules! check {
+            ($($n:literal),*) => {$(
+                let r: i16x4 = transmute(vdup_lane_s16::<$n>(transmute(a)));
+                assert_eq!(r, i16x4::splat(10 + $n));
+                let r: i16x8 = transmute(vdupq_lane_s16::<$n>(transmute(a)));
+                assert_eq!(r, i16x8::splat(10 + $n));
+                let r: f32x2 = transmute(vdup_laneq_f32::<$n>(transmute(b)));
+                assert_eq!(r, f32x2::splat($n as f32 + 0.5));
+                let r: f32x4 = transmute(vdupq_laneq_f32::<$n>(transmute(b)));
+                assert_eq!(r, f32x4::splat($n as f32 + 0.5));
+            )*};
+        }
+        check!(0, 1, 2, 3);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vextq_u8_rotate() {
+        // Extracting from a vector concatenated with itself rotates it.
+        let a = u8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let e = u8x16::new(5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4);
+        let r: u8x16 = transmute(vextq_u8::<5>(transmute(a), transmute(a)));
+        assert_eq!(r, e);
+        let r: u8x16 = transmute(vextq_u8::<0>(transmute(a), transmute(a)));
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vext_s16_rotate() {
+        let a = i16x4::new(0, 1, 2, 3);
+        let e = i16x4::new(3, 0, 1, 2);
+        let r: i16x4 = transmute(vext_s16::<3>(transmute(a), transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vextq_f32_rotate() {
+        let a = f32x4::new(0., 1., 2., 3.);
+        let e = f32x4::new(2., 3., 0., 1.);
+        let r: f32x4 = transmute(vextq_f32::<2>(transmute(a), transmute(a)));
+        assert_eq!(r, e);
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vget_high_s8() {
         let a = i8x16::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
@@ -12191,6 +13057,49 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vpaddl_vpadal_widening() {
+        // Pairwise sums that overflow the source element type.
+        let r: u16x8 = transmute(vpaddlq_u8(vdupq_n_u8(u8::MAX)));
+        assert_eq!(r, u16x8::splat(2 * u8::MAX as u16));
+        let r: i16x8 = transmute(vpaddlq_s8(vdupq_n_s8(i8::MIN)));
+        assert_eq!(r, i16x8::splat(2 * i8::MIN as i16));
+        let r: i64x2 = transmute(vpaddlq_s32(vdupq_n_s32(i32::MAX)));
+        assert_eq!(r, i64x2::splat(2 * i32::MAX as i64));
+        // Non-widening pairwise additions wrap instead.
+        let r: u8x8 = transmute(vpadd_u8(vdup_n_u8(u8::MAX), vdup_n_u8(1)));
+        assert_eq!(r, u8x8::new(254, 254, 254, 254, 2, 2, 2, 2));
+        // The accumulation happens at the wide type, and wraps there.
+        let a = u32x4::new(0, 1, u32::MAX - 2 * u16::MAX as u32, u32::MAX);
+        let r: u32x4 = transmute(vpadalq_u16(transmute(a), vdupq_n_u16(u16::MAX)));
+        let e = u32x4::new(
+            2 * u16::MAX as u32,
+            2 * u16::MAX as u32 + 1,
+            u32::MAX,
+            2 * u16::MAX as u32 - 1,
+        );
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vpaddl_popcount_reduction() {
+        // Widening pairwise additions reduce per-byte bit counts without
+        // overflowing, as used for population counts of large buffers.
+        let bytes: [u8; 16] = [
+            0xff, 0x00, 0x0f, 0xf0, 0x55, 0xaa, 0x01, 0x80, 0xff, 0xff, 0x7f, 0xfe, 0x3c, 0xc3,
+            0x00, 0x11,
+        ];
+        let counts = vcntq_u8(vld1q_u8(bytes.as_ptr()));
+        let r: u64x2 = transmute(vpaddlq_u32(vpaddlq_u16(vpaddlq_u8(counts))));
+        let low: u32 = bytes[..8].iter().map(|b| b.count_ones()).sum();
+        let high: u32 = bytes[8..].iter().map(|b| b.count_ones()).sum();
+        assert_eq!(r, u64x2::new(low as u64, high as u64));
+        // `vpadal` accumulates the next chunk into the running totals.
+        let acc = vpadalq_u8(vpaddlq_u8(counts), counts);
+        let r: u64x2 = transmute(vpaddlq_u32(vpaddlq_u16(acc)));
+        assert_eq!(r, u64x2::new(2 * low as u64, 2 * high as u64));
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vpaddl_s8() {
         let a = i8x8::new(-4, -3, -2, -1, 0, 1, 2, 3);
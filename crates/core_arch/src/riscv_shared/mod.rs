@@ -25,12 +25,15 @@
 //! single inline assembly block and make sure to restore the original rounding mode before the end
 //! of the block.
 mod p;
+mod v;
 mod zb;
 mod zk;
 
 #[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
 pub use p::*;
 #[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+pub use v::*;
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
 pub use zb::*;
 #[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
 pub use zk::*;
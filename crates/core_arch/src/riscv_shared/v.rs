@@ -0,0 +1,168 @@
+//! Vector extension (V) intrinsics.
+//!
+//! Only the configuration intrinsics, which take and return scalars, are
+//! provided for now. The vector types of the [RVV intrinsics][rvv]
+//! (`vint32m1_t`, `vfloat32m1_t`, ...) are sizeless, like the SVE types of
+//! AArch64, and Rust cannot yet express them. The loads, stores and
+//! arithmetic of the extension (`vle32_v_i32m1`, `vse32_v_i32m1`,
+//! `vadd_vv_i32m1`, `vmul_vv_f32m1`, ...) take and return these types, so
+//! they cannot be exposed. Neither can the vector crypto extensions
+//! (`vaesef_vv`, `vsha2ch_vv`, ... of Zvkned and Zvknha), which operate on
+//! element groups of four 32-bit elements, each holding an AES state or half
+//! of a SHA-256 state, in the same types.
+//!
+//! The names encode the vector configuration: `e32` is the selected element
+//! width (SEW) of 32 bits and `m1` is the register group multiplier (LMUL)
+//! of one register. Only the integer multipliers `m1`, `m2`, `m4` and `m8`
+//! are provided.
+//!
+//! [rvv]: https://github.com/riscv-non-isa/rvv-intrinsic-doc
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[cfg(target_arch = "riscv32")]
+extern "unadjusted" {
+    #[link_name = "llvm.riscv.vsetvli.i32"]
+    fn _vsetvli_32(avl: i32, sew: i32, lmul: i32) -> i32;
+
+    #[link_name = "llvm.riscv.vsetvlimax.i32"]
+    fn _vsetvlimax_32(sew: i32, lmul: i32) -> i32;
+}
+
+#[cfg(target_arch = "riscv64")]
+extern "unadjusted" {
+    #[link_name = "llvm.riscv.vsetvli.i64"]
+    fn _vsetvli_64(avl: i64, sew: i64, lmul: i64) -> i64;
+
+    #[link_name = "llvm.riscv.vsetvlimax.i64"]
+    fn _vsetvlimax_64(sew: i64, lmul: i64) -> i64;
+}
+
+macro_rules! vsetvl {
+    ($($vsetvl:ident, $vsetvlmax:ident: $sew:literal, $lmul:literal = $vsew:literal, $vlmul:literal;)*) => {$(
+        #[doc = concat!(
+            "Sets the vector length for ", stringify!($sew), "-bit elements in groups of ",
+            stringify!($lmul), " registers and returns it."
+        )]
+        ///
+        /// The returned vector length `vl` is the number of elements that the following vector
+        /// instructions process. It is `avl` if `avl` is at most the maximum vector length
+        /// `VLMAX` of this configuration. A loop over `n` elements can process `vsetvl(n)`
+        /// elements at a time, including the last ones.
+        ///
+        /// # Safety
+        ///
+        /// This function is safe to use if the `v` target feature is present.
+        #[inline]
+        #[target_feature(enable = "v")]
+        #[cfg_attr(test, assert_instr(vsetvli))]
+        #[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+        pub unsafe fn $vsetvl(avl: usize) -> usize {
+            #[cfg(target_arch = "riscv32")]
+            {
+                _vsetvli_32(avl as i32, $vsew, $vlmul) as usize
+            }
+
+            #[cfg(target_arch = "riscv64")]
+            {
+                _vsetvli_64(avl as i64, $vsew, $vlmul) as usize
+            }
+        }
+
+        #[doc = concat!(
+            "Sets the maximum vector length for ", stringify!($sew), "-bit elements in groups of ",
+            stringify!($lmul), " registers and returns it."
+        )]
+        ///
+        /// # Safety
+        ///
+        /// This function is safe to use if the `v` target feature is present.
+        #[inline]
+        #[target_feature(enable = "v")]
+        #[cfg_attr(test, assert_instr(vsetvli))]
+        #[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+        pub unsafe fn $vsetvlmax() -> usize {
+            #[cfg(target_arch = "riscv32")]
+            {
+                _vsetvlimax_32($vsew, $vlmul) as usize
+            }
+
+            #[cfg(target_arch = "riscv64")]
+            {
+                _vsetvlimax_64($vsew, $vlmul) as usize
+            }
+        }
+    )*};
+}
+
+// The `vsew` and `vlmul` fields of the `vtype` CSR.
+vsetvl! {
+    vsetvl_e8m1, vsetvlmax_e8m1: 8, 1 = 0, 0;
+    vsetvl_e8m2, vsetvlmax_e8m2: 8, 2 = 0, 1;
+    vsetvl_e8m4, vsetvlmax_e8m4: 8, 4 = 0, 2;
+    vsetvl_e8m8, vsetvlmax_e8m8: 8, 8 = 0, 3;
+    vsetvl_e16m1, vsetvlmax_e16m1: 16, 1 = 1, 0;
+    vsetvl_e16m2, vsetvlmax_e16m2: 16, 2 = 1, 1;
+    vsetvl_e16m4, vsetvlmax_e16m4: 16, 4 = 1, 2;
+    vsetvl_e16m8, vsetvlmax_e16m8: 16, 8 = 1, 3;
+    vsetvl_e32m1, vsetvlmax_e32m1: 32, 1 = 2, 0;
+    vsetvl_e32m2, vsetvlmax_e32m2: 32, 2 = 2, 1;
+    vsetvl_e32m4, vsetvlmax_e32m4: 32, 4 = 2, 2;
+    vsetvl_e32m8, vsetvlmax_e32m8: 32, 8 = 2, 3;
+    vsetvl_e64m1, vsetvlmax_e64m1: 64, 1 = 3, 0;
+    vsetvl_e64m2, vsetvlmax_e64m2: 64, 2 = 3, 1;
+    vsetvl_e64m4, vsetvlmax_e64m4: 64, 4 = 3, 2;
+    vsetvl_e64m8, vsetvlmax_e64m8: 64, 8 = 3, 3;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "riscv32")]
+    use crate::core_arch::riscv32::*;
+    #[cfg(target_arch = "riscv64")]
+    use crate::core_arch::riscv64::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "v")]
+    unsafe fn test_vsetvlmax() {
+        // VLEN is a power of two of at least 128 bits for the V extension.
+        let vlmax = vsetvlmax_e8m1();
+        assert!(vlmax >= 16);
+        assert!(vlmax.is_power_of_two());
+        assert_eq!(vsetvlmax_e16m1(), vlmax / 2);
+        assert_eq!(vsetvlmax_e32m1(), vlmax / 4);
+        assert_eq!(vsetvlmax_e64m1(), vlmax / 8);
+        assert_eq!(vsetvlmax_e32m2(), vlmax / 2);
+        assert_eq!(vsetvlmax_e32m4(), vlmax);
+        assert_eq!(vsetvlmax_e32m8(), vlmax * 2);
+    }
+
+    #[simd_test(enable = "v")]
+    unsafe fn test_vsetvl_tail() {
+        let vlmax = vsetvlmax_e32m1();
+        assert_eq!(vsetvl_e32m1(0), 0);
+        assert_eq!(vsetvl_e32m1(1), 1);
+        assert_eq!(vsetvl_e32m1(vlmax), vlmax);
+        assert_eq!(vsetvl_e32m1(2 * vlmax), vlmax);
+        assert_eq!(vsetvl_e32m1(usize::MAX), vlmax);
+
+        // Strip-mine an element-wise addition: every element is added once,
+        // including those of the last, partial, group.
+        let a: [i32; 67] = core::array::from_fn(|i| i as i32);
+        let b: [i32; 67] = core::array::from_fn(|i| 1000 - 3 * i as i32);
+        let mut c = [0; 67];
+        let mut i = 0;
+        while i < c.len() {
+            let vl = vsetvl_e32m1(c.len() - i);
+            assert!(vl > 0 && vl <= vlmax);
+            for ((c, a), b) in c[i..i + vl].iter_mut().zip(&a[i..]).zip(&b[i..]) {
+                *c = a + b;
+            }
+            i += vl;
+        }
+        assert_eq!(i, c.len());
+        let e: [i32; 67] = core::array::from_fn(|i| 1000 - 2 * i as i32);
+        assert_eq!(c, e);
+    }
+}
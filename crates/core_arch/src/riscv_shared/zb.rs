@@ -14,6 +14,18 @@ extern "unadjusted" {
 
     #[link_name = "llvm.riscv.clmulr.i32"]
     fn _clmulr_32(rs1: i32, rs2: i32) -> i32;
+
+    #[link_name = "llvm.smin.i32"]
+    fn _smin_32(rs1: i32, rs2: i32) -> i32;
+
+    #[link_name = "llvm.smax.i32"]
+    fn _smax_32(rs1: i32, rs2: i32) -> i32;
+
+    #[link_name = "llvm.umin.i32"]
+    fn _umin_32(rs1: i32, rs2: i32) -> i32;
+
+    #[link_name = "llvm.umax.i32"]
+    fn _umax_32(rs1: i32, rs2: i32) -> i32;
 }
 
 #[cfg(target_arch = "riscv64")]
@@ -29,6 +41,18 @@ extern "unadjusted" {
 
     #[link_name = "llvm.riscv.clmulr.i64"]
     fn _clmulr_64(rs1: i64, rs2: i64) -> i64;
+
+    #[link_name = "llvm.smin.i64"]
+    fn _smin_64(rs1: i64, rs2: i64) -> i64;
+
+    #[link_name = "llvm.smax.i64"]
+    fn _smax_64(rs1: i64, rs2: i64) -> i64;
+
+    #[link_name = "llvm.umin.i64"]
+    fn _umin_64(rs1: i64, rs2: i64) -> i64;
+
+    #[link_name = "llvm.umax.i64"]
+    fn _umax_64(rs1: i64, rs2: i64) -> i64;
 }
 
 /// Bitwise OR-Combine, byte granule
@@ -62,6 +86,191 @@ pub unsafe fn orc_b(rs: usize) -> usize {
     }
 }
 
+/// Maximum
+///
+/// Returns the larger of rs1 and rs2, interpreted as signed integers.
+///
+/// Source: RISC-V Bit-Manipulation ISA-extensions
+///
+/// Version: v1.0.0
+///
+/// Section: 2.20
+///
+/// # Safety
+///
+/// This function is safe to use if the `zbb` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(max))]
+#[inline]
+pub unsafe fn max(rs1: isize, rs2: isize) -> isize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _smax_32(rs1 as i32, rs2 as i32) as isize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _smax_64(rs1 as i64, rs2 as i64) as isize
+    }
+}
+
+/// Unsigned maximum
+///
+/// Returns the larger of rs1 and rs2, interpreted as unsigned integers.
+///
+/// Source: RISC-V Bit-Manipulation ISA-extensions
+///
+/// Version: v1.0.0
+///
+/// Section: 2.21
+///
+/// # Safety
+///
+/// This function is safe to use if the `zbb` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(maxu))]
+#[inline]
+pub unsafe fn maxu(rs1: usize, rs2: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _umax_32(rs1 as i32, rs2 as i32) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _umax_64(rs1 as i64, rs2 as i64) as usize
+    }
+}
+
+/// Minimum
+///
+/// Returns the smaller of rs1 and rs2, interpreted as signed integers.
+///
+/// Source: RISC-V Bit-Manipulation ISA-extensions
+///
+/// Version: v1.0.0
+///
+/// Section: 2.22
+///
+/// # Safety
+///
+/// This function is safe to use if the `zbb` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(min))]
+#[inline]
+pub unsafe fn min(rs1: isize, rs2: isize) -> isize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _smin_32(rs1 as i32, rs2 as i32) as isize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _smin_64(rs1 as i64, rs2 as i64) as isize
+    }
+}
+
+/// Unsigned minimum
+///
+/// Returns the smaller of rs1 and rs2, interpreted as unsigned integers.
+///
+/// Source: RISC-V Bit-Manipulation ISA-extensions
+///
+/// Version: v1.0.0
+///
+/// Section: 2.23
+///
+/// # Safety
+///
+/// This function is safe to use if the `zbb` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(minu))]
+#[inline]
+pub unsafe fn minu(rs1: usize, rs2: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _umin_32(rs1 as i32, rs2 as i32) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _umin_64(rs1 as i64, rs2 as i64) as usize
+    }
+}
+
+/// Sign-extend byte
+///
+/// Sign-extends the least-significant byte of rs to XLEN bits by copying its most-significant
+/// bit to all of the more-significant bits.
+///
+/// Source: RISC-V Bit-Manipulation ISA-extensions
+///
+/// Version: v1.0.0
+///
+/// Section: 2.33
+///
+/// # Safety
+///
+/// This function is safe to use if the `zbb` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(sext.b))]
+#[inline]
+pub unsafe fn sext_b(rs: usize) -> usize {
+    rs as i8 as isize as usize
+}
+
+/// Sign-extend halfword
+///
+/// Sign-extends the least-significant halfword of rs to XLEN bits by copying its
+/// most-significant bit to all of the more-significant bits.
+///
+/// Source: RISC-V Bit-Manipulation ISA-extensions
+///
+/// Version: v1.0.0
+///
+/// Section: 2.34
+///
+/// # Safety
+///
+/// This function is safe to use if the `zbb` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(sext.h))]
+#[inline]
+pub unsafe fn sext_h(rs: usize) -> usize {
+    rs as i16 as isize as usize
+}
+
+/// Zero-extend halfword
+///
+/// Zero-extends the least-significant halfword of rs to XLEN bits by clearing all of the
+/// more-significant bits.
+///
+/// Zbb is needed for the single instruction. Zbkb has the same encoding as `pack` (RV32) or
+/// `packw` (RV64) with `zero` as the second operand, and the base ISA needs two shifts.
+///
+/// Source: RISC-V Bit-Manipulation ISA-extensions
+///
+/// Version: v1.0.0
+///
+/// Section: 2.44
+///
+/// # Safety
+///
+/// This function is safe to use if the `zbb` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(zext.h))]
+#[inline]
+pub unsafe fn zext_h(rs: usize) -> usize {
+    rs as u16 as usize
+}
+
 /// Carry-less multiply (low-part)
 ///
 /// clmul produces the lower half of the 2·XLEN carry-less product.
@@ -148,3 +357,70 @@ pub unsafe fn clmulr(rs1: usize, rs2: usize) -> usize {
         _clmulr_64(rs1 as i64, rs2 as i64) as usize
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "riscv32")]
+    use crate::core_arch::riscv32::*;
+    #[cfg(target_arch = "riscv64")]
+    use crate::core_arch::riscv64::*;
+    use stdarch_test::simd_test;
+
+    const VALUES: [u64; 8] = [
+        0,
+        1,
+        0x7f,
+        0x80,
+        0xff,
+        0x7fff_1234,
+        0x8000_ff80,
+        0xdead_beef_cafe_f00d,
+    ];
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_sext_zext() {
+        for v in VALUES {
+            let rs = v as usize;
+            assert_eq!(sext_b(rs) as isize as i64, v as i8 as i64, "sext_b({v:#x})");
+            assert_eq!(
+                sext_h(rs) as isize as i64,
+                v as i16 as i64,
+                "sext_h({v:#x})"
+            );
+            assert_eq!(zext_h(rs) as u64, v as u16 as u64, "zext_h({v:#x})");
+        }
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_min_max() {
+        let signed = [
+            isize::MIN,
+            isize::MIN + 1,
+            -1,
+            0,
+            1,
+            isize::MAX - 1,
+            isize::MAX,
+        ];
+        for a in signed {
+            for b in signed {
+                assert_eq!(min(a, b), a.min(b), "min({a}, {b})");
+                assert_eq!(max(a, b), a.max(b), "max({a}, {b})");
+            }
+        }
+        let unsigned = [
+            0,
+            1,
+            isize::MAX as usize,
+            isize::MIN as usize,
+            usize::MAX - 1,
+            usize::MAX,
+        ];
+        for a in unsigned {
+            for b in unsigned {
+                assert_eq!(minu(a, b), a.min(b), "minu({a}, {b})");
+                assert_eq!(maxu(a, b), a.max(b), "maxu({a}, {b})");
+            }
+        }
+    }
+}
@@ -3818,7 +3818,7 @@ pub fn f32x4_min(a: v128, b: v128) -> v128 {
     unsafe { llvm_f32x4_min(a.as_f32x4(), b.as_f32x4()).v128() }
 }
 
-/// Calculates the lane-wise minimum of two 128-bit vectors interpreted
+/// Calculates the lane-wise maximum of two 128-bit vectors interpreted
 /// as four 32-bit floating point numbers.
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.max))]
@@ -3830,6 +3830,11 @@ pub fn f32x4_max(a: v128, b: v128) -> v128 {
 }
 
 /// Lane-wise minimum value, defined as `b < a ? b : a`
+///
+/// Unlike [`f32x4_min`], this returns the lane of `a` whenever the
+/// comparison is false, which is when either lane is NaN or when the
+/// lanes are `-0.0` and `+0.0`. [`f32x4_min`] returns NaN if either lane
+/// is NaN and considers `-0.0` to be less than `+0.0`.
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.pmin))]
 #[target_feature(enable = "simd128")]
@@ -3847,6 +3852,11 @@ pub fn f32x4_pmin(a: v128, b: v128) -> v128 {
 }
 
 /// Lane-wise maximum value, defined as `a < b ? b : a`
+///
+/// Unlike [`f32x4_max`], this returns the lane of `a` whenever the
+/// comparison is false, which is when either lane is NaN or when the
+/// lanes are `-0.0` and `+0.0`. [`f32x4_max`] returns NaN if either lane
+/// is NaN and considers `+0.0` to be greater than `-0.0`.
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.pmax))]
 #[target_feature(enable = "simd128")]
@@ -4005,6 +4015,11 @@ pub fn f64x2_max(a: v128, b: v128) -> v128 {
 }
 
 /// Lane-wise minimum value, defined as `b < a ? b : a`
+///
+/// Unlike [`f64x2_min`], this returns the lane of `a` whenever the
+/// comparison is false, which is when either lane is NaN or when the
+/// lanes are `-0.0` and `+0.0`. [`f64x2_min`] returns NaN if either lane
+/// is NaN and considers `-0.0` to be less than `+0.0`.
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.pmin))]
 #[target_feature(enable = "simd128")]
@@ -4022,6 +4037,11 @@ pub fn f64x2_pmin(a: v128, b: v128) -> v128 {
 }
 
 /// Lane-wise maximum value, defined as `a < b ? b : a`
+///
+/// Unlike [`f64x2_max`], this returns the lane of `a` whenever the
+/// comparison is false, which is when either lane is NaN or when the
+/// lanes are `-0.0` and `+0.0`. [`f64x2_max`] returns NaN if either lane
+/// is NaN and considers `+0.0` to be greater than `-0.0`.
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.pmax))]
 #[target_feature(enable = "simd128")]
@@ -4320,6 +4340,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_load_store_lane_every_lane() {
+        macro_rules! check {
+            ($load:ident, $store:ident, $t:ty, $n:literal, [$($lane:literal),*]) => {$(
+                let v: [$t; $n] = core::array::from_fn(|i| i as $t + 1);
+                let x: $t = 100;
+                let r: [$t; $n] = transmute($load::<$lane>(transmute(v), &x));
+                let mut e = v;
+                e[$lane] = x;
+                assert_eq!(r, e, "{}::<{}>", stringify!($load), $lane);
+
+                let mut spot: $t = 0;
+                $store::<$lane>(transmute(v), &mut spot);
+                assert_eq!(spot, v[$lane], "{}::<{}>", stringify!($store), $lane);
+            )*};
+        }
+        unsafe {
+            check!(
+                v128_load8_lane,
+                v128_store8_lane,
+                u8,
+                16,
+                [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+            );
+            check!(
+                v128_load16_lane,
+                v128_store16_lane,
+                u16,
+                8,
+                [0, 1, 2, 3, 4, 5, 6, 7]
+            );
+            check!(v128_load32_lane, v128_store32_lane, u32, 4, [0, 1, 2, 3]);
+            check!(v128_load64_lane, v128_store64_lane, u64, 2, [0, 1]);
+        }
+    }
+
+    #[test]
+    fn test_load_zero_clears_upper_bits() {
+        unsafe {
+            // Load from an odd offset into a buffer of all ones, so that the
+            // load is unaligned and the memory after the element isn't zero.
+            let buf = [u8::MAX; 17];
+            let m = buf.as_ptr().add(1);
+            let r: [u32; 4] = transmute(v128_load32_zero(m as *const u32));
+            assert_eq!(r, [u32::MAX, 0, 0, 0]);
+            let r: [u64; 2] = transmute(v128_load64_zero(m as *const u64));
+            assert_eq!(r, [u64::MAX, 0]);
+        }
+    }
+
+    #[test]
+    fn test_load_extend_boundaries() {
+        unsafe {
+            let arr: [i8; 8] = [i8::MIN, -1, i8::MAX, 0, 1, -2, -127, 126];
+            let r: [i16; 8] = transmute(i16x8_load_extend_i8x8(arr.as_ptr()));
+            assert_eq!(r, [-128, -1, 127, 0, 1, -2, -127, 126]);
+            let r: [u16; 8] = transmute(i16x8_load_extend_u8x8(arr.as_ptr() as *const u8));
+            assert_eq!(r, [0x80, 0xff, 0x7f, 0, 1, 0xfe, 0x81, 0x7e]);
+
+            let arr: [i16; 4] = [i16::MIN, -1, i16::MAX, 1];
+            let r: [i32; 4] = transmute(i32x4_load_extend_i16x4(arr.as_ptr()));
+            assert_eq!(r, [-0x8000, -1, 0x7fff, 1]);
+            let r: [u32; 4] = transmute(i32x4_load_extend_u16x4(arr.as_ptr() as *const u16));
+            assert_eq!(r, [0x8000, 0xffff, 0x7fff, 1]);
+
+            let arr: [i32; 2] = [i32::MIN, -1];
+            let r: [i64; 2] = transmute(i64x2_load_extend_i32x2(arr.as_ptr()));
+            assert_eq!(r, [-0x8000_0000, -1]);
+            let r: [u64; 2] = transmute(i64x2_load_extend_u32x2(arr.as_ptr() as *const u32));
+            assert_eq!(r, [0x8000_0000, 0xffff_ffff]);
+        }
+    }
+
     #[test]
     fn test_i8x16() {
         const A: v128 = super::i8x16(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
@@ -5167,6 +5260,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pmin_pmax_nan_and_zero() {
+        let bits = |v: v128| -> [u32; 4] { unsafe { transmute(v) } };
+        let nan = f32::NAN;
+
+        // When the comparison is false, because a lane is NaN or the lanes
+        // are zeros of different signs, the lane of `a` is returned.
+        let a = f32x4(nan, 1.0, -0.0, 0.0);
+        let b = f32x4(1.0, nan, 0.0, -0.0);
+        let e = [nan.to_bits(), 1.0f32.to_bits(), (-0.0f32).to_bits(), 0];
+        assert_eq!(bits(f32x4_pmin(a, b)), e);
+        assert_eq!(bits(f32x4_pmax(a, b)), e);
+        // min and max are symmetric instead: NaN wins, and -0.0 is less than
+        // +0.0.
+        let r: [f32; 4] = unsafe { transmute(f32x4_min(a, b)) };
+        assert!(r[0].is_nan() && r[1].is_nan());
+        assert_eq!(bits(f32x4_min(a, b))[2..], [(-0.0f32).to_bits(); 2]);
+        let r: [f32; 4] = unsafe { transmute(f32x4_max(a, b)) };
+        assert!(r[0].is_nan() && r[1].is_nan());
+        assert_eq!(bits(f32x4_max(a, b))[2..], [0; 2]);
+
+        // Otherwise they are ordinary minimum and maximum.
+        let a = f32x4(1.0, 2.0, f32::NEG_INFINITY, f32::INFINITY);
+        let b = f32x4(2.0, 1.0, f32::INFINITY, -0.0);
+        let r: [f32; 4] = unsafe { transmute(f32x4_pmin(a, b)) };
+        assert_eq!(r, [1.0, 1.0, f32::NEG_INFINITY, -0.0]);
+        let r: [f32; 4] = unsafe { transmute(f32x4_pmax(a, b)) };
+        assert_eq!(r, [2.0, 2.0, f32::INFINITY, f32::INFINITY]);
+
+        let bits = |v: v128| -> [u64; 2] { unsafe { transmute(v) } };
+        let nan = f64::NAN;
+        let (a, b) = (f64x2(nan, -0.0), f64x2(1.0, 0.0));
+        let e = [nan.to_bits(), (-0.0f64).to_bits()];
+        assert_eq!(bits(f64x2_pmin(a, b)), e);
+        assert_eq!(bits(f64x2_pmax(a, b)), e);
+        let e = [1.0f64.to_bits(), 0];
+        assert_eq!(bits(f64x2_pmin(b, a)), e);
+        assert_eq!(bits(f64x2_pmax(b, a)), e);
+        let (a, b) = (f64x2(-1.0, f64::INFINITY), f64x2(1.0, f64::MAX));
+        let r: [f64; 2] = unsafe { transmute(f64x2_pmin(a, b)) };
+        assert_eq!(r, [-1.0, f64::MAX]);
+        let r: [f64; 2] = unsafe { transmute(f64x2_pmax(a, b)) };
+        assert_eq!(r, [1.0, f64::INFINITY]);
+    }
+
     test_unop! {
         test_i8x16_abs => {
             (wrapping_abs | i8x16_abs)
@@ -22,7 +22,11 @@ use stdarch_test::assert_instr;
 
 /// Counts the leading most significant zero bits.
 ///
-/// When the operand is zero, it returns its size in bits.
+/// When the operand is zero, it returns its size in bits. This is unlike
+/// `bsr`, which leaves its destination undefined for a zero operand and
+/// otherwise returns the index of the highest set bit. CPUs without `lzcnt`
+/// execute the instruction as `bsr`, so it must not be used without checking
+/// for the feature.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_lzcnt_u32)
 #[inline]
@@ -53,10 +57,17 @@ mod tests {
     #[simd_test(enable = "lzcnt")]
     unsafe fn test_lzcnt_u32() {
         assert_eq!(_lzcnt_u32(0b0101_1010), 25);
+        assert_eq!(_lzcnt_u32(0), 32);
+        assert_eq!(_lzcnt_u32(1), 31);
+        assert_eq!(_lzcnt_u32(1 << 31), 0);
+        assert_eq!(_lzcnt_u32(u32::MAX), 0);
     }
 
     #[simd_test(enable = "popcnt")]
     unsafe fn test_popcnt32() {
         assert_eq!(_popcnt32(0b0101_1010), 4);
+        assert_eq!(_popcnt32(0), 0);
+        assert_eq!(_popcnt32(-1), 32);
+        assert_eq!(_popcnt32(i32::MIN), 1);
     }
 }
@@ -0,0 +1,217 @@
+//! Position of the minimum and maximum element of a vector.
+//!
+//! Intel only defines such an intrinsic for unsigned 16-bit elements in a
+//! 128-bit vector, [`_mm_minpos_epu16`](fn._mm_minpos_epu16.html). These
+//! functions extend it to signed 32-bit elements in 256-bit and 512-bit
+//! vectors, and to the maximum. They are not single instructions: the index of
+//! each element is carried next to its value in a second vector, and a
+//! reduction tree compares each element with its partner and blends the
+//! winning value and index into both vectors.
+//!
+//! When several elements are equal to the minimum or maximum, the one with the
+//! lowest index is returned, like `_mm_minpos_epu16` does.
+//!
+//! These are not Intel intrinsics, and no other compiler provides them under
+//! these names. They are unstable under their own `stdarch_x86_argminmax`
+//! feature.
+
+use crate::core_arch::x86::*;
+
+/// Returns the minimum (or with `MAX`, the maximum) of the elements of `a` and
+/// the lowest index at which it occurs.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn argreduce_epi32_256<const MAX: bool>(a: __m256i) -> (i32, u32) {
+    let iota = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+    let (mut v, mut i) = (a, iota);
+    // Pair each element with the one 4, 2 and then 1 position away. Both
+    // elements of a pair get the same winner, so after the last step every
+    // element holds the result.
+    for distance in [4, 2, 1] {
+        let partner = _mm256_xor_si256(iota, _mm256_set1_epi32(distance));
+        let w = _mm256_permutevar8x32_epi32(v, partner);
+        let j = _mm256_permutevar8x32_epi32(i, partner);
+        let better = if MAX {
+            _mm256_cmpgt_epi32(w, v)
+        } else {
+            _mm256_cmpgt_epi32(v, w)
+        };
+        let tie = _mm256_and_si256(_mm256_cmpeq_epi32(v, w), _mm256_cmpgt_epi32(i, j));
+        let take = _mm256_or_si256(better, tie);
+        v = _mm256_blendv_epi8(v, w, take);
+        i = _mm256_blendv_epi8(i, j, take);
+    }
+    (_mm256_cvtsi256_si32(v), _mm256_cvtsi256_si32(i) as u32)
+}
+
+/// Returns the minimum (or with `MAX`, the maximum) of the elements of `a` and
+/// the lowest index at which it occurs.
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn argreduce_epi32_512<const MAX: bool>(a: __m512i) -> (i32, u32) {
+    let iota = _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let (mut v, mut i) = (a, iota);
+    // Same reduction as `argreduce_epi32_256`, with one more step.
+    for distance in [8, 4, 2, 1] {
+        let partner = _mm512_xor_si512(iota, _mm512_set1_epi32(distance));
+        let w = _mm512_permutexvar_epi32(partner, v);
+        let j = _mm512_permutexvar_epi32(partner, i);
+        let better = if MAX {
+            _mm512_cmpgt_epi32_mask(w, v)
+        } else {
+            _mm512_cmplt_epi32_mask(w, v)
+        };
+        let tie = _mm512_cmpeq_epi32_mask(v, w) & _mm512_cmplt_epi32_mask(j, i);
+        let take = better | tie;
+        v = _mm512_mask_blend_epi32(take, v, w);
+        i = _mm512_mask_blend_epi32(take, i, j);
+    }
+    (_mm512_cvtsi512_si32(v), _mm512_cvtsi512_si32(i) as u32)
+}
+
+/// Returns the minimum of the packed signed 32-bit integers in `a`, and the
+/// index of the element holding it.
+///
+/// If the minimum occurs more than once, the lowest index is returned.
+#[inline]
+#[target_feature(enable = "avx2")]
+#[unstable(feature = "stdarch_x86_argminmax", issue = "none")]
+pub unsafe fn _mm256_argmin_epi32(a: __m256i) -> (i32, u32) {
+    argreduce_epi32_256::<false>(a)
+}
+
+/// Returns the maximum of the packed signed 32-bit integers in `a`, and the
+/// index of the element holding it.
+///
+/// If the maximum occurs more than once, the lowest index is returned.
+#[inline]
+#[target_feature(enable = "avx2")]
+#[unstable(feature = "stdarch_x86_argminmax", issue = "none")]
+pub unsafe fn _mm256_argmax_epi32(a: __m256i) -> (i32, u32) {
+    argreduce_epi32_256::<true>(a)
+}
+
+/// Returns the minimum of the packed signed 32-bit integers in `a`, and the
+/// index of the element holding it.
+///
+/// If the minimum occurs more than once, the lowest index is returned.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[unstable(feature = "stdarch_x86_argminmax", issue = "none")]
+pub unsafe fn _mm512_argmin_epi32(a: __m512i) -> (i32, u32) {
+    argreduce_epi32_512::<false>(a)
+}
+
+/// Returns the maximum of the packed signed 32-bit integers in `a`, and the
+/// index of the element holding it.
+///
+/// If the maximum occurs more than once, the lowest index is returned.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[unstable(feature = "stdarch_x86_argminmax", issue = "none")]
+pub unsafe fn _mm512_argmax_epi32(a: __m512i) -> (i32, u32) {
+    argreduce_epi32_512::<true>(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::fuzz::Rng;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
+
+    use crate::core_arch::x86::*;
+
+    // Unlike `Iterator::max`, which returns the last maximum, both references
+    // keep the first element that compares equal.
+    fn argmin(a: &[i32]) -> (i32, u32) {
+        let mut r = (a[0], 0);
+        for (i, &x) in a.iter().enumerate() {
+            if x < r.0 {
+                r = (x, i as u32);
+            }
+        }
+        r
+    }
+
+    fn argmax(a: &[i32]) -> (i32, u32) {
+        let mut r = (a[0], 0);
+        for (i, &x) in a.iter().enumerate() {
+            if x > r.0 {
+                r = (x, i as u32);
+            }
+        }
+        r
+    }
+
+    // Few distinct values, so that most inputs have ties.
+    fn small<const N: usize>(rng: &mut Rng) -> [i32; N] {
+        rng.gen::<[u8; N]>().map(|x| (x % 4) as i32 - 2)
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn test_mm256_argmin_epi32() {
+        let a = _mm256_setr_epi32(5, 3, 8, -1, 7, -1, i32::MAX, 0);
+        assert_eq!(_mm256_argmin_epi32(a), (-1, 3));
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, i32::MIN);
+        assert_eq!(_mm256_argmin_epi32(a), (i32::MIN, 7));
+        assert_eq!(_mm256_argmin_epi32(_mm256_set1_epi32(4)), (4, 0));
+        fuzz_intrinsic!(
+            _mm256_argmin_epi32(a: [i32; 8]) -> (i32, u32),
+            |a| argmin(&a),
+        );
+        fuzz_intrinsic!(
+            _mm256_argmin_epi32(a: [i32; 8] = small::<8>) -> (i32, u32),
+            |a| argmin(&a),
+        );
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn test_mm256_argmax_epi32() {
+        let a = _mm256_setr_epi32(5, 3, 8, -1, 7, 8, i32::MIN, 0);
+        assert_eq!(_mm256_argmax_epi32(a), (8, 2));
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, i32::MAX);
+        assert_eq!(_mm256_argmax_epi32(a), (i32::MAX, 7));
+        assert_eq!(_mm256_argmax_epi32(_mm256_set1_epi32(-4)), (-4, 0));
+        fuzz_intrinsic!(
+            _mm256_argmax_epi32(a: [i32; 8]) -> (i32, u32),
+            |a| argmax(&a),
+        );
+        fuzz_intrinsic!(
+            _mm256_argmax_epi32(a: [i32; 8] = small::<8>) -> (i32, u32),
+            |a| argmax(&a),
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_argmin_epi32() {
+        let a = _mm512_setr_epi32(9, 9, 9, 9, 9, 9, 9, 9, 9, -3, 9, 9, 9, -3, 9, 9);
+        assert_eq!(_mm512_argmin_epi32(a), (-3, 9));
+        let a = _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, i32::MIN);
+        assert_eq!(_mm512_argmin_epi32(a), (i32::MIN, 15));
+        assert_eq!(_mm512_argmin_epi32(_mm512_set1_epi32(4)), (4, 0));
+        fuzz_intrinsic!(
+            _mm512_argmin_epi32(a: [i32; 16]) -> (i32, u32),
+            |a| argmin(&a),
+        );
+        fuzz_intrinsic!(
+            _mm512_argmin_epi32(a: [i32; 16] = small::<16>) -> (i32, u32),
+            |a| argmin(&a),
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_argmax_epi32() {
+        let a = _mm512_setr_epi32(1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 7, 1, 1, 7, 1);
+        assert_eq!(_mm512_argmax_epi32(a), (7, 11));
+        let a = _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, i32::MAX);
+        assert_eq!(_mm512_argmax_epi32(a), (i32::MAX, 15));
+        assert_eq!(_mm512_argmax_epi32(_mm512_set1_epi32(-4)), (-4, 0));
+        fuzz_intrinsic!(
+            _mm512_argmax_epi32(a: [i32; 16]) -> (i32, u32),
+            |a| argmax(&a),
+        );
+        fuzz_intrinsic!(
+            _mm512_argmax_epi32(a: [i32; 16] = small::<16>) -> (i32, u32),
+            |a| argmax(&a),
+        );
+    }
+}
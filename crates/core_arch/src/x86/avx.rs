@@ -1,5 +1,10 @@
 //! Advanced Vector Extensions (AVX)
 //!
+//! The masked loads and stores, `maskload` and `maskstore` here and their
+//! integer versions in AVX2, only access the elements selected by the mask.
+//! Masked-off elements are neither read nor written and can't fault, so they
+//! may lie past the end of an allocation.
+//!
 //! The references are:
 //!
 //! - [Intel 64 and IA-32 Architectures Software Developer's Manual Volume 2:
@@ -3107,7 +3112,7 @@ extern "C" {
 mod tests {
     use crate::hint::black_box;
     use crate::ptr;
-    use stdarch_test::simd_test;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     use crate::core_arch::x86::*;
 
@@ -4129,6 +4134,19 @@ mod tests {
         assert_eq_m256(r, e);
     }
 
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_maskload_maskstore_ps_short_buffer() {
+        // Only the active elements are accessed, so the buffer may be shorter
+        // than the vector.
+        let mut g = Guarded::<f32, 3, 5>::new([1., 2., 3.], 42.);
+        let mask = black_box(_mm256_setr_epi32(!0, !0, !0, 0, 0, 0, 0, 0));
+        let r = _mm256_maskload_ps(g.buf.as_ptr(), mask);
+        assert_eq_m256(r, _mm256_setr_ps(1., 2., 3., 0., 0., 0., 0., 0.));
+        _mm256_maskstore_ps(g.buf.as_mut_ptr(), mask, _mm256_set1_ps(-1.));
+        assert_eq!(g.buf, [-1.; 3]);
+        assert_eq!(g.guard, [42.; 5]);
+    }
+
     #[simd_test(enable = "avx")]
     unsafe fn test_mm_maskload_ps() {
         let a = &[1.0f32, 2., 3., 4.];
@@ -4970,4 +4988,32 @@ mod tests {
         let r = _mm256_cvtss_f32(a);
         assert_eq!(r, 1.);
     }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn fuzz_mm256_hadd_ps() {
+        fuzz_intrinsic!(
+            _mm256_hadd_ps(a: [f32; 8], b: [f32; 8]) -> [f32; 8],
+            |a, b| {
+                core::array::from_fn(|i| {
+                    let src = if i % 4 < 2 { a } else { b };
+                    let j = i / 4 * 4 + i % 2 * 2;
+                    src[j] + src[j + 1]
+                })
+            },
+        );
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn fuzz_mm256_hsub_pd() {
+        fuzz_intrinsic!(
+            _mm256_hsub_pd(a: [f64; 4], b: [f64; 4]) -> [f64; 4],
+            |a, b| {
+                core::array::from_fn(|i| {
+                    let src = if i % 2 == 0 { a } else { b };
+                    let j = i / 2 * 2;
+                    src[j] - src[j + 1]
+                })
+            },
+        );
+    }
 }
@@ -2061,10 +2061,14 @@ pub unsafe fn _mm256_movemask_epi8(a: __m256i) -> i32 {
 /// 8-bit integers in `a` compared to those in `b`, and stores the 16-bit
 /// results in dst. Eight SADs are performed for each 128-bit lane using one
 /// quadruplet from `b` and eight quadruplets from `a`. One quadruplet is
-/// selected from `b` starting at on the offset specified in `imm8`. Eight
+/// selected from `b` starting at the offset specified in `imm8`. Eight
 /// quadruplets are formed from sequential 8-bit integers selected from `a`
 /// starting at the offset specified in `imm8`.
 ///
+/// The offsets for the low 128-bit lane are taken from `IMM8[2:0]` and those
+/// for the high lane from `IMM8[5:3]`, each laid out as for
+/// `_mm_mpsadbw_epu8`.
+///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mpsadbw_epu8)
 #[inline]
 #[target_feature(enable = "avx2")]
@@ -3816,9 +3820,10 @@ extern "C" {
 #[cfg(test)]
 mod tests {
 
-    use stdarch_test::simd_test;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     use crate::core_arch::x86::*;
+    use crate::hint::black_box;
 
     #[simd_test(enable = "avx2")]
     unsafe fn test_mm256_abs_epi32() {
@@ -4381,6 +4386,15 @@ mod tests {
         );
         let r = _mm256_setr_epi32(0, 0, -1, 1, -2, 2, -3, 3);
         assert_eq_m256i(r, _mm256_cvtepi8_epi32(a));
+
+        // Bytes with the top bit set are sign-extended
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0x7f, -0x80, -1, 0x01, -0x7f, 0x40, -0x40, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        );
+        let r = _mm256_setr_epi32(0x7f, -0x80, -1, 0x01, -0x7f, 0x40, -0x40, 0);
+        assert_eq_m256i(r, _mm256_cvtepi8_epi32(a));
     }
 
     #[simd_test(enable = "avx2")]
@@ -4460,6 +4474,15 @@ mod tests {
         );
         let r = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
         assert_eq_m256i(r, _mm256_cvtepu8_epi32(a));
+
+        // Bytes with the top bit set are zero-extended
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0x7f, -0x80, -1, 0x01, -0x7f, 0x40, -0x40, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        );
+        let r = _mm256_setr_epi32(0x7f, 0x80, 0xff, 0x01, 0x81, 0x40, 0xc0, 0);
+        assert_eq_m256i(r, _mm256_cvtepu8_epi32(a));
     }
 
     #[simd_test(enable = "avx2")]
@@ -4650,6 +4673,19 @@ mod tests {
         assert_eq!(arr, e);
     }
 
+    #[simd_test(enable = "avx2")]
+    unsafe fn test_mm256_maskload_maskstore_epi64_short_buffer() {
+        // Only the active elements are accessed, so the buffer may be shorter
+        // than the vector.
+        let mut g = Guarded::<i64, 2, 2>::new([1, 2], 42);
+        let mask = black_box(_mm256_setr_epi64x(-1, -1, 0, 0));
+        let r = _mm256_maskload_epi64(g.buf.as_ptr(), mask);
+        assert_eq_m256i(r, _mm256_setr_epi64x(1, 2, 0, 0));
+        _mm256_maskstore_epi64(g.buf.as_mut_ptr(), mask, _mm256_set1_epi64x(-1));
+        assert_eq!(g.buf, [-1; 2]);
+        assert_eq!(g.guard, [42; 2]);
+    }
+
     #[simd_test(enable = "avx2")]
     unsafe fn test_mm256_max_epi16() {
         let a = _mm256_set1_epi16(2);
@@ -4763,6 +4799,39 @@ mod tests {
         assert_eq_m256i(r, e);
     }
 
+    #[simd_test(enable = "avx2")]
+    unsafe fn test_mm256_mpsadbw_epu8_every_offset() {
+        let a: [u8; 32] = core::array::from_fn(|i| (i * 97 + 13) as u8);
+        let b: [u8; 32] = core::array::from_fn(|i| (i * 61 + 200) as u8);
+        // Each 128-bit lane works like `_mm_mpsadbw_epu8`, with the offsets of
+        // the low lane in `IMM8[2:0]` and those of the high lane in
+        // `IMM8[5:3]`.
+        let reference = |imm8: usize| -> [u16; 16] {
+            core::array::from_fn(|k| {
+                let (lane, k) = (k / 8 * 16, k % 8);
+                let imm8 = imm8 >> (lane / 16 * 3);
+                let (i, j) = (lane + (imm8 >> 2 & 1) * 4, lane + (imm8 & 0b11) * 4);
+                (0..4).map(|n| a[i + k + n].abs_diff(b[j + n]) as u16).sum()
+            })
+        };
+        let va = _mm256_loadu_si256(a.as_ptr() as *const _);
+        let vb = _mm256_loadu_si256(b.as_ptr() as *const _);
+        macro_rules! check {
+            ($($imm8:literal)*) => {$(
+                let r: [u16; 16] = crate::mem::transmute(_mm256_mpsadbw_epu8::<$imm8>(va, vb));
+                assert_eq!(r, reference($imm8), "IMM8 = {:#o}", $imm8);
+            )*};
+        }
+        check!(0o00 0o01 0o02 0o03 0o04 0o05 0o06 0o07);
+        check!(0o10 0o11 0o12 0o13 0o14 0o15 0o16 0o17);
+        check!(0o20 0o21 0o22 0o23 0o24 0o25 0o26 0o27);
+        check!(0o30 0o31 0o32 0o33 0o34 0o35 0o36 0o37);
+        check!(0o40 0o41 0o42 0o43 0o44 0o45 0o46 0o47);
+        check!(0o50 0o51 0o52 0o53 0o54 0o55 0o56 0o57);
+        check!(0o60 0o61 0o62 0o63 0o64 0o65 0o66 0o67);
+        check!(0o70 0o71 0o72 0o73 0o74 0o75 0o76 0o77);
+    }
+
     #[simd_test(enable = "avx2")]
     unsafe fn test_mm256_mul_epi32() {
         let a = _mm256_setr_epi32(0, 0, 0, 0, 2, 2, 2, 2);
@@ -4895,6 +4964,16 @@ mod tests {
         assert_eq_m256i(r, e);
     }
 
+    #[simd_test(enable = "avx2")]
+    unsafe fn fuzz_mm256_sad_epu8() {
+        fuzz_intrinsic!(
+            _mm256_sad_epu8(a: [u8; 32], b: [u8; 32]) -> [u64; 4],
+            |a, b| core::array::from_fn(|i| {
+                (8 * i..8 * i + 8).map(|j| a[j].abs_diff(b[j]) as u64).sum()
+            }),
+        );
+    }
+
     #[simd_test(enable = "avx2")]
     unsafe fn test_mm256_shufflehi_epi16() {
         #[rustfmt::skip]
@@ -5779,4 +5858,123 @@ mod tests {
         assert_eq!(r1, 0xFFFF);
         assert_eq!(r2, 3);
     }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn test_mm256_insert_extract_epi8_epi16_round_trip() {
+        let a = _mm256_set1_epi8(0x5a);
+        macro_rules! check_epi8 {
+            ($($n:literal),*) => {$(
+                let r = _mm256_insert_epi8::<$n>(a, -$n - 1);
+                assert_eq!(_mm256_extract_epi8::<$n>(r), (-$n - 1) as u8 as i32);
+                let mask = _mm256_cmpeq_epi8(r, a);
+                assert_eq!(_mm256_movemask_epi8(mask), !(1 << $n));
+            )*};
+        }
+        check_epi8!(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31
+        );
+        macro_rules! check_epi16 {
+            ($($n:literal),*) => {$(
+                let r = _mm256_insert_epi16::<$n>(a, i16::MIN + $n);
+                assert_eq!(_mm256_extract_epi16::<$n>(r), (i16::MIN + $n) as u16 as i32);
+                let mask = _mm256_cmpeq_epi8(r, a);
+                assert_eq!(_mm256_movemask_epi8(mask), !(0b11 << (2 * $n)));
+            )*};
+        }
+        check_epi16!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn fuzz_mm256_add_epi32() {
+        fuzz_intrinsic!(
+            _mm256_add_epi32(a: [i32; 8], b: [i32; 8]) -> [i32; 8],
+            |a, b| core::array::from_fn(|i| a[i].wrapping_add(b[i])),
+        );
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn fuzz_mm256_mullo_epi32() {
+        fuzz_intrinsic!(
+            _mm256_mullo_epi32(a: [i32; 8], b: [i32; 8]) -> [i32; 8],
+            |a, b| core::array::from_fn(|i| a[i].wrapping_mul(b[i])),
+        );
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn fuzz_mm256_subs_epi8() {
+        fuzz_intrinsic!(
+            _mm256_subs_epi8(a: [i8; 32], b: [i8; 32]) -> [i8; 32],
+            |a, b| core::array::from_fn(|i| a[i].saturating_sub(b[i])),
+        );
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn fuzz_mm256_max_epu16() {
+        fuzz_intrinsic!(
+            _mm256_max_epu16(a: [u16; 16], b: [u16; 16]) -> [u16; 16],
+            |a, b| core::array::from_fn(|i| a[i].max(b[i])),
+        );
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn fuzz_mm256_abs_epi32() {
+        // `i32::MIN` has no positive counterpart and is returned unchanged.
+        fuzz_intrinsic!(
+            _mm256_abs_epi32(a: [i32; 8]) -> [i32; 8],
+            |a| a.map(i32::wrapping_abs),
+        );
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn fuzz_mm256_sllv_epi32() {
+        use stdarch_test::fuzz::Rng;
+        // Shift counts of 32 and more clear the lane.
+        fuzz_intrinsic!(
+            _mm256_sllv_epi32(
+                a: [i32; 8],
+                count: [u32; 8] = |rng: &mut Rng| rng.gen::<[u8; 8]>().map(|c| (c % 40) as u32),
+            ) -> [i32; 8],
+            |a, count| core::array::from_fn(|i| a[i].checked_shl(count[i]).unwrap_or(0)),
+        );
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn test_mm256_hadd_epi32_lane_pairing() {
+        // The 256-bit forms work on each 128-bit half separately: the low
+        // half of the result only sees the low halves of `a` and `b`.
+        let a = _mm256_setr_epi32(1, 2, 10, 20, 100, 200, 1000, 2000);
+        let b = _mm256_setr_epi32(3, 4, 30, 40, 300, 400, 3000, 4000);
+        let r = _mm256_hadd_epi32(a, b);
+        let e = _mm256_setr_epi32(3, 30, 7, 70, 300, 3000, 700, 7000);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn fuzz_mm256_hadd_epi16() {
+        fuzz_intrinsic!(
+            _mm256_hadd_epi16(a: [i16; 16], b: [i16; 16]) -> [i16; 16],
+            |a, b| {
+                core::array::from_fn(|i| {
+                    let src = if i % 8 < 4 { a } else { b };
+                    let j = i / 8 * 8 + i % 4 * 2;
+                    src[j].wrapping_add(src[j + 1])
+                })
+            },
+        );
+    }
+
+    #[simd_test(enable = "avx2")]
+    unsafe fn fuzz_mm256_hsubs_epi16() {
+        fuzz_intrinsic!(
+            _mm256_hsubs_epi16(a: [i16; 16], b: [i16; 16]) -> [i16; 16],
+            |a, b| {
+                core::array::from_fn(|i| {
+                    let src = if i % 8 < 4 { a } else { b };
+                    let j = i / 8 * 8 + i % 4 * 2;
+                    src[j].saturating_sub(src[j + 1])
+                })
+            },
+        );
+    }
 }
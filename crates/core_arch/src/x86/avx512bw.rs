@@ -9059,7 +9059,11 @@ pub unsafe fn _mm_maskz_shufflehi_epi16<const IMM8: i32>(k: __mmask8, a: __m128i
     transmute(simd_select_bitmask(k, shuffle.as_i16x8(), zero))
 }
 
-/// Shuffle packed 8-bit integers in a according to shuffle control mask in the corresponding 8-bit element of b, and store the results in dst.
+/// Shuffle 8-bit integers in a within 128-bit lanes using the control in the corresponding 8-bit element of b, and store the results in dst.
+///
+/// Each byte of b selects a byte from the same 128-bit lane of a with its low
+/// four bits, or zeroes the result if its high bit is set. Bytes never move
+/// between lanes.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_shuffle_epi8&expand=5159)
 #[inline]
@@ -9920,7 +9924,7 @@ pub unsafe fn _mm_movm_epi8(k: __mmask16) -> __m128i {
 
 /// Convert 32-bit mask a into an integer value, and store the result in dst.
 ///
-/// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#_cvtmask32_u32)
+/// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_cvtmask32_u32)
 #[inline]
 #[target_feature(enable = "avx512bw")]
 #[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
@@ -11652,11 +11656,12 @@ extern "C" {
 #[cfg(test)]
 mod tests {
 
-    use stdarch_test::simd_test;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     use crate::core_arch::x86::*;
     use crate::hint::black_box;
     use crate::mem::{self};
+    use crate::ptr;
 
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_abs_epi16() {
@@ -11818,6 +11823,37 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_abs_epi8_epi16_min_wraps() {
+        // The absolute value of the minimum wraps around to the minimum.
+        let r = _mm512_abs_epi8(_mm512_set1_epi8(i8::MIN));
+        assert_eq_m512i(r, _mm512_set1_epi8(i8::MIN));
+        let r = _mm512_mask_abs_epi8(
+            _mm512_set1_epi8(3),
+            0x5555_5555_5555_5555,
+            _mm512_set1_epi8(i8::MIN),
+        );
+        let r: [i8; 64] = transmute(r);
+        let e: [i8; 64] = core::array::from_fn(|i| if i % 2 == 0 { i8::MIN } else { 3 });
+        assert_eq!(r, e);
+        let r = _mm512_abs_epi16(_mm512_set1_epi16(i16::MIN));
+        assert_eq_m512i(r, _mm512_set1_epi16(i16::MIN));
+        let r = _mm512_maskz_abs_epi16(0xffff_0000, _mm512_set1_epi16(i16::MIN));
+        let r: [i16; 32] = transmute(r);
+        let e: [i16; 32] = core::array::from_fn(|i| if i >= 16 { i16::MIN } else { 0 });
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn fuzz_mm512_mask_abs_epi8() {
+        fuzz_intrinsic!(
+            _mm512_mask_abs_epi8(src: [i8; 64], k: u64, a: [i8; 64]) -> [i8; 64],
+            |src, k, a| {
+                core::array::from_fn(|i| if k >> i & 1 != 0 { a[i].wrapping_abs() } else { src[i] })
+            },
+        );
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_add_epi16() {
         let a = _mm512_set1_epi16(1);
@@ -16316,6 +16352,33 @@ mod tests {
         assert_eq_m512i(_mm512_loadu_epi8(r.as_ptr()), e);
     }
 
+    #[simd_test(enable = "avx512f,avx512bw")]
+    unsafe fn test_mm512_mask_loadu_storeu_epi8_short_buffer() {
+        // Only the active lanes are accessed, so the buffer may be shorter
+        // than the vector.
+        let mut g = Guarded::<i8, 5, 59>::new([1, 2, 3, 4, 5], 42);
+        let k = black_box(0b11111);
+        let r = _mm512_maskz_loadu_epi8(k, g.buf.as_ptr());
+        let mut e = [0_i8; 64];
+        e[..5].copy_from_slice(&g.buf);
+        assert_eq_m512i(r, _mm512_loadu_epi8(e.as_ptr()));
+        _mm512_mask_storeu_epi8(g.buf.as_mut_ptr(), k, _mm512_set1_epi8(-1));
+        assert_eq!(g.buf, [-1; 5]);
+        assert_eq!(g.guard, [42; 59]);
+    }
+
+    #[simd_test(enable = "avx512f,avx512bw")]
+    unsafe fn test_mm512_mask_loadu_storeu_epi16_empty_mask() {
+        // Faults are suppressed for masked-off lanes, so with an empty mask
+        // even a null pointer isn't accessed.
+        let p = black_box(ptr::null_mut::<i16>());
+        let k = black_box(0);
+        let src = _mm512_set1_epi16(42);
+        assert_eq_m512i(_mm512_mask_loadu_epi16(src, k, p), src);
+        assert_eq_m512i(_mm512_maskz_loadu_epi16(k, p), _mm512_setzero_si512());
+        _mm512_mask_storeu_epi16(p, k, src);
+    }
+
     #[simd_test(enable = "avx512f,avx512bw,avx512vl")]
     unsafe fn test_mm256_mask_loadu_epi16() {
         let src = _mm256_set1_epi16(42);
@@ -16599,10 +16662,10 @@ mod tests {
         let src = _mm512_set1_epi16(1);
         let r = _mm512_mask_maddubs_epi16(src, 0, a, b);
         assert_eq_m512i(r, src);
-        let r = _mm512_mask_add_epi16(src, 0b00000000_00000000_00000000_00000001, a, b);
+        let r = _mm512_mask_maddubs_epi16(src, 0b00000000_00000000_00000000_00000001, a, b);
         #[rustfmt::skip]
         let e = _mm512_set_epi16(1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-                                 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1<<9|2);
+                                 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2);
         assert_eq_m512i(r, e);
     }
 
@@ -16619,6 +16682,45 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maddubs_epi16_saturation() {
+        // Each product fits in 16 bits, but their sum may not.
+        let a = _mm512_set1_epi8(-1); // 255 as the unsigned operand
+        let r = _mm512_maddubs_epi16(a, _mm512_set1_epi8(127));
+        assert_eq_m512i(r, _mm512_set1_epi16(i16::MAX));
+        let r = _mm512_maddubs_epi16(a, _mm512_set1_epi8(-128));
+        assert_eq_m512i(r, _mm512_set1_epi16(i16::MIN));
+        // 255 * 127 - 255 * 128 doesn't saturate.
+        let b = _mm512_set1_epi16(0x807f_u16 as i16);
+        let r = _mm512_maddubs_epi16(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi16(-255));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn fuzz_mm512_madd_epi16() {
+        fuzz_intrinsic!(
+            _mm512_madd_epi16(a: [i16; 32], b: [i16; 32]) -> [i32; 16],
+            |a, b| {
+                let p = |i: usize| a[i] as i32 * b[i] as i32;
+                // Only -2^15 * -2^15 * 2 overflows, and wraps around.
+                core::array::from_fn(|i| p(2 * i).wrapping_add(p(2 * i + 1)))
+            },
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn fuzz_mm512_maddubs_epi16() {
+        fuzz_intrinsic!(
+            _mm512_maddubs_epi16(a: [u8; 64], b: [i8; 64]) -> [i16; 32],
+            |a, b| {
+                let p = |i: usize| a[i] as i32 * b[i] as i32;
+                core::array::from_fn(|i| {
+                    (p(2 * i) + p(2 * i + 1)).clamp(i16::MIN as i32, i16::MAX as i32) as i16
+                })
+            },
+        );
+    }
+
     #[simd_test(enable = "avx512bw,avx512vl")]
     unsafe fn test_mm256_mask_maddubs_epi16() {
         let a = _mm256_set1_epi8(1);
@@ -18262,6 +18364,43 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mask_blend_epi8_epi16_selects_per_bit() {
+        // Lane i comes from b if bit i of k is set and from a otherwise.
+        macro_rules! check {
+            ($($blend:ident: [$t:ty; $n:literal], $k:ty;)*) => {$(
+                let a: [$t; $n] = core::array::from_fn(|i| i as $t + 1);
+                let b: [$t; $n] = core::array::from_fn(|i| -(i as $t) - 1);
+                #[rustfmt::skip]
+                let masks = [
+                    0, !0, 0x5555_5555_5555_5555, 0xaaaa_aaaa_aaaa_aaaa, 1, 1 << ($n - 1),
+                    0x9abc_def0_1234_5678,
+                ];
+                for k in masks.map(|k: u64| k as $k) {
+                    let r: [$t; $n] = transmute($blend(k, transmute(a), transmute(b)));
+                    let e = core::array::from_fn(|i| if k >> i & 1 != 0 { b[i] } else { a[i] });
+                    assert_eq!(r, e, "{}({:#x})", stringify!($blend), k);
+                }
+            )*};
+        }
+        check! {
+            _mm512_mask_blend_epi8: [i8; 64], __mmask64;
+            _mm256_mask_blend_epi8: [i8; 32], __mmask32;
+            _mm_mask_blend_epi8: [i8; 16], __mmask16;
+            _mm512_mask_blend_epi16: [i16; 32], __mmask32;
+            _mm256_mask_blend_epi16: [i16; 16], __mmask16;
+            _mm_mask_blend_epi16: [i16; 8], __mmask8;
+        }
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn fuzz_mm512_mask_blend_epi8() {
+        fuzz_intrinsic!(
+            _mm512_mask_blend_epi8(k: u64, a: [i8; 64], b: [i8; 64]) -> [i8; 64],
+            |k, a, b| core::array::from_fn(|i| if k >> i & 1 != 0 { b[i] } else { a[i] }),
+        );
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_broadcastw_epi16() {
         let a = _mm_set_epi16(17, 18, 19, 20, 21, 22, 23, 24);
@@ -18776,6 +18915,53 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn fuzz_mm512_unpack_epi8_epi16() {
+        // Each 128-bit lane interleaves the low or high half of the same lane
+        // of `a` and `b`; nothing crosses a lane boundary.
+        fuzz_intrinsic!(
+            _mm512_unpacklo_epi8(a: [i8; 64], b: [i8; 64]) -> [i8; 64],
+            |a, b| core::array::from_fn(|i| {
+                let j = i / 16 * 16 + i % 16 / 2;
+                if i % 2 == 0 { a[j] } else { b[j] }
+            }),
+        );
+        fuzz_intrinsic!(
+            _mm512_unpackhi_epi8(a: [i8; 64], b: [i8; 64]) -> [i8; 64],
+            |a, b| core::array::from_fn(|i| {
+                let j = i / 16 * 16 + 8 + i % 16 / 2;
+                if i % 2 == 0 { a[j] } else { b[j] }
+            }),
+        );
+        fuzz_intrinsic!(
+            _mm512_unpacklo_epi16(a: [i16; 32], b: [i16; 32]) -> [i16; 32],
+            |a, b| core::array::from_fn(|i| {
+                let j = i / 8 * 8 + i % 8 / 2;
+                if i % 2 == 0 { a[j] } else { b[j] }
+            }),
+        );
+        fuzz_intrinsic!(
+            _mm512_unpackhi_epi16(a: [i16; 32], b: [i16; 32]) -> [i16; 32],
+            |a, b| core::array::from_fn(|i| {
+                let j = i / 8 * 8 + 4 + i % 8 / 2;
+                if i % 2 == 0 { a[j] } else { b[j] }
+            }),
+        );
+        fuzz_intrinsic!(
+            _mm512_mask_unpacklo_epi8(src: [i8; 64], k: u64, a: [i8; 64], b: [i8; 64]) -> [i8; 64],
+            |src, k, a, b| core::array::from_fn(|i| {
+                let j = i / 16 * 16 + i % 16 / 2;
+                if k >> i & 1 == 0 {
+                    src[i]
+                } else if i % 2 == 0 {
+                    a[j]
+                } else {
+                    b[j]
+                }
+            }),
+        );
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_mask_unpacklo_epi8() {
         #[rustfmt::skip]
@@ -19360,6 +19546,17 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn fuzz_mm512_shuffle_epi8() {
+        fuzz_intrinsic!(
+            _mm512_shuffle_epi8(a: [i8; 64], b: [i8; 64]) -> [i8; 64],
+            |a, b| core::array::from_fn(|i| {
+                // Only the same 128-bit lane of `a` is ever read.
+                if b[i] < 0 { 0 } else { a[i / 16 * 16 + (b[i] & 15) as usize] }
+            }),
+        );
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_mask_shuffle_epi8() {
         #[rustfmt::skip]
@@ -19760,6 +19957,16 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn fuzz_mm512_sad_epu8() {
+        fuzz_intrinsic!(
+            _mm512_sad_epu8(a: [u8; 64], b: [u8; 64]) -> [u64; 8],
+            |a, b| core::array::from_fn(|i| {
+                (8 * i..8 * i + 8).map(|j| a[j].abs_diff(b[j]) as u64).sum()
+            }),
+        );
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_dbsad_epu8() {
         let a = _mm512_set1_epi8(2);
@@ -19856,6 +20063,74 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    /// Scalar double-block SAD: each 128-bit lane of `b` is first shuffled by
+    /// dwords according to `imm8`, then each 64-bit lane yields four SADs.
+    /// The first two use the low quadruplet of `a` and the last two the high
+    /// one, against the shuffled `b` at byte offsets 0, 1, 2 and 3.
+    fn dbsad_epu8_reference<const N: usize>(a: &[u8], b: &[u8], imm8: usize) -> [u16; N] {
+        let tmp = |t: usize| b[t / 16 * 16 + (imm8 >> (t % 16 / 4 * 2) & 3) * 4 + t % 4];
+        core::array::from_fn(|k| {
+            let (lane, k) = (k / 4 * 8, k % 4);
+            (0..4)
+                .map(|n| a[lane + k / 2 * 4 + n].abs_diff(tmp(lane + k + n)) as u16)
+                .sum()
+        })
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_dbsad_epu8_every_selection() {
+        let a: [u8; 64] = core::array::from_fn(|i| (i * 97 + 13) as u8);
+        let b: [u8; 64] = core::array::from_fn(|i| (i * 61 + 200) as u8);
+        let a512 = _mm512_loadu_si512(a.as_ptr() as *const _);
+        let b512 = _mm512_loadu_si512(b.as_ptr() as *const _);
+        let a256 = _mm256_loadu_si256(a.as_ptr() as *const _);
+        let b256 = _mm256_loadu_si256(b.as_ptr() as *const _);
+        let a128 = _mm_loadu_si128(a.as_ptr() as *const _);
+        let b128 = _mm_loadu_si128(b.as_ptr() as *const _);
+        // 0x00, 0x55, 0xaa and 0xff pick every dword of `b` for every slot;
+        // the others check that the four selectors are independent.
+        macro_rules! check {
+            ($($imm8:literal)*) => {$(
+                let r: [u16; 32] = transmute(_mm512_dbsad_epu8::<$imm8>(a512, b512));
+                assert_eq!(r, dbsad_epu8_reference(&a, &b, $imm8), "IMM8 = {:#x}", $imm8);
+                let r: [u16; 16] = transmute(_mm256_dbsad_epu8::<$imm8>(a256, b256));
+                assert_eq!(r, dbsad_epu8_reference(&a, &b, $imm8), "IMM8 = {:#x}", $imm8);
+                let r: [u16; 8] = transmute(_mm_dbsad_epu8::<$imm8>(a128, b128));
+                assert_eq!(r, dbsad_epu8_reference(&a, &b, $imm8), "IMM8 = {:#x}", $imm8);
+            )*};
+        }
+        check!(0x00 0x55 0xaa 0xff 0xe4 0x1b 0x4e 0xb1 0x8d);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn fuzz_mm512_dbsad_epu8() {
+        let dbsad = |a: __m512i, b: __m512i| _mm512_dbsad_epu8::<0b10_00_11_01>(a, b);
+        fuzz_intrinsic!(
+            dbsad(a: [u8; 64], b: [u8; 64]) -> [u16; 32],
+            |a, b| dbsad_epu8_reference(&a, &b, 0b10_00_11_01),
+        );
+        let mask_dbsad = |src: __m512i, k: __mmask32, a: __m512i, b: __m512i| {
+            _mm512_mask_dbsad_epu8::<0b10_00_11_01>(src, k, a, b)
+        };
+        fuzz_intrinsic!(
+            mask_dbsad(src: [u16; 32], k: u32, a: [u8; 64], b: [u8; 64]) -> [u16; 32],
+            |src, k, a, b| {
+                let r: [u16; 32] = dbsad_epu8_reference(&a, &b, 0b10_00_11_01);
+                core::array::from_fn(|i| if k >> i & 1 == 0 { src[i] } else { r[i] })
+            },
+        );
+        let maskz_dbsad = |k: __mmask32, a: __m512i, b: __m512i| {
+            _mm512_maskz_dbsad_epu8::<0b10_00_11_01>(k, a, b)
+        };
+        fuzz_intrinsic!(
+            maskz_dbsad(k: u32, a: [u8; 64], b: [u8; 64]) -> [u16; 32],
+            |k, a, b| {
+                let r: [u16; 32] = dbsad_epu8_reference(&a, &b, 0b10_00_11_01);
+                core::array::from_fn(|i| if k >> i & 1 == 0 { 0 } else { r[i] })
+            },
+        );
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_movepi16_mask() {
         let a = _mm512_set1_epi16(1 << 15);
@@ -20024,6 +20299,21 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtmask32_round_trip() {
+        let ones = _mm512_set1_epi16(-1);
+        let patterns = (0..32)
+            .map(|i| 1 << i)
+            .chain((0..64).map(|i: u32| i.wrapping_mul(0x9e37_79b9)))
+            .chain([0, u32::MAX, 0x5555_5555, 0xaaaa_aaaa]);
+        for k in patterns {
+            assert_eq!(_cvtu32_mask32(_cvtmask32_u32(k)), k);
+            // The integer selects the same elements as the mask.
+            let v = _mm512_maskz_mov_epi16(_cvtu32_mask32(k), ones);
+            assert_eq!(_cvtmask32_u32(_mm512_movepi16_mask(v)), k);
+        }
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_kadd_mask32() {
         let a: __mmask32 = 11;
@@ -20583,6 +20873,42 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cvt_narrow_epi16_saturation_boundaries() {
+        #[rustfmt::skip]
+        let a: [i16; 32] = [
+            i16::MIN, -32767, -257, -256, -255, -129, -128, -127,
+            -2, -1, 0, 1, 2, 126, 127, 128,
+            129, 200, 254, 255, 256, 257, 511, 0x1234,
+            -0x1234, 0x7f80, 0x0180, -0x0180, 1000, -1000, 32766, i16::MAX,
+        ];
+        let r: [i8; 32] = transmute(_mm512_cvtepi16_epi8(transmute(a)));
+        assert_eq!(r, a.map(|x| x as i8));
+        let r: [i8; 32] = transmute(_mm512_cvtsepi16_epi8(transmute(a)));
+        assert_eq!(r, a.map(|x| x.clamp(-128, 127) as i8));
+        // The unsigned form treats the source as unsigned, so negative
+        // values saturate to 255.
+        let r: [u8; 32] = transmute(_mm512_cvtusepi16_epi8(transmute(a)));
+        assert_eq!(r, a.map(|x| (x as u16).min(255) as u8));
+
+        let mut r = [0x55_u8; 34];
+        let k = 0xf0f0_3c3c;
+        _mm512_mask_cvtusepi16_storeu_epi8(r.as_mut_ptr().add(1) as *mut i8, k, transmute(a));
+        let e: [u8; 34] = core::array::from_fn(|i| match i {
+            1..=32 if k >> (i - 1) & 1 != 0 => (a[i - 1] as u16).min(255) as u8,
+            _ => 0x55,
+        });
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn fuzz_mm512_cvtsepi16_epi8() {
+        fuzz_intrinsic!(
+            _mm512_cvtsepi16_epi8(a: [i16; 32]) -> [i8; 32],
+            |a| a.map(|x| x.clamp(-128, 127) as i8),
+        );
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_cvtepi8_epi16() {
         let a = _mm256_set1_epi8(2);
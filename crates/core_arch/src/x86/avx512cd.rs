@@ -658,6 +658,32 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_conflict_epi32_shared_indices() {
+        // Histogram-style bucket indices, with several lanes sharing an index.
+        let idx: [i32; 16] = [3, 7, 3, 0, 7, 7, 1, 3, 9, 0, 2, 2, 5, 3, 8, 7];
+        let r = _mm512_conflict_epi32(_mm512_loadu_epi32(idx.as_ptr()));
+        let mut r_arr = [0_i32; 16];
+        _mm512_storeu_epi32(r_arr.as_mut_ptr(), r);
+        for i in 0..16 {
+            // Bit j is set for every earlier lane j that holds the same value.
+            let mut e = 0;
+            for j in 0..i {
+                if idx[j] == idx[i] {
+                    e |= 1 << j;
+                }
+            }
+            assert_eq!(r_arr[i], e, "lane {i}");
+        }
+        // Lane 13 is the fourth occurrence of index 3, after lanes 0, 2 and 7.
+        assert_eq!(r_arr[13], 1 << 0 | 1 << 2 | 1 << 7);
+        // Lanes without an earlier duplicate are zero.
+        assert_eq!(
+            _mm512_cmpeq_epi32_mask(r, _mm512_setzero_si512()),
+            0b0101_0101_0100_1011
+        );
+    }
+
     #[simd_test(enable = "avx512cd")]
     unsafe fn test_mm512_mask_conflict_epi32() {
         let a = _mm512_set1_epi32(1);
@@ -6152,9 +6152,8 @@ pub unsafe fn _mm_maskz_reduce_round_sd<const IMM8: i32, const SAE: i32>(
 }
 
 /// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b
-/// by the number of bits specified by imm8, store the result in the lower element of dst using, and
-/// copy the upper element from a.
-/// to the upper element of dst.
+/// by the number of bits specified by imm8, store the result in the lower element of dst, and
+/// copy the upper element from a to the upper element of dst.
 /// Rounding is done according to the imm8 parameter, which can be one of:
 ///
 ///     - _MM_FROUND_TO_NEAREST_INT // round to nearest
@@ -6234,8 +6233,7 @@ pub unsafe fn _mm_maskz_reduce_sd<const IMM8: i32>(k: __mmask8, a: __m128d, b: _
 
 /// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b
 /// by the number of bits specified by imm8, store the result in the lower element of dst, and copy
-/// the upper element from a.
-/// to the upper element of dst.
+/// the upper 3 packed elements from a to the upper elements of dst.
 /// Rounding is done according to the imm8 parameter, which can be one of:
 ///
 ///     - _MM_FROUND_TO_NEAREST_INT // round to nearest
@@ -6260,8 +6258,8 @@ pub unsafe fn _mm_reduce_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, b:
 
 /// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b
 /// by the number of bits specified by imm8, store the result in the lower element of dst using writemask
-/// k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a.
-/// to the upper element of dst.
+/// k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed
+/// elements from a to the upper elements of dst.
 /// Rounding is done according to the imm8 parameter, which can be one of:
 ///
 ///     - _MM_FROUND_TO_NEAREST_INT // round to nearest
@@ -6298,8 +6296,8 @@ pub unsafe fn _mm_mask_reduce_round_ss<const IMM8: i32, const SAE: i32>(
 
 /// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b
 /// by the number of bits specified by imm8, store the result in the lower element of dst using zeromask
-/// k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a.
-/// to the upper element of dst.
+/// k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed
+/// elements from a to the upper elements of dst.
 /// Rounding is done according to the imm8 parameter, which can be one of:
 ///
 ///     - _MM_FROUND_TO_NEAREST_INT // round to nearest
@@ -6328,8 +6326,7 @@ pub unsafe fn _mm_maskz_reduce_round_ss<const IMM8: i32, const SAE: i32>(
 
 /// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b
 /// by the number of bits specified by imm8, store the result in the lower element of dst, and copy
-/// the upper element from a.
-/// to the upper element of dst.
+/// the upper 3 packed elements from a to the upper elements of dst.
 /// Rounding is done according to the imm8 parameter, which can be one of:
 ///
 ///     - _MM_FROUND_TO_NEAREST_INT // round to nearest
@@ -6351,8 +6348,8 @@ pub unsafe fn _mm_reduce_ss<const IMM8: i32>(a: __m128, b: __m128) -> __m128 {
 
 /// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b
 /// by the number of bits specified by imm8, store the result in the lower element of dst using writemask
-/// k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a.
-/// to the upper element of dst.
+/// k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed
+/// elements from a to the upper elements of dst.
 /// Rounding is done according to the imm8 parameter, which can be one of:
 ///
 ///     - _MM_FROUND_TO_NEAREST_INT // round to nearest
@@ -6386,8 +6383,8 @@ pub unsafe fn _mm_mask_reduce_ss<const IMM8: i32>(
 
 /// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b
 /// by the number of bits specified by imm8, store the result in the lower element of dst using zeromask
-/// k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a.
-/// to the upper element of dst.
+/// k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed
+/// elements from a to the upper elements of dst.
 /// Rounding is done according to the imm8 parameter, which can be one of:
 ///
 ///     - _MM_FROUND_TO_NEAREST_INT // round to nearest
@@ -6419,7 +6416,7 @@ pub unsafe fn _mm_maskz_reduce_ss<const IMM8: i32>(k: __mmask8, a: __m128, b: __
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_fpclass_pd_mask&ig_expand=3493)
@@ -6444,7 +6441,7 @@ pub unsafe fn _mm_fpclass_pd_mask<const IMM8: i32>(a: __m128d) -> __mmask8 {
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_fpclass_pd_mask&ig_expand=3494)
@@ -6468,7 +6465,7 @@ pub unsafe fn _mm_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_fpclass_pd_mask&ig_expand=3495)
@@ -6493,7 +6490,7 @@ pub unsafe fn _mm256_fpclass_pd_mask<const IMM8: i32>(a: __m256d) -> __mmask8 {
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_fpclass_pd_mask&ig_expand=3496)
@@ -6517,7 +6514,7 @@ pub unsafe fn _mm256_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m2
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_fpclass_pd_mask&ig_expand=3497)
@@ -6542,7 +6539,7 @@ pub unsafe fn _mm512_fpclass_pd_mask<const IMM8: i32>(a: __m512d) -> __mmask8 {
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_fpclass_pd_mask&ig_expand=3498)
@@ -6566,7 +6563,7 @@ pub unsafe fn _mm512_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m5
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_fpclass_ps_mask&ig_expand=3505)
@@ -6591,7 +6588,7 @@ pub unsafe fn _mm_fpclass_ps_mask<const IMM8: i32>(a: __m128) -> __mmask8 {
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_fpclass_ps_mask&ig_expand=3506)
@@ -6615,7 +6612,7 @@ pub unsafe fn _mm_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m128)
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_fpclass_ps_mask&ig_expand=3507)
@@ -6640,7 +6637,7 @@ pub unsafe fn _mm256_fpclass_ps_mask<const IMM8: i32>(a: __m256) -> __mmask8 {
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_fpclass_ps_mask&ig_expand=3508)
@@ -6664,7 +6661,7 @@ pub unsafe fn _mm256_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m2
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_fpclass_ps_mask&ig_expand=3509)
@@ -6689,7 +6686,7 @@ pub unsafe fn _mm512_fpclass_ps_mask<const IMM8: i32>(a: __m512) -> __mmask16 {
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_fpclass_ps_mask&ig_expand=3510)
@@ -6713,7 +6710,7 @@ pub unsafe fn _mm512_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask16, a: __m
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_fpclass_sd_mask&ig_expand=3511)
@@ -6738,7 +6735,7 @@ pub unsafe fn _mm_fpclass_sd_mask<const IMM8: i32>(a: __m128d) -> __mmask8 {
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_fpclass_sd_mask&ig_expand=3512)
@@ -6762,7 +6759,7 @@ pub unsafe fn _mm_mask_fpclass_sd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_fpclass_ss_mask&ig_expand=3515)
@@ -6787,7 +6784,7 @@ pub unsafe fn _mm_fpclass_ss_mask<const IMM8: i32>(a: __m128) -> __mmask8 {
 ///     - 0x08 // Positive Infinity
 ///     - 0x10 // Negative Infinity
 ///     - 0x20 // Denormal
-///     - 0x40 // Negative
+///     - 0x40 // Negative Finite
 ///     - 0x80 // SNaN
 ///
 /// [Intel's Documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_fpclass_ss_mask&ig_expand=3516)
@@ -8167,6 +8164,45 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_insert_extract_x8_x2_round_trip() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm256_setr_epi32(9, 10, 11, 12, 13, 14, 15, 16);
+        let c = _mm_setr_epi64x(17, 18);
+        macro_rules! check_32x8 {
+            ($($i:literal),*) => {$(
+                let r = _mm512_inserti32x8::<$i>(a, b);
+                assert_eq_m256i(_mm512_extracti32x8_epi32::<$i>(r), b);
+                assert_eq_m256i(
+                    _mm512_extracti32x8_epi32::<{ 1 - $i }>(r),
+                    _mm512_extracti32x8_epi32::<{ 1 - $i }>(a),
+                );
+                let (af, bf) = (_mm512_castsi512_ps(a), _mm256_castsi256_ps(b));
+                let r = _mm512_insertf32x8::<$i>(af, bf);
+                assert_eq_m256(_mm512_extractf32x8_ps::<$i>(r), bf);
+            )*};
+        }
+        check_32x8!(0, 1);
+
+        macro_rules! check_64x2 {
+            ($($i:literal),*) => {$(
+                // Only the selected 128-bit sub-vector is replaced.
+                let r = _mm512_inserti64x2::<$i>(a, c);
+                assert_eq_m128i(_mm512_extracti64x2_epi64::<$i>(r), c);
+                let r: [i64; 8] = transmute(r);
+                let e: [i64; 8] = transmute(a);
+                let e: [i64; 8] =
+                    core::array::from_fn(|j| if j / 2 == $i { 17 + j as i64 % 2 } else { e[j] });
+                assert_eq!(r, e, "_mm512_inserti64x2::<{}>", $i);
+
+                let (af, cf) = (_mm512_castsi512_pd(a), _mm_castsi128_pd(c));
+                let r = _mm512_insertf64x2::<$i>(af, cf);
+                assert_eq_m128d(_mm512_extractf64x2_pd::<$i>(r), cf);
+            )*};
+        }
+        check_64x2!(0, 1, 2, 3);
+    }
+
     #[simd_test(enable = "avx512dq")]
     unsafe fn test_mm512_cvt_roundepi64_pd() {
         let a = _mm512_set_epi64(1, 2, 3, 4, 5, 6, 7, 8);
@@ -9499,6 +9535,21 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_cvtmask8_round_trip() {
+        let ones = _mm512_set1_epi64(-1);
+        for k in 0..=u8::MAX {
+            assert_eq!(_cvtu32_mask8(_cvtmask8_u32(k)), k);
+            // Converting to an integer zero-extends the mask.
+            assert_eq!(_cvtmask8_u32(k), k as u32);
+            // The integer selects the same elements as the mask.
+            let v = _mm512_maskz_mov_epi64(_cvtu32_mask8(k as u32), ones);
+            assert_eq!(_cvtmask8_u32(_mm512_movepi64_mask(v)), k as u32);
+        }
+        // Converting to a mask keeps the low 8 bits.
+        assert_eq!(_cvtu32_mask8(0xabcd_1234), 0x34);
+    }
+
     #[simd_test(enable = "avx512dq")]
     unsafe fn test_kadd_mask16() {
         let a: __mmask16 = 27549;
@@ -10044,6 +10095,92 @@ mod tests {
         assert_eq_m512(r, e);
     }
 
+    // The result of `vrangeps` and `vrangepd` for one element. `IMM8[1:0]`
+    // selects min, max, absolute min or absolute max, and `IMM8[3:2]` selects
+    // the sign of the result.
+    fn range_reference(imm8: i32, a: f64, b: f64) -> f64 {
+        // -0.0 is smaller than 0.0 here, unlike for `f64::min` and `f64::max`.
+        let min = |a: f64, b: f64| {
+            if a < b || (a == b && a.is_sign_negative()) {
+                a
+            } else {
+                b
+            }
+        };
+        let max = |a: f64, b: f64| {
+            if a > b || (a == b && b.is_sign_negative()) {
+                a
+            } else {
+                b
+            }
+        };
+        let r = match imm8 & 0b11 {
+            0b00 => min(a, b),
+            0b01 => max(a, b),
+            0b10 if a.abs() < b.abs() => a,
+            0b10 if b.abs() < a.abs() => b,
+            // Elements of the same magnitude are ordered like for min and max.
+            0b10 => min(a, b),
+            0b11 if a.abs() > b.abs() => a,
+            0b11 if b.abs() > a.abs() => b,
+            _ => max(a, b),
+        };
+        match imm8 >> 2 {
+            0b00 => r.copysign(a),
+            0b01 => r,
+            0b10 => r.abs(),
+            _ => -r.abs(),
+        }
+    }
+
+    #[rustfmt::skip]
+    const RANGE_INPUTS: [(f64, f64); 16] = [
+        (1., 2.), (2., 1.), (-1., 2.), (2., -1.),
+        (-3., -1.), (1., -3.), (2., -2.), (-2., 2.),
+        (0., -0.), (-0., 0.), (0., 0.), (-0., -0.),
+        (-5., -5.), (f64::INFINITY, -1.), (f64::NEG_INFINITY, 1.), (0.5, -0.25),
+    ];
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_ps_all_controls() {
+        let a: [f32; 16] = core::array::from_fn(|i| RANGE_INPUTS[i].0 as f32);
+        let b: [f32; 16] = core::array::from_fn(|i| RANGE_INPUTS[i].1 as f32);
+        let (va, vb) = (_mm512_loadu_ps(a.as_ptr()), _mm512_loadu_ps(b.as_ptr()));
+        let (sa, sb) = (_mm512_castps512_ps128(va), _mm512_castps512_ps128(vb));
+        macro_rules! check {
+            ($($imm8:literal)*) => {$(
+                let e: [u32; 16] = core::array::from_fn(|i| {
+                    (range_reference($imm8, a[i] as f64, b[i] as f64) as f32).to_bits()
+                });
+                // Compare the bits, so that the sign of zeros is checked too.
+                let r: [u32; 16] = transmute(_mm512_range_ps::<$imm8>(va, vb));
+                assert_eq!(r, e, "IMM8 = {}", $imm8);
+                // The scalar form copies the upper elements from `a`.
+                let r: [u32; 4] = transmute(_mm_mask_range_ss::<$imm8>(sa, 1, sa, sb));
+                assert_eq!(r, [e[0], a[1].to_bits(), a[2].to_bits(), a[3].to_bits()]);
+            )*};
+        }
+        check!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_pd_all_controls() {
+        for chunk in RANGE_INPUTS.chunks(8) {
+            let a: [f64; 8] = core::array::from_fn(|i| chunk[i].0);
+            let b: [f64; 8] = core::array::from_fn(|i| chunk[i].1);
+            let (va, vb) = (_mm512_loadu_pd(a.as_ptr()), _mm512_loadu_pd(b.as_ptr()));
+            macro_rules! check {
+                ($($imm8:literal)*) => {$(
+                    let e: [u64; 8] =
+                        core::array::from_fn(|i| range_reference($imm8, a[i], b[i]).to_bits());
+                    let r: [u64; 8] = transmute(_mm512_range_pd::<$imm8>(va, vb));
+                    assert_eq!(r, e, "IMM8 = {}", $imm8);
+                )*};
+            }
+            check!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15);
+        }
+    }
+
     #[simd_test(enable = "avx512dq")]
     unsafe fn test_mm_range_round_sd() {
         let a = _mm_set_sd(1.);
@@ -10383,6 +10520,58 @@ mod tests {
         assert_eq_m512(r, e);
     }
 
+    // The result of `vreduceps` and `vreducepd` for one element: `a` minus `a`
+    // rounded to `IMM8[7:4]` fraction bits with the rounding mode `IMM8[1:0]`.
+    fn reduce_reference(imm8: i32, a: f64) -> f64 {
+        let scale = (1 << (imm8 >> 4)) as f64;
+        let rounded = match imm8 & 0b11 {
+            _MM_FROUND_TO_NEAREST_INT => (a * scale).round_ties_even(),
+            _MM_FROUND_TO_NEG_INF => (a * scale).floor(),
+            _MM_FROUND_TO_POS_INF => (a * scale).ceil(),
+            _ => (a * scale).trunc(),
+        };
+        a - rounded / scale
+    }
+
+    // The results for these are exact, so they don't depend on how the
+    // subtraction is rounded.
+    const REDUCE_INPUTS: [f64; 16] = [
+        1.3, -1.3, 0.09375, -0.6, 2.5, -2.5, 3., -3., 100.123, 0., 0.28125, -7.75, 0.0625,
+        12345.678, 0.5, -0.375,
+    ];
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_ps_precisions() {
+        let a: [f32; 16] = core::array::from_fn(|i| REDUCE_INPUTS[i] as f32);
+        let va = _mm512_loadu_ps(a.as_ptr());
+        macro_rules! check {
+            ($($imm8:literal)*) => {$(
+                let e: [f32; 16] =
+                    core::array::from_fn(|i| reduce_reference($imm8, a[i] as f64) as f32);
+                let r: [f32; 16] = transmute(_mm512_reduce_ps::<$imm8>(va));
+                assert_eq!(r, e, "IMM8 = {:#x}", $imm8);
+            )*};
+        }
+        check!(0x00 0x01 0x02 0x03 0x10 0x11 0x12 0x13 0x20 0x21 0x22 0x23);
+        check!(0x40 0x41 0x42 0x43 0x80 0x81 0x82 0x83 0xf0 0xf1 0xf2 0xf3);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_pd_precisions() {
+        for a in REDUCE_INPUTS.chunks(8) {
+            let va = _mm512_loadu_pd(a.as_ptr());
+            macro_rules! check {
+                ($($imm8:literal)*) => {$(
+                    let e: [f64; 8] = core::array::from_fn(|i| reduce_reference($imm8, a[i]));
+                    let r: [f64; 8] = transmute(_mm512_reduce_pd::<$imm8>(va));
+                    assert_eq!(r, e, "IMM8 = {:#x}", $imm8);
+                )*};
+            }
+            check!(0x00 0x01 0x02 0x03 0x10 0x11 0x12 0x13 0x20 0x21 0x22 0x23);
+            check!(0x40 0x41 0x42 0x43 0x80 0x81 0x82 0x83 0xf0 0xf1 0xf2 0xf3);
+        }
+    }
+
     #[simd_test(enable = "avx512dq")]
     unsafe fn test_mm_reduce_round_sd() {
         let a = _mm_set_pd(1., 2.);
@@ -10698,4 +10887,109 @@ mod tests {
         let e = 0b0;
         assert_eq!(r, e);
     }
+
+    // One value of every category, and positive normal values that are in
+    // none of them.
+    const FPCLASS_PS: [f32; 16] = [
+        f32::NAN,
+        -f32::NAN,
+        f32::from_bits(0x7fa0_0000), // SNaN
+        f32::from_bits(0xffa0_0000), // negative SNaN
+        0.0,
+        -0.0,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        1.0e-40,
+        -1.0e-40,
+        1.0,
+        -1.0,
+        f32::MAX,
+        f32::MIN,
+        f32::MIN_POSITIVE,
+        -f32::MIN_POSITIVE,
+    ];
+
+    // The elements of `FPCLASS_PS` in each category, in the order of the
+    // `imm8` bits: QNaN, +0, -0, +inf, -inf, denormal, negative finite, SNaN.
+    const FPCLASS_PS_CATEGORIES: [u16; 8] = [
+        0b00000000_00000011,
+        0b00000000_00010000,
+        0b00000000_00100000,
+        0b00000000_01000000,
+        0b00000000_10000000,
+        0b00000011_00000000,
+        0b10101010_00000000,
+        0b00000000_00001100,
+    ];
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_fpclass_ps_mask_categories() {
+        let a = _mm512_loadu_ps(FPCLASS_PS.as_ptr());
+        let e = FPCLASS_PS_CATEGORIES;
+        macro_rules! check {
+            ($($bit:literal),*) => {$(
+                let r = _mm512_fpclass_ps_mask::<{ 1 << $bit }>(a);
+                assert_eq!(r, e[$bit], "bit {}", $bit);
+            )*};
+        }
+        check!(0, 1, 2, 3, 4, 5, 6, 7);
+        // Combined bits test for any of their categories.
+        assert_eq!(
+            _mm512_fpclass_ps_mask::<0xff>(a),
+            e.iter().fold(0, |r, e| r | e)
+        );
+        let r = _mm512_mask_fpclass_ps_mask::<0x81>(0x00ff, a);
+        assert_eq!(r, (e[0] | e[7]) & 0x00ff);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_fpclass_ss_mask_categories() {
+        let e = FPCLASS_PS_CATEGORIES;
+        for (i, x) in FPCLASS_PS.into_iter().enumerate() {
+            // Only the lowest element is tested.
+            let a = _mm_setr_ps(x, f32::NAN, 0.0, -1.0);
+            macro_rules! check {
+                ($($bit:literal),*) => {$(
+                    let r = _mm_fpclass_ss_mask::<{ 1 << $bit }>(a);
+                    assert_eq!(r, (e[$bit] >> i) as u8 & 1, "{x:?} bit {}", $bit);
+                )*};
+            }
+            check!(0, 1, 2, 3, 4, 5, 6, 7);
+            assert_eq!(_mm_mask_fpclass_ss_mask::<0xff>(0b10, a), 0);
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_fpclass_pd_mask_categories() {
+        let v = [
+            f64::NAN,
+            f64::from_bits(0x7ff4_0000_0000_0000), // SNaN
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            -1.0e-310,
+            -1.0,
+        ];
+        let a = _mm512_loadu_pd(v.as_ptr());
+        // In the order of the `imm8` bits: QNaN, +0, -0, +inf, -inf,
+        // denormal, negative finite, SNaN.
+        let e: [u8; 8] = [
+            0b00000001, 0b00000100, 0b00001000, 0b00010000, 0b00100000, 0b01000000, 0b11000000,
+            0b00000010,
+        ];
+        macro_rules! check {
+            ($($bit:literal),*) => {$(
+                let r = _mm512_fpclass_pd_mask::<{ 1 << $bit }>(a);
+                assert_eq!(r, e[$bit], "bit {}", $bit);
+                for (i, x) in v.into_iter().enumerate() {
+                    let r = _mm_fpclass_sd_mask::<{ 1 << $bit }>(_mm_setr_pd(x, f64::NAN));
+                    assert_eq!(r, (e[$bit] >> i) & 1, "{x:?} bit {}", $bit);
+                }
+            )*};
+        }
+        check!(0, 1, 2, 3, 4, 5, 6, 7);
+        let r = _mm512_mask_fpclass_pd_mask::<0x60>(0b01010101, a);
+        assert_eq!(r, 0b01000000);
+    }
 }
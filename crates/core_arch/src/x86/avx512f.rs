@@ -42295,11 +42295,12 @@ extern "C" {
 #[cfg(test)]
 mod tests {
 
-    use stdarch_test::simd_test;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     use crate::core_arch::x86::*;
     use crate::hint::black_box;
     use crate::mem::{self};
+    use crate::ptr;
 
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_abs_epi32() {
@@ -42421,6 +42422,22 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn fuzz_mm512_maskz_abs_epi32() {
+        fuzz_intrinsic!(
+            _mm512_maskz_abs_epi32(k: u16, a: [i32; 16]) -> [i32; 16],
+            |k, a| core::array::from_fn(|i| if k >> i & 1 != 0 { a[i].wrapping_abs() } else { 0 }),
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn fuzz_mm512_abs_epi64() {
+        fuzz_intrinsic!(
+            _mm512_abs_epi64(a: [i64; 8]) -> [u64; 8],
+            |a| a.map(i64::unsigned_abs),
+        );
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_abs_ps() {
         #[rustfmt::skip]
@@ -44743,6 +44760,30 @@ mod tests {
         assert_eq_m512(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_rcp14_rsqrt14_ps_relative_error() {
+        // Sweep a wide range of magnitudes, with mantissas that vary too.
+        for batch in 0..16 {
+            let xs: [f32; 16] =
+                core::array::from_fn(|i| 1.37_f32.powi(batch * 16 + i as i32 - 128));
+            let x = _mm512_loadu_ps(xs.as_ptr());
+            let rcp: [f32; 16] = mem::transmute(_mm512_rcp14_ps(x));
+            let rsqrt: [f32; 16] = mem::transmute(_mm512_rsqrt14_ps(x));
+            for ((x, rcp), rsqrt) in xs.iter().zip(rcp).zip(rsqrt) {
+                let e = 1.0 / *x as f64;
+                assert!(
+                    ((rcp as f64 - e) / e).abs() < 2.0f64.powi(-14),
+                    "rcp14({x})"
+                );
+                let e = 1.0 / (*x as f64).sqrt();
+                assert!(
+                    ((rsqrt as f64 - e) / e).abs() < 2.0f64.powi(-14),
+                    "rsqrt14({x})"
+                );
+            }
+        }
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_rcp14_ps() {
         let a = _mm512_set1_ps(3.);
@@ -45011,6 +45052,66 @@ mod tests {
         assert_eq_m512(r, e);
     }
 
+    // The result of `vrndscaleps` and `vrndscalepd` for one element: `a`
+    // rounded to `IMM8[7:4]` fraction bits with the rounding mode `IMM8[1:0]`.
+    fn roundscale_reference(imm8: i32, a: f64) -> f64 {
+        let scale = (1 << (imm8 >> 4)) as f64;
+        let rounded = match imm8 & 0b11 {
+            _MM_FROUND_TO_NEAREST_INT => (a * scale).round_ties_even(),
+            _MM_FROUND_TO_NEG_INF => (a * scale).floor(),
+            _MM_FROUND_TO_POS_INF => (a * scale).ceil(),
+            _ => (a * scale).trunc(),
+        };
+        rounded / scale
+    }
+
+    const ROUNDSCALE_INPUTS: [f64; 16] = [
+        1.3, -1.3, 0.1, -0.3, 2.5, -2.5, 0.75, -0.625, 100.123, -0., 1.03125, -7.96875, 1e-3,
+        12345.678, 1e30, -0.09375,
+    ];
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_roundscale_ps_precisions() {
+        let a: [f32; 16] = core::array::from_fn(|i| ROUNDSCALE_INPUTS[i] as f32);
+        let va = _mm512_loadu_ps(a.as_ptr());
+        macro_rules! check {
+            ($($imm8:literal)*) => {$(
+                let e: [u32; 16] = core::array::from_fn(|i| {
+                    (roundscale_reference($imm8, a[i] as f64) as f32).to_bits()
+                });
+                // Compare the bits, so that the sign of zeros is checked too.
+                let r: [u32; 16] = transmute(_mm512_roundscale_ps::<$imm8>(va));
+                assert_eq!(r, e, "IMM8 = {:#x}", $imm8);
+                let r: [u32; 16] =
+                    transmute(_mm512_roundscale_round_ps::<$imm8, _MM_FROUND_NO_EXC>(va));
+                assert_eq!(r, e, "IMM8 = {:#x} with exceptions suppressed", $imm8);
+            )*};
+        }
+        check!(0x00 0x01 0x02 0x03 0x10 0x11 0x12 0x13 0x40 0x41 0x42 0x43 0x80 0x81 0x82 0x83);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_roundscale_pd_precisions() {
+        for a in ROUNDSCALE_INPUTS.chunks(8) {
+            let va = _mm512_loadu_pd(a.as_ptr());
+            macro_rules! check {
+                ($($imm8:literal)*) => {$(
+                    let e: [u64; 8] =
+                        core::array::from_fn(|i| roundscale_reference($imm8, a[i]).to_bits());
+                    let r: [u64; 8] = transmute(_mm512_roundscale_pd::<$imm8>(va));
+                    assert_eq!(r, e, "IMM8 = {:#x}", $imm8);
+                    // The scalar form copies the upper element from `a`.
+                    let r: [u64; 2] = transmute(_mm_roundscale_sd::<$imm8>(
+                        _mm_set_pd(42., 0.),
+                        _mm_set_sd(a[0]),
+                    ));
+                    assert_eq!(r, [e[0], 42f64.to_bits()], "scalar IMM8 = {:#x}", $imm8);
+                )*};
+            }
+            check!(0x00 0x01 0x02 0x03 0x10 0x11 0x12 0x13 0x40 0x41 0x42 0x43 0x80 0x81 0x82 0x83);
+        }
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_roundscale_ps() {
         let a = _mm512_set1_ps(1.1);
@@ -45198,6 +45299,43 @@ mod tests {
         assert_eq_m512(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_fixupimm_ps_token_responses() {
+        // Each element of `b` is classified into a token, and the matching
+        // nibble of `c` selects the response.
+        const QNAN: i32 = 0;
+        const SNAN: i32 = 1;
+        const ZERO: i32 = 2;
+        const POS_ONE: i32 = 3;
+        const NEG_INF: i32 = 4;
+        const POS_INF: i32 = 5;
+        const NEG: i32 = 6;
+        const POS: i32 = 7;
+        let response = |token: i32, r: i32| r << (4 * token);
+        let c = response(QNAN, 8) // +0.0
+            | response(SNAN, 8) // +0.0
+            | response(ZERO, 10) // +1.0
+            | response(POS_ONE, 0) // the element of `a`
+            | response(NEG_INF, 3) // the default QNaN
+            | response(POS_INF, 1) // the element of `b`
+            | response(NEG, 7) // -0.0
+            | response(POS, 0); // the element of `a`
+        let a = _mm512_set1_ps(42.);
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            f32::NAN, 0., -0., 1., f32::NEG_INFINITY, f32::INFINITY, -3., 3.,
+            -f32::NAN, -f32::MIN_POSITIVE, f32::MAX, -f32::MAX, 2., -1., 0.5, -0.5,
+        );
+        let r = _mm512_fixupimm_ps::<0>(a, b, _mm512_set1_epi32(c));
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            0., 1., 1., 42., f32::from_bits(0xffc0_0000), f32::INFINITY, -0., 42.,
+            0., -0., 42., -0., 42., -0., 42., -0.,
+        );
+        // Compare the bits to tell apart +0.0 and -0.0, and to check the NaN.
+        assert_eq_m512i(_mm512_castps_si512(r), _mm512_castps_si512(e));
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_fixupimm_ps() {
         #[rustfmt::skip]
@@ -45304,6 +45442,38 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_ternarylogic_epi32_truth_tables() {
+        // With these inputs bit `i` of the result is bit `i` of the truth table.
+        let a = _mm512_set1_epi32(0xf0);
+        let b = _mm512_set1_epi32(0xcc);
+        let c = _mm512_set1_epi32(0xaa);
+        let r = _mm512_ternarylogic_epi32::<0xfe>(a, b, c);
+        assert_eq_m512i(r, _mm512_set1_epi32(0xfe));
+        let r = _mm512_ternarylogic_epi32::<0x96>(a, b, c);
+        assert_eq_m512i(r, _mm512_set1_epi32(0x96));
+
+        let (x, y, z) = (0x1234_5678, 0x0f0f_f0f0, 0x7777_aaaa);
+        let a = _mm512_set1_epi32(x);
+        let b = _mm512_set1_epi32(y);
+        let c = _mm512_set1_epi32(z);
+        // OR of all three.
+        let r = _mm512_ternarylogic_epi32::<0xfe>(a, b, c);
+        assert_eq_m512i(r, _mm512_set1_epi32(x | y | z));
+        // AND of all three.
+        let r = _mm512_ternarylogic_epi32::<0x80>(a, b, c);
+        assert_eq_m512i(r, _mm512_set1_epi32(x & y & z));
+        // XOR of all three.
+        let r = _mm512_ternarylogic_epi32::<0x96>(a, b, c);
+        assert_eq_m512i(r, _mm512_set1_epi32(x ^ y ^ z));
+        // Bitwise majority.
+        let r = _mm512_ternarylogic_epi32::<0xe8>(a, b, c);
+        assert_eq_m512i(r, _mm512_set1_epi32((x & y) | (x & z) | (y & z)));
+        // Bitwise select: `a ? b : c`.
+        let r = _mm512_ternarylogic_epi32::<0xca>(a, b, c);
+        assert_eq_m512i(r, _mm512_set1_epi32((x & y) | (!x & z)));
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_ternarylogic_epi32() {
         let src = _mm512_set1_epi32(1 << 2);
@@ -45404,6 +45574,34 @@ mod tests {
         assert_eq_m512(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_getmant_getexp_scalef_ps_round_trip() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            10., -10., 1., -1., 0.75, 3.0e-30, -7.5e20, 1234.5678,
+            f32::MAX, f32::MIN_POSITIVE, -f32::MIN_POSITIVE, 0.1, 2., -0.5, 1.5, 1.0e-10,
+        );
+        let mant = _mm512_getmant_ps::<_MM_MANT_NORM_1_2, _MM_MANT_SIGN_SRC>(a);
+        let exp = _mm512_getexp_ps(a);
+        // The mantissa is in [1, 2) and keeps the sign of the input.
+        let abs = _mm512_abs_ps(mant);
+        assert_eq!(
+            _mm512_cmp_ps_mask::<_CMP_GE_OQ>(abs, _mm512_set1_ps(1.)),
+            0xffff
+        );
+        assert_eq!(
+            _mm512_cmp_ps_mask::<_CMP_LT_OQ>(abs, _mm512_set1_ps(2.)),
+            0xffff
+        );
+        // The exponent is `floor(log2(|a|))`, and scaling the mantissa by it
+        // recovers the input exactly.
+        let e = _mm512_setr_ps(
+            3., 3., 0., 0., -1., -99., 69., 10., 127., -126., -126., -4., 1., -1., 0., -34.,
+        );
+        assert_eq_m512(exp, e);
+        assert_eq_m512(_mm512_scalef_ps(mant, exp), a);
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_getmant_ps() {
         let a = _mm512_set1_ps(10.);
@@ -47903,6 +48101,82 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[rustfmt::skip]
+    const NARROW_INPUTS: [i64; 32] = [
+        i64::MIN, -(1 << 32), -(1 << 31) - 1, -(1 << 31), -32769, -32768, -32767, -129,
+        -128, -127, -1, 0, 1, 126, 127, 128,
+        129, 254, 255, 256, 32767, 32768, 65535, 65536,
+        (1 << 31) - 1, 1 << 31, (1 << 32) - 1, 1 << 32, i64::MAX, 0x1234_5678_9abc_def0,
+        -0x1234_5678_9abc_def0, 0x180,
+    ];
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_cvt_narrow_saturation_boundaries() {
+        // Each value is converted to the source element type first, so the
+        // 32-bit sources see the boundaries of the 32-bit range too.
+        macro_rules! check {
+            ($($cvt:ident: [$s:ty; $n:literal] -> [$d:ty; $m:literal], $reference:expr;)*) => {$(
+                for a in NARROW_INPUTS.chunks($n) {
+                    let a: [$s; $n] = core::array::from_fn(|i| a[i] as $s);
+                    let r: [$d; $m] = transmute($cvt(transmute(a)));
+                    let reference: fn($s) -> $d = $reference;
+                    // Elements past the converted ones are zeroed.
+                    let e: [$d; $m] =
+                        core::array::from_fn(|i| if i < $n { reference(a[i]) } else { 0 });
+                    assert_eq!(r, e, "{}({:?})", stringify!($cvt), a);
+                }
+            )*};
+        }
+        check! {
+            _mm512_cvtepi32_epi8: [i32; 16] -> [i8; 16], |x| x as i8;
+            _mm512_cvtsepi32_epi8: [i32; 16] -> [i8; 16], |x| x.clamp(-128, 127) as i8;
+            _mm512_cvtusepi32_epi8: [u32; 16] -> [u8; 16], |x| x.min(255) as u8;
+            _mm512_cvtepi32_epi16: [i32; 16] -> [i16; 16], |x| x as i16;
+            _mm512_cvtsepi32_epi16: [i32; 16] -> [i16; 16], |x| x.clamp(-32768, 32767) as i16;
+            _mm512_cvtusepi32_epi16: [u32; 16] -> [u16; 16], |x| x.min(65535) as u16;
+            _mm512_cvtepi64_epi8: [i64; 8] -> [i8; 16], |x| x as i8;
+            _mm512_cvtsepi64_epi8: [i64; 8] -> [i8; 16], |x| x.clamp(-128, 127) as i8;
+            _mm512_cvtusepi64_epi8: [u64; 8] -> [u8; 16], |x| x.min(255) as u8;
+            _mm512_cvtepi64_epi16: [i64; 8] -> [i16; 8], |x| x as i16;
+            _mm512_cvtsepi64_epi16: [i64; 8] -> [i16; 8], |x| x.clamp(-32768, 32767) as i16;
+            _mm512_cvtusepi64_epi16: [u64; 8] -> [u16; 8], |x| x.min(65535) as u16;
+            _mm512_cvtepi64_epi32: [i64; 8] -> [i32; 8], |x| x as i32;
+            _mm512_cvtsepi64_epi32: [i64; 8] -> [i32; 8],
+                |x| x.clamp(i32::MIN.into(), i32::MAX.into()) as i32;
+            _mm512_cvtusepi64_epi32: [u64; 8] -> [u32; 8], |x| x.min(u32::MAX as u64) as u32;
+        }
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_cvtsepi32_storeu_epi8_boundaries() {
+        // Only the selected bytes are written, saturated.
+        let a: [i32; 16] = core::array::from_fn(|i| NARROW_INPUTS[i + 8] as i32 * 3);
+        let mut r = [0x55_i8; 18];
+        let k = 0b1010_1111_0110_1101;
+        _mm512_mask_cvtsepi32_storeu_epi8(r.as_mut_ptr().add(1), k, transmute(a));
+        let e: [i8; 18] = core::array::from_fn(|i| match i {
+            1..=16 if k >> (i - 1) & 1 != 0 => a[i - 1].clamp(-128, 127) as i8,
+            _ => 0x55,
+        });
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn fuzz_mm512_cvtsepi32_epi16() {
+        fuzz_intrinsic!(
+            _mm512_cvtsepi32_epi16(a: [i32; 16]) -> [i16; 16],
+            |a| a.map(|x| x.clamp(-32768, 32767) as i16),
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn fuzz_mm512_cvtusepi64_epi32() {
+        fuzz_intrinsic!(
+            _mm512_cvtusepi64_epi32(a: [u64; 8]) -> [u32; 8],
+            |a| a.map(|x| x.min(u32::MAX as u64) as u32),
+        );
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_cvt_roundps_epi32() {
         let a = _mm512_setr_ps(
@@ -48914,6 +49188,80 @@ mod tests {
         assert_eq!(r, 0b00000100_00000100);
     }
 
+    /// Scalar reference for the `_CMP_*` predicates.
+    // The negated comparisons are what the `N*` predicates are defined as.
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn cmp_predicate(imm8: i32, a: f64, b: f64) -> bool {
+        let unordered = a.is_nan() || b.is_nan();
+        // Bit 4 only selects whether quiet NaNs signal, which doesn't change
+        // the result.
+        match imm8 & 0xf {
+            _CMP_EQ_OQ => a == b,
+            _CMP_LT_OS => a < b,
+            _CMP_LE_OS => a <= b,
+            _CMP_UNORD_Q => unordered,
+            _CMP_NEQ_UQ => a != b,
+            _CMP_NLT_US => !(a < b),
+            _CMP_NLE_US => !(a <= b),
+            _CMP_ORD_Q => !unordered,
+            _CMP_EQ_UQ => unordered || a == b,
+            _CMP_NGE_US => !(a >= b),
+            _CMP_NGT_US => !(a > b),
+            _CMP_FALSE_OQ => false,
+            _CMP_NEQ_OQ => !unordered && a != b,
+            _CMP_GE_OS => a >= b,
+            _CMP_GT_OS => a > b,
+            _ => true,
+        }
+    }
+
+    /// Pairs of operands covering each ordering, signed zeros, infinities and
+    /// NaN on either side.
+    #[rustfmt::skip]
+    const CMP_INPUTS: [(f64, f64); 16] = [
+        (1., 1.), (1., 2.), (2., 1.), (f64::NAN, 1.),
+        (1., f64::NAN), (f64::NAN, f64::NAN), (0., -0.), (-0., 0.),
+        (f64::INFINITY, f64::INFINITY), (f64::NEG_INFINITY, f64::INFINITY),
+        (f64::INFINITY, f64::NEG_INFINITY), (f64::INFINITY, f64::NAN),
+        (-1., -2.), (f64::NAN, 0.), (5., 5.), (-5., 4.),
+    ];
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_cmp_ps_mask_all_predicates() {
+        let a: [f32; 16] = core::array::from_fn(|i| CMP_INPUTS[i].0 as f32);
+        let b: [f32; 16] = core::array::from_fn(|i| CMP_INPUTS[i].1 as f32);
+        let (va, vb) = (_mm512_loadu_ps(a.as_ptr()), _mm512_loadu_ps(b.as_ptr()));
+        macro_rules! check {
+            ($($imm8:literal)*) => {$(
+                let e = (0..16).fold(0, |m, i| {
+                    m | (cmp_predicate($imm8, a[i] as f64, b[i] as f64) as u16) << i
+                });
+                assert_eq!(_mm512_cmp_ps_mask::<$imm8>(va, vb), e, "predicate {}", $imm8);
+                let r = _mm512_mask_cmp_ps_mask::<$imm8>(0x5a5a, va, vb);
+                assert_eq!(r, e & 0x5a5a, "masked predicate {}", $imm8);
+            )*};
+        }
+        check!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_cmp_pd_mask_all_predicates() {
+        for chunk in CMP_INPUTS.chunks(8) {
+            let a: [f64; 8] = core::array::from_fn(|i| chunk[i].0);
+            let b: [f64; 8] = core::array::from_fn(|i| chunk[i].1);
+            let (va, vb) = (_mm512_loadu_pd(a.as_ptr()), _mm512_loadu_pd(b.as_ptr()));
+            macro_rules! check {
+                ($($imm8:literal)*) => {$(
+                    let e = (0..8).fold(0, |m, i| m | (cmp_predicate($imm8, a[i], b[i]) as u8) << i);
+                    assert_eq!(_mm512_cmp_pd_mask::<$imm8>(va, vb), e, "predicate {}", $imm8);
+                    let r = _mm512_mask_cmp_pd_mask::<$imm8>(0x5a, va, vb);
+                    assert_eq!(r, e & 0x5a, "masked predicate {}", $imm8);
+                )*};
+            }
+            check!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31);
+        }
+    }
+
     #[simd_test(enable = "avx512f,avx512vl")]
     unsafe fn test_mm256_cmp_ps_mask() {
         let a = _mm256_set_ps(0., 1., -1., 13., f32::MAX, f32::MIN, 100., -100.);
@@ -49913,6 +50261,28 @@ mod tests {
         )
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_set_epi8_lane_order() {
+        // The last argument goes to the lowest lane.
+        let r = _mm512_set_epi8(
+            63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49, 48, 47, 46, 45, 44, 43, 42,
+            41, 40, 39, 38, 37, 36, 35, 34, 33, 32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20,
+            19, 18, 17, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let e: [i8; 64] = core::array::from_fn(|i| i as i8);
+        assert_eq!(mem::transmute::<_, [i8; 64]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_set_epi16_lane_order() {
+        let r = _mm512_set_epi16(
+            31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16, 15, 14, 13, 12, 11, 10,
+            9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let e: [i16; 32] = core::array::from_fn(|i| i as i16);
+        assert_eq!(mem::transmute::<_, [i16; 32]>(r), e);
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_set_epi32() {
         let r = _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
@@ -50140,6 +50510,39 @@ mod tests {
         assert_eq_m512i(_mm512_loadu_epi32(r.as_ptr()), e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_loadu_storeu_epi32_short_buffer() {
+        // Only the active lanes are accessed, so the buffer may be shorter
+        // than the vector.
+        let mut g = Guarded::<i32, 3, 13>::new([1, 2, 3], 42);
+        let k = black_box(0b111);
+        let r = _mm512_maskz_loadu_epi32(k, g.buf.as_ptr());
+        let e = _mm512_setr_epi32(1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+        _mm512_mask_storeu_epi32(g.buf.as_mut_ptr(), k, _mm512_set1_epi32(-1));
+        assert_eq!(g.buf, [-1; 3]);
+        assert_eq!(g.guard, [42; 13]);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_loadu_storeu_ps_pd_empty_mask() {
+        // Faults are suppressed for masked-off lanes, so with an empty mask
+        // even a null pointer isn't accessed.
+        let src = _mm512_set1_ps(42.0);
+        let p = black_box(ptr::null_mut::<f32>());
+        let k = black_box(0);
+        assert_eq_m512(_mm512_mask_loadu_ps(src, k, p), src);
+        assert_eq_m512(_mm512_maskz_loadu_ps(k, p), _mm512_setzero_ps());
+        _mm512_mask_storeu_ps(p, k, src);
+
+        let src = _mm512_set1_pd(42.0);
+        let p = black_box(ptr::null_mut::<f64>());
+        let k = black_box(0);
+        assert_eq_m512d(_mm512_mask_loadu_pd(src, k, p), src);
+        assert_eq_m512d(_mm512_maskz_loadu_pd(k, p), _mm512_setzero_pd());
+        _mm512_mask_storeu_pd(p, k, src);
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_store_epi32() {
         #[repr(align(64))]
@@ -52943,6 +53346,20 @@ mod tests {
         assert_eq_m512(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn fuzz_mm512_shuffle_ps() {
+        let shuffle_ps = |a: __m512, b: __m512| _mm512_shuffle_ps::<0b01_11_00_10>(a, b);
+        fuzz_intrinsic!(
+            shuffle_ps(a: [f32; 16], b: [f32; 16]) -> [f32; 16],
+            |a, b| core::array::from_fn(|i| {
+                // The low two elements of each 128-bit lane come from `a` and
+                // the high two from `b`, both from the same lane.
+                let j = i / 4 * 4 + ((0b01_11_00_10 >> (i % 4 * 2)) & 3);
+                if i % 4 < 2 { a[j] } else { b[j] }
+            }),
+        );
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_shuffle_ps() {
         let a = _mm512_setr_ps(
@@ -53454,6 +53871,78 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_insert_extract_x4_round_trip() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let b = _mm_setr_epi32(17, 18, 19, 20);
+        let c = _mm256_setr_epi64x(21, 22, 23, 24);
+        macro_rules! check_32x4 {
+            ($($i:literal),*) => {$(
+                // Inserting replaces exactly the selected sub-vector, which
+                // then extracts unchanged.
+                let r = _mm512_inserti32x4::<$i>(a, b);
+                assert_eq_m128i(_mm512_extracti32x4_epi32::<$i>(r), b);
+                let r: [i32; 16] = transmute(r);
+                let e: [i32; 16] = transmute(a);
+                let e: [i32; 16] =
+                    core::array::from_fn(|j| if j / 4 == $i { 17 + j as i32 % 4 } else { e[j] });
+                assert_eq!(r, e, "_mm512_inserti32x4::<{}>", $i);
+
+                let r = _mm512_insertf32x4::<$i>(_mm512_castsi512_ps(a), _mm_castsi128_ps(b));
+                assert_eq_m128(_mm512_extractf32x4_ps::<$i>(r), _mm_castsi128_ps(b));
+                assert_eq_m512i(_mm512_castps_si512(r), transmute(e));
+            )*};
+        }
+        check_32x4!(0, 1, 2, 3);
+
+        macro_rules! check_64x4 {
+            ($($i:literal),*) => {$(
+                let r = _mm512_inserti64x4::<$i>(a, c);
+                assert_eq_m256i(_mm512_extracti64x4_epi64::<$i>(r), c);
+                assert_eq_m256i(
+                    _mm512_extracti64x4_epi64::<{ 1 - $i }>(r),
+                    _mm512_extracti64x4_epi64::<{ 1 - $i }>(a),
+                );
+
+                let r = _mm512_insertf64x4::<$i>(_mm512_castsi512_pd(a), _mm256_castsi256_pd(c));
+                assert_eq_m256d(_mm512_extractf64x4_pd::<$i>(r), _mm256_castsi256_pd(c));
+                assert_eq_m256d(
+                    _mm512_extractf64x4_pd::<{ 1 - $i }>(r),
+                    _mm512_extractf64x4_pd::<{ 1 - $i }>(_mm512_castsi512_pd(a)),
+                );
+            )*};
+        }
+        check_64x4!(0, 1);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_insert_extract_x4_round_trip() {
+        let a = _mm256_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm_setr_epi32(9, 10, 11, 12);
+        macro_rules! check {
+            ($($i:literal),*) => {$(
+                let r = _mm256_inserti32x4::<$i>(a, b);
+                assert_eq_m128i(_mm256_extracti32x4_epi32::<$i>(r), b);
+                assert_eq_m128i(
+                    _mm256_extracti32x4_epi32::<{ 1 - $i }>(r),
+                    _mm256_extracti32x4_epi32::<{ 1 - $i }>(a),
+                );
+                // The AVX and AVX2 forms agree with the AVX-512 ones.
+                assert_eq_m256i(_mm256_inserti128_si256::<$i>(a, b), r);
+                assert_eq_m128i(_mm256_extracti128_si256::<$i>(r), b);
+                assert_eq_m256i(_mm256_insertf128_si256::<$i>(a, b), r);
+                assert_eq_m128i(_mm256_extractf128_si256::<$i>(r), b);
+
+                let (af, bf) = (_mm256_castsi256_ps(a), _mm_castsi128_ps(b));
+                let r = _mm256_insertf32x4::<$i>(af, bf);
+                assert_eq_m128(_mm256_extractf32x4_ps::<$i>(r), bf);
+                assert_eq_m256(_mm256_insertf128_ps::<$i>(af, bf), r);
+                assert_eq_m128(_mm256_extractf128_ps::<$i>(r), bf);
+            )*};
+        }
+        check!(0, 1);
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_inserti32x4() {
         let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
@@ -53977,6 +54466,46 @@ mod tests {
         assert_eq_m128(r, e);
     }
 
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mask_blend_selects_per_bit() {
+        // Lane i comes from b if bit i of k is set and from a otherwise.
+        // Mask bits above the number of lanes are ignored.
+        macro_rules! check {
+            ($($blend:ident: [$t:ty; $n:literal], $k:ty;)*) => {$(
+                let a: [$t; $n] = core::array::from_fn(|i| i as $t + 1 as $t);
+                let b: [$t; $n] = core::array::from_fn(|i| -(i as $t) - 1 as $t);
+                let masks = [0, !0, 0x5555_5555, 0xaaaa_aaaa, 1, 1 << ($n - 1), 0x9abc_def0];
+                for k in masks.map(|k: u64| k as $k) {
+                    let r: [$t; $n] = transmute($blend(k, transmute(a), transmute(b)));
+                    let e = core::array::from_fn(|i| if k >> i & 1 != 0 { b[i] } else { a[i] });
+                    assert_eq!(r, e, "{}({:#x})", stringify!($blend), k);
+                }
+            )*};
+        }
+        check! {
+            _mm512_mask_blend_epi32: [i32; 16], __mmask16;
+            _mm256_mask_blend_epi32: [i32; 8], __mmask8;
+            _mm_mask_blend_epi32: [i32; 4], __mmask8;
+            _mm512_mask_blend_epi64: [i64; 8], __mmask8;
+            _mm256_mask_blend_epi64: [i64; 4], __mmask8;
+            _mm_mask_blend_epi64: [i64; 2], __mmask8;
+            _mm512_mask_blend_ps: [f32; 16], __mmask16;
+            _mm256_mask_blend_ps: [f32; 8], __mmask8;
+            _mm_mask_blend_ps: [f32; 4], __mmask8;
+            _mm512_mask_blend_pd: [f64; 8], __mmask8;
+            _mm256_mask_blend_pd: [f64; 4], __mmask8;
+            _mm_mask_blend_pd: [f64; 2], __mmask8;
+        }
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn fuzz_mm512_mask_blend_epi32() {
+        fuzz_intrinsic!(
+            _mm512_mask_blend_epi32(k: u16, a: [i32; 16], b: [i32; 16]) -> [i32; 16],
+            |k, a, b| core::array::from_fn(|i| if k >> i & 1 != 0 { b[i] } else { a[i] }),
+        );
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_unpackhi_epi32() {
         let a = _mm512_set_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
@@ -54162,6 +54691,45 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn fuzz_mm512_unpack_epi32_epi64() {
+        fuzz_intrinsic!(
+            _mm512_unpacklo_epi32(a: [i32; 16], b: [i32; 16]) -> [i32; 16],
+            |a, b| core::array::from_fn(|i| {
+                let j = i / 4 * 4 + i % 4 / 2;
+                if i % 2 == 0 { a[j] } else { b[j] }
+            }),
+        );
+        fuzz_intrinsic!(
+            _mm512_unpackhi_epi32(a: [i32; 16], b: [i32; 16]) -> [i32; 16],
+            |a, b| core::array::from_fn(|i| {
+                let j = i / 4 * 4 + 2 + i % 4 / 2;
+                if i % 2 == 0 { a[j] } else { b[j] }
+            }),
+        );
+        fuzz_intrinsic!(
+            _mm512_unpacklo_epi64(a: [i64; 8], b: [i64; 8]) -> [i64; 8],
+            |a, b| core::array::from_fn(|i| if i % 2 == 0 { a[i] } else { b[i - 1] }),
+        );
+        fuzz_intrinsic!(
+            _mm512_unpackhi_epi64(a: [i64; 8], b: [i64; 8]) -> [i64; 8],
+            |a, b| core::array::from_fn(|i| if i % 2 == 0 { a[i + 1] } else { b[i] }),
+        );
+        fuzz_intrinsic!(
+            _mm512_maskz_unpackhi_epi32(k: u16, a: [i32; 16], b: [i32; 16]) -> [i32; 16],
+            |k, a, b| core::array::from_fn(|i| {
+                let j = i / 4 * 4 + 2 + i % 4 / 2;
+                if k >> i & 1 == 0 {
+                    0
+                } else if i % 2 == 0 {
+                    a[j]
+                } else {
+                    b[j]
+                }
+            }),
+        );
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_unpacklo_epi32() {
         let a = _mm512_set_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
@@ -54340,6 +54908,24 @@ mod tests {
             1, 32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18,
         );
         assert_eq_m512i(r, e);
+
+        // Compare against shifting the concatenation `a:b` by hand
+        let a_: [i32; 16] = mem::transmute(a);
+        let b_: [i32; 16] = mem::transmute(b);
+        let concat = |shift: usize| -> [i32; 16] {
+            let mut e = [0; 16];
+            for (i, e) in e.iter_mut().enumerate() {
+                let j = i + shift % 16;
+                *e = if j < 16 { b_[j] } else { a_[j - 16] };
+            }
+            e
+        };
+        let r: [i32; 16] = mem::transmute(_mm512_alignr_epi32::<5>(a, b));
+        assert_eq!(r, concat(5));
+        let r: [i32; 16] = mem::transmute(_mm512_alignr_epi32::<15>(a, b));
+        assert_eq!(r, concat(15));
+        let r: [i32; 16] = mem::transmute(_mm512_alignr_epi32::<19>(a, b));
+        assert_eq!(r, concat(19));
     }
 
     #[simd_test(enable = "avx512f")]
@@ -55013,6 +55599,25 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_cvtmask16_round_trip() {
+        let ones = _mm512_set1_epi32(-1);
+        for k in 0..=u16::MAX {
+            assert_eq!(_cvtu32_mask16(_cvtmask16_u32(k)), k);
+            assert_eq!(_mm512_int2mask(_mm512_mask2int(k)), k);
+            // Converting to an integer zero-extends the mask.
+            assert_eq!(_cvtmask16_u32(k), k as u32);
+            assert_eq!(_mm512_mask2int(k), k as i32);
+            // The integer selects the same elements as the mask.
+            let v = _mm512_maskz_mov_epi32(_cvtu32_mask16(k as u32), ones);
+            assert_eq!(_cvtmask16_u32(_mm512_cmpeq_epi32_mask(v, ones)), k as u32);
+        }
+        // Converting to a mask keeps the low 16 bits.
+        assert_eq!(_cvtu32_mask16(0xabcd_1234), 0x1234);
+        assert_eq!(_mm512_int2mask(-1), 0xffff);
+        assert_eq!(_mm512_int2mask(i32::MIN | 0x8001), 0x8001);
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_kand() {
         let a: u16 = 0b11001100_00110011;
@@ -55727,6 +56332,19 @@ mod tests {
         assert_eq!(&r, &[0_i32; 16]);
         _mm512_mask_compressstoreu_epi32(r.as_mut_ptr() as *mut _, 0b1111000011001010, a);
         assert_eq!(&r, &[2, 4, 7, 8, 13, 14, 15, 16, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        // Round-trip through a misaligned buffer with expandloadu
+        #[repr(align(64))]
+        struct Align {
+            data: [i32; 17],
+        }
+        let m = 0b0110_1001_0011_1100;
+        let mut buf = Align { data: [0_i32; 17] };
+        let p = buf.data.as_mut_ptr().add(1);
+        _mm512_mask_compressstoreu_epi32(p as *mut _, m, a);
+        assert_eq!(&buf.data[1..9], &[3, 4, 5, 6, 9, 12, 14, 15]);
+        let r = _mm512_maskz_expandloadu_epi32(m, p);
+        assert_eq_m512i(r, _mm512_maskz_mov_epi32(m, a));
     }
 
     #[simd_test(enable = "avx512f,avx512vl")]
@@ -11045,7 +11045,7 @@ macro_rules! fpclass_asm { // FIXME: use LLVM intrinsics
 ///     0x08 // Positive Infinity
 ///     0x10 // Negative Infinity
 ///     0x20 // Denormal
-///     0x40 // Negative
+///     0x40 // Negative Finite
 ///     0x80 // SNaN
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fpclass_ph_mask)
@@ -11070,7 +11070,7 @@ pub unsafe fn _mm_fpclass_ph_mask<const IMM8: i32>(a: __m128h) -> __mmask8 {
 ///     0x08 // Positive Infinity
 ///     0x10 // Negative Infinity
 ///     0x20 // Denormal
-///     0x40 // Negative
+///     0x40 // Negative Finite
 ///     0x80 // SNaN
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fpclass_ph_mask)
@@ -11094,7 +11094,7 @@ pub unsafe fn _mm_mask_fpclass_ph_mask<const IMM8: i32>(k1: __mmask8, a: __m128h
 ///     0x08 // Positive Infinity
 ///     0x10 // Negative Infinity
 ///     0x20 // Denormal
-///     0x40 // Negative
+///     0x40 // Negative Finite
 ///     0x80 // SNaN
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fpclass_ph_mask)
@@ -11119,7 +11119,7 @@ pub unsafe fn _mm256_fpclass_ph_mask<const IMM8: i32>(a: __m256h) -> __mmask16 {
 ///     0x08 // Positive Infinity
 ///     0x10 // Negative Infinity
 ///     0x20 // Denormal
-///     0x40 // Negative
+///     0x40 // Negative Finite
 ///     0x80 // SNaN
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fpclass_ph_mask)
@@ -11143,7 +11143,7 @@ pub unsafe fn _mm256_mask_fpclass_ph_mask<const IMM8: i32>(k1: __mmask16, a: __m
 ///     0x08 // Positive Infinity
 ///     0x10 // Negative Infinity
 ///     0x20 // Denormal
-///     0x40 // Negative
+///     0x40 // Negative Finite
 ///     0x80 // SNaN
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fpclass_ph_mask)
@@ -11168,7 +11168,7 @@ pub unsafe fn _mm512_fpclass_ph_mask<const IMM8: i32>(a: __m512h) -> __mmask32 {
 ///     0x08 // Positive Infinity
 ///     0x10 // Negative Infinity
 ///     0x20 // Denormal
-///     0x40 // Negative
+///     0x40 // Negative Finite
 ///     0x80 // SNaN
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fpclass_ph_mask)
@@ -11192,7 +11192,7 @@ pub unsafe fn _mm512_mask_fpclass_ph_mask<const IMM8: i32>(k1: __mmask32, a: __m
 ///     0x08 // Positive Infinity
 ///     0x10 // Negative Infinity
 ///     0x20 // Denormal
-///     0x40 // Negative
+///     0x40 // Negative Finite
 ///     0x80 // SNaN
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fpclass_sh_mask)
@@ -11216,7 +11216,7 @@ pub unsafe fn _mm_fpclass_sh_mask<const IMM8: i32>(a: __m128h) -> __mmask8 {
 ///     0x08 // Positive Infinity
 ///     0x10 // Negative Infinity
 ///     0x20 // Denormal
-///     0x40 // Negative
+///     0x40 // Negative Finite
 ///     0x80 // SNaN
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fpclass_sh_mask)
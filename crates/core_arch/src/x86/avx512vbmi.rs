@@ -466,9 +466,10 @@ extern "C" {
 #[cfg(test)]
 mod tests {
 
-    use stdarch_test::simd_test;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     use crate::core_arch::x86::*;
+    use crate::mem::transmute;
 
     #[simd_test(enable = "avx512vbmi")]
     unsafe fn test_mm512_permutex2var_epi8() {
@@ -852,6 +853,29 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn fuzz_mm512_multishift_epi64_epi8() {
+        fuzz_intrinsic!(
+            _mm512_multishift_epi64_epi8(a: [u8; 64], b: [u64; 8]) -> [u8; 64],
+            // Each byte of `a` is a bit offset into the 64-bit element of `b`,
+            // wrapping around at the top.
+            |a, b| core::array::from_fn(|i| b[i / 8].rotate_right(a[i] as u32 % 64) as u8),
+        );
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_multishift_epi64_epi8_unpack_7bit_fields() {
+        // Unpack eight 7-bit fields from each 64-bit element.
+        let fields: [[u8; 8]; 8] =
+            core::array::from_fn(|i| core::array::from_fn(|j| ((i * 8 + j) * 29 % 128) as u8));
+        let packed: [u64; 8] =
+            fields.map(|f| (0..8).fold(0, |packed, j| packed | (f[j] as u64) << (7 * j)));
+        let offsets = _mm512_set1_epi64(0x31_2a_23_1c_15_0e_07_00);
+        let r = _mm512_multishift_epi64_epi8(offsets, transmute(packed));
+        let r: [[u8; 8]; 8] = transmute(_mm512_and_si512(r, _mm512_set1_epi8(0x7f)));
+        assert_eq!(r, fields);
+    }
+
     #[simd_test(enable = "avx512vbmi")]
     unsafe fn test_mm512_mask_multishift_epi64_epi8() {
         let a = _mm512_set1_epi8(1);
@@ -52,6 +52,8 @@ pub unsafe fn _andn_u32(a: u32, b: u32) -> u32 {
 
 /// Extracts lowest set isolated bit.
 ///
+/// Returns `0` if `x` is `0`.
+///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_blsi_u32)
 #[inline]
 #[target_feature(enable = "bmi1")]
@@ -63,6 +65,8 @@ pub unsafe fn _blsi_u32(x: u32) -> u32 {
 
 /// Gets mask up to lowest set bit.
 ///
+/// If `x` is `0`, all bits of the result are set.
+///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_blsmsk_u32)
 #[inline]
 #[target_feature(enable = "bmi1")]
@@ -74,7 +78,7 @@ pub unsafe fn _blsmsk_u32(x: u32) -> u32 {
 
 /// Resets the lowest set bit of `x`.
 ///
-/// If `x` is sets CF.
+/// Returns `0` if `x` is `0`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_blsr_u32)
 #[inline]
@@ -141,6 +145,14 @@ mod tests {
         assert_eq!(r, 0b0000_0101u32);
     }
 
+    #[simd_test(enable = "bmi1")]
+    unsafe fn test_bextr2_u32() {
+        // The start is in bits 0..8 of the control and the length in 8..16.
+        let r = _bextr2_u32(0b0101_0000u32, 4 | 4 << 8);
+        assert_eq!(r, 0b0000_0101u32);
+        assert_eq!(_bextr2_u32(u32::MAX, 0), 0);
+    }
+
     #[simd_test(enable = "bmi1")]
     unsafe fn test_andn_u32() {
         assert_eq!(_andn_u32(0, 0), 0);
@@ -167,19 +179,27 @@ mod tests {
     #[simd_test(enable = "bmi1")]
     unsafe fn test_blsi_u32() {
         assert_eq!(_blsi_u32(0b1101_0000u32), 0b0001_0000u32);
+        assert_eq!(_blsi_u32(0), 0);
+        assert_eq!(_blsi_u32(u32::MAX), 1);
+        assert_eq!(_blsi_u32(1 << 31), 1 << 31);
     }
 
     #[simd_test(enable = "bmi1")]
     unsafe fn test_blsmsk_u32() {
         let r = _blsmsk_u32(0b0011_0000u32);
         assert_eq!(r, 0b0001_1111u32);
+        assert_eq!(_blsmsk_u32(0), u32::MAX);
+        assert_eq!(_blsmsk_u32(1), 1);
+        assert_eq!(_blsmsk_u32(1 << 31), u32::MAX);
     }
 
     #[simd_test(enable = "bmi1")]
     unsafe fn test_blsr_u32() {
-        // TODO: test the behavior when the input is `0`.
         let r = _blsr_u32(0b0011_0000u32);
         assert_eq!(r, 0b0010_0000u32);
+        assert_eq!(_blsr_u32(0), 0);
+        assert_eq!(_blsr_u32(u32::MAX), u32::MAX - 1);
+        assert_eq!(_blsr_u32(1 << 31), 0);
     }
 
     #[simd_test(enable = "bmi1")]
@@ -195,4 +215,11 @@ mod tests {
         assert_eq!(_tzcnt_u32(0b0000_0000u32), 32u32);
         assert_eq!(_tzcnt_u32(0b1001_0000u32), 4u32);
     }
+
+    #[simd_test(enable = "bmi1")]
+    unsafe fn test_mm_tzcnt_32() {
+        assert_eq!(_mm_tzcnt_32(0b0000_0001u32), 0);
+        assert_eq!(_mm_tzcnt_32(0), 32);
+        assert_eq!(_mm_tzcnt_32(0b1001_0000u32), 4);
+    }
 }
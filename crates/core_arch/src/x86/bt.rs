@@ -1,3 +1,20 @@
+//! Bit test intrinsics.
+//!
+//! These are the `_bittest` family of MSVC intrinsics, for code ported
+//! from or interoperating with code that uses them. `p` is the base of a bit
+//! string rather than a single integer: `b` may be any offset, including
+//! negative ones or ones past the first `i32`, and addresses bit
+//! `b.rem_euclid(32)` of the `i32` at `p.offset(b.div_euclid(32))`, which
+//! has to be valid memory.
+//!
+//! The `_interlocked` versions are atomic and can be used on memory shared
+//! with other threads. They need `p` to be aligned like an `AtomicI32`.
+//!
+//! The `bt` family of instructions with a memory operand is slow on most
+//! CPUs, so new code testing the bits of an integer should rather use shifts
+//! and masks, which the compiler turns into `bt` with a register operand when
+//! that's profitable.
+
 use crate::arch::asm;
 #[cfg(test)]
 use stdarch_test::assert_instr;
@@ -94,9 +111,51 @@ pub unsafe fn _bittestandcomplement(p: *mut i32, b: i32) -> u8 {
     r
 }
 
+/// Atomically returns the bit in position `b` of the memory addressed by `p`, then sets the bit to `1`.
+///
+/// This uses a `lock bts` instruction, which is sequentially consistent like an atomic
+/// read-modify-write with [`Ordering::SeqCst`](crate::sync::atomic::Ordering::SeqCst).
+#[inline]
+#[cfg_attr(test, assert_instr(bts))]
+#[unstable(feature = "stdarch_x86_interlocked_bittest", issue = "none")]
+pub unsafe fn _interlockedbittestandset(p: *mut i32, b: i32) -> u8 {
+    let r: u8;
+    asm!(
+        bt!("lock btsl"),
+        "setc {r}",
+        p = in(reg) p,
+        b = in(reg) b,
+        r = out(reg_byte) r,
+        options(nostack, att_syntax)
+    );
+    r
+}
+
+/// Atomically returns the bit in position `b` of the memory addressed by `p`, then resets that bit to `0`.
+///
+/// This uses a `lock btr` instruction, which is sequentially consistent like an atomic
+/// read-modify-write with [`Ordering::SeqCst`](crate::sync::atomic::Ordering::SeqCst).
+#[inline]
+#[cfg_attr(test, assert_instr(btr))]
+#[unstable(feature = "stdarch_x86_interlocked_bittest", issue = "none")]
+pub unsafe fn _interlockedbittestandreset(p: *mut i32, b: i32) -> u8 {
+    let r: u8;
+    asm!(
+        bt!("lock btrl"),
+        "setc {r}",
+        p = in(reg) p,
+        b = in(reg) b,
+        r = out(reg_byte) r,
+        options(nostack, att_syntax)
+    );
+    r
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core_arch::x86::*;
+    use std::sync::atomic::{AtomicI32, Ordering};
+    use std::thread;
 
     #[test]
     #[cfg_attr(miri, ignore)] // Uses inline assembly
@@ -144,4 +203,92 @@ mod tests {
             assert_eq!(_bittestandcomplement(&mut a as _, 5), 1);
         }
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Uses inline assembly
+    fn test_bittest_bit_array() {
+        unsafe {
+            let mut a = [0i32; 4];
+            let p = a.as_mut_ptr();
+            // Offsets past the first element address the following ones.
+            for b in (0..4 * 32).step_by(3) {
+                assert_eq!(_bittestandset(p, b), 0);
+            }
+            for b in 0..4 * 32 {
+                assert_eq!(_bittest(p, b), (b % 3 == 0) as u8);
+            }
+            assert_eq!(*p.add(1), 0x92492492u32 as i32);
+            for b in (0..4 * 32).step_by(2) {
+                assert_eq!(_bittestandcomplement(p, b), (b % 3 == 0) as u8);
+            }
+            for b in 0..4 * 32 {
+                let e = (b % 3 == 0) != (b % 2 == 0);
+                assert_eq!(_bittestandreset(p, b), e as u8);
+            }
+            assert_eq!(a, [0; 4]);
+
+            // Negative offsets address the elements before `p`.
+            *p = 1 << (32 - 1);
+            assert_eq!(_bittest(p.add(2), -33), 1);
+            assert_eq!(_bittestandreset(p.add(2), -33), 1);
+            assert_eq!(*p, 0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Uses inline assembly
+    fn test_interlockedbittestandset() {
+        unsafe {
+            let mut a = 0b0101_0000i32;
+            assert_eq!(_interlockedbittestandset(&mut a as _, 4), 1);
+            assert_eq!(_interlockedbittestandset(&mut a as _, 4), 1);
+            assert_eq!(_interlockedbittestandset(&mut a as _, 5), 0);
+            assert_eq!(_interlockedbittestandset(&mut a as _, 5), 1);
+            assert_eq!(a, 0b0111_0000);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Uses inline assembly
+    fn test_interlockedbittestandreset() {
+        unsafe {
+            let mut a = 0b0101_0000i32;
+            assert_eq!(_interlockedbittestandreset(&mut a as _, 4), 1);
+            assert_eq!(_interlockedbittestandreset(&mut a as _, 4), 0);
+            assert_eq!(_interlockedbittestandreset(&mut a as _, 5), 0);
+            assert_eq!(_interlockedbittestandreset(&mut a as _, 5), 0);
+            assert_eq!(a, 0b0100_0000);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Uses inline assembly
+    fn test_interlockedbittest_concurrent() {
+        const THREADS: i32 = 8;
+        // Thread `t` owns the bits `b` with `b % THREADS == t`, so all threads
+        // modify every element at the same time. Without `lock` they would
+        // overwrite each other's bits.
+        let bits: [AtomicI32; 4] = Default::default();
+        thread::scope(|s| {
+            for t in 0..THREADS {
+                let bits = &bits;
+                s.spawn(move || unsafe {
+                    let p = bits.as_ptr() as *mut i32;
+                    let own = (t..4 * 32).step_by(THREADS as usize);
+                    for _ in 0..10_000 {
+                        for b in own.clone() {
+                            assert_eq!(_interlockedbittestandset(p, b), 0);
+                            assert_eq!(_interlockedbittestandreset(p, b), 1);
+                        }
+                    }
+                    for b in own {
+                        assert_eq!(_interlockedbittestandset(p, b), 0);
+                    }
+                });
+            }
+        });
+        for b in &bits {
+            assert_eq!(b.load(Ordering::SeqCst), -1);
+        }
+    }
 }
@@ -0,0 +1,105 @@
+//! Control-flow Enforcement Technology (CET) shadow stack instructions.
+//!
+//! When shadow stacks are enabled, every `call` also pushes the return
+//! address to a separate, write-protected shadow stack, and every `ret`
+//! checks the return address against it. Runtimes that switch stacks
+//! themselves, such as fiber and coroutine libraries, need these
+//! instructions to keep the shadow stack in sync.
+//!
+//! Whether shadow stacks are enabled for the current thread is up to the
+//! operating system. When they aren't, [`_rdsspd_i32`] returns `0` and the
+//! other instructions raise `#UD`.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 1: Basic Architecture, Chapter 17][intel64_ref].
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Increments the shadow stack pointer by 4 times the low 8 bits of `a`,
+/// popping that many 4-byte entries off the shadow stack.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_incsspd)
+#[inline]
+#[cfg_attr(test, assert_instr(incsspd))]
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub unsafe fn _incsspd(a: i32) {
+    asm!("incsspd {a:e}", a = in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Reads the low 32 bits of the current shadow stack pointer.
+///
+/// Returns `0` if shadow stacks are not enabled.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_rdsspd_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(rdsspd))]
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub unsafe fn _rdsspd_i32() -> i32 {
+    // RDSSP leaves its operand unchanged if shadow stacks are disabled.
+    let mut r = 0;
+    asm!(
+        "rdsspd {r:e}",
+        r = inout(reg) r,
+        options(nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Pushes a restore token for the previous shadow stack onto it, after a
+/// switch with [`_rstorssp`].
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_saveprevssp)
+#[inline]
+#[cfg_attr(test, assert_instr(saveprevssp))]
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub unsafe fn _saveprevssp() {
+    asm!("saveprevssp", options(nostack, preserves_flags));
+}
+
+/// Switches to the shadow stack whose restore token is at `p`.
+///
+/// The token at `p` is checked and replaced with a previous-SSP token, which
+/// [`_saveprevssp`] then uses to make the old shadow stack restorable.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_rstorssp)
+#[inline]
+#[cfg_attr(test, assert_instr(rstorssp))]
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub unsafe fn _rstorssp(p: *mut u8) {
+    asm!("rstorssp [{p}]", p = in(reg) p, options(nostack));
+}
+
+/// Writes `val` to the shadow stack memory at `p`.
+///
+/// This requires the operating system to allow writes to the shadow stack.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_wrssd)
+#[inline]
+#[cfg_attr(test, assert_instr(wrssd))]
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub unsafe fn _wrssd(val: i32, p: *mut i32) {
+    asm!(
+        "wrssd [{p}], {val:e}",
+        p = in(reg) p,
+        val = in(reg) val,
+        options(nostack, preserves_flags)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_rdsspd_i32() {
+        // This is a no-op unless shadow stacks are enabled, so it is safe to
+        // run anywhere.
+        let ssp = unsafe { _rdsspd_i32() };
+        assert_eq!(ssp % 4, 0);
+    }
+}
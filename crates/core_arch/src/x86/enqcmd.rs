@@ -0,0 +1,70 @@
+//! Enqueue store instructions (ENQCMD and ENQCMDS).
+//!
+//! These instructions submit a 64-byte command descriptor to a device through
+//! an enqueue register mapped in MMIO space. The device may decline the
+//! command, for example because its work queue is full; the instruction then
+//! reports that the command should be retried, and software is expected to
+//! try again later or fall back to another submission path.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Reads the 64-byte command at `src` and performs a 64-byte enqueue store of
+/// it to the device enqueue register at `dst`.
+///
+/// `dst` must be 64-byte aligned. The processor replaces the PASID field of
+/// the command with the PASID of the current process, so this may be used
+/// from user mode.
+///
+/// Returns `0` if the device accepted the command, and `1` if it must be
+/// retried.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_enqcmd)
+#[inline]
+#[cfg_attr(test, assert_instr(enqcmd))]
+#[unstable(feature = "stdarch_x86_enqcmd", issue = "none")]
+pub unsafe fn _enqcmd(dst: *mut u8, src: *const u8) -> i32 {
+    let r: u8;
+    asm!(
+        "enqcmd {dst}, [{src}]",
+        "sete {r}",
+        dst = in(reg) dst,
+        src = in(reg) src,
+        r = out(reg_byte) r,
+        options(nostack)
+    );
+    r as i32
+}
+
+/// Reads the 64-byte command at `src` and performs a 64-byte enqueue store of
+/// it to the device enqueue register at `dst`.
+///
+/// Unlike [`_enqcmd`], the PASID and privilege fields of the command are used
+/// as given. This may only be used in privileged mode.
+///
+/// Returns `0` if the device accepted the command, and `1` if it must be
+/// retried.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_enqcmds)
+#[inline]
+#[cfg_attr(test, assert_instr(enqcmds))]
+#[unstable(feature = "stdarch_x86_enqcmd", issue = "none")]
+pub unsafe fn _enqcmds(dst: *mut u8, src: *const u8) -> i32 {
+    let r: u8;
+    asm!(
+        "enqcmds {dst}, [{src}]",
+        "sete {r}",
+        dst = in(reg) dst,
+        src = in(reg) src,
+        r = out(reg_byte) r,
+        options(nostack)
+    );
+    r as i32
+}
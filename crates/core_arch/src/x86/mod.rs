@@ -1008,3 +1008,30 @@ pub use self::avxneconvert::*;
 mod avx512fp16;
 #[unstable(feature = "stdarch_x86_avx512_f16", issue = "127213")]
 pub use self::avx512fp16::*;
+
+mod waitpkg;
+#[unstable(feature = "stdarch_x86_waitpkg", issue = "none")]
+pub use self::waitpkg::*;
+
+mod movdir;
+#[unstable(feature = "stdarch_x86_movdir", issue = "none")]
+pub use self::movdir::*;
+
+mod enqcmd;
+#[unstable(feature = "stdarch_x86_enqcmd", issue = "none")]
+pub use self::enqcmd::*;
+
+mod serialize;
+#[unstable(feature = "stdarch_x86_serialize", issue = "none")]
+pub use self::serialize::*;
+
+mod cet;
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub use self::cet::*;
+
+mod argminmax;
+#[unstable(feature = "stdarch_x86_argminmax", issue = "none")]
+pub use self::argminmax::*;
+
+#[unstable(feature = "stdarch_x86_safe", issue = "none")]
+pub mod safe;
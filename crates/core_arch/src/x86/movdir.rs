@@ -0,0 +1,102 @@
+//! Direct store instructions (MOVDIRI and MOVDIR64B).
+//!
+//! Direct stores bypass the cache hierarchy and write to memory using
+//! write-combining semantics, regardless of the memory type of the
+//! destination. They are mainly useful for submitting work to devices through
+//! memory-mapped I/O.
+//!
+//! `MOVDIRI` stores a doubleword or quadword, and `MOVDIR64B` stores a full
+//! 64-byte cache line as a single atomic write. Direct stores are weakly
+//! ordered with respect to other stores, so use [`_mm_sfence`] to order them.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+//! [`_mm_sfence`]: fn._mm_sfence.html
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Stores the 32-bit integer `val` to `dst` using a direct store.
+///
+/// `dst` does not need to be aligned, but the store is only guaranteed to be
+/// atomic if it is 4-byte aligned.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_directstoreu_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(movdiri))]
+#[unstable(feature = "stdarch_x86_movdir", issue = "none")]
+pub unsafe fn _directstoreu_u32(dst: *mut u32, val: u32) {
+    asm!(
+        "movdiri [{dst}], {val:e}",
+        dst = in(reg) dst,
+        val = in(reg) val,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Copies the 64 bytes at `src` to `dst` as a single 64-byte direct store.
+///
+/// `dst` must be 64-byte aligned. The write to `dst` is performed atomically
+/// as a whole, so a device observing the destination never sees a partially
+/// written cache line. The read from `src` has no alignment requirement and
+/// is not guaranteed to be atomic.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_movdir64b)
+#[inline]
+#[cfg_attr(test, assert_instr(movdir64b))]
+#[unstable(feature = "stdarch_x86_movdir", issue = "none")]
+pub unsafe fn _movdir64b(dst: *mut u8, src: *const u8) {
+    asm!(
+        "movdir64b {dst}, [{src}]",
+        dst = in(reg) dst,
+        src = in(reg) src,
+        options(nostack, preserves_flags)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    /// Returns whether CPUID reports both MOVDIRI and MOVDIR64B, since there
+    /// is no run-time detection for them yet.
+    fn has_movdir() -> bool {
+        let ecx = unsafe { __cpuid_count(7, 0) }.ecx;
+        ecx & (1 << 27) != 0 && ecx & (1 << 28) != 0
+    }
+
+    #[test]
+    fn test_directstoreu_u32() {
+        if !has_movdir() {
+            return;
+        }
+        let mut dst = [0u32; 3];
+        unsafe {
+            _directstoreu_u32(&mut dst[1], 0xdead_beef);
+            _mm_sfence();
+        }
+        assert_eq!(dst, [0, 0xdead_beef, 0]);
+    }
+
+    #[test]
+    fn test_movdir64b() {
+        #[repr(align(64))]
+        struct Line([u8; 64]);
+
+        if !has_movdir() {
+            return;
+        }
+        let src: [u8; 65] = core::array::from_fn(|i| i as u8);
+        let mut dst = Line([0; 64]);
+        unsafe {
+            // The source doesn't need to be aligned.
+            _movdir64b(dst.0.as_mut_ptr(), src[1..].as_ptr());
+            _mm_sfence();
+        }
+        assert_eq!(dst.0[..], src[1..]);
+    }
+}
@@ -36,7 +36,9 @@ pub unsafe fn _rdtsc() -> u64 {
 /// The RDTSCP instruction waits until all previous instructions have
 /// been executed before reading the counter. However, subsequent
 /// instructions may begin execution before the read operation is
-/// performed.
+/// performed. It is therefore only partially serializing: to keep later
+/// instructions from being hoisted above the read, follow it with a
+/// fence such as [`_mm_lfence`](fn._mm_lfence.html).
 ///
 /// On processors that support the Intel 64 architecture, the
 /// high-order 32 bits of each of RAX, RDX, and RCX are cleared.
@@ -49,12 +51,35 @@ pub unsafe fn __rdtscp(aux: *mut u32) -> u64 {
     rdtscp(aux as *mut _)
 }
 
+/// Reads the performance-monitoring counter specified by `a`.
+///
+/// The width and meaning of the counters are implementation specific.
+/// Unless the operating system has set `CR4.PCE`, executing RDPMC outside
+/// of ring 0 raises a general-protection fault.
+///
+/// Like RDTSC, the RDPMC instruction is not a serializing instruction and
+/// may be reordered with respect to surrounding instructions.
+///
+/// This has the name and signature Intel gives it, taking an `int` and
+/// returning a signed `__int64`. The counter is read from EDX:EAX, so it is
+/// also available on 32-bit targets.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_rdpmc)
+#[inline]
+#[cfg_attr(test, assert_instr(rdpmc))]
+#[unstable(feature = "stdarch_x86_rdpmc", issue = "none")]
+pub unsafe fn _rdpmc(a: i32) -> i64 {
+    rdpmc(a)
+}
+
 #[allow(improper_ctypes)]
 extern "C" {
     #[link_name = "llvm.x86.rdtsc"]
     fn rdtsc() -> u64;
     #[link_name = "llvm.x86.rdtscp"]
     fn rdtscp(aux: *mut u8) -> u64;
+    #[link_name = "llvm.x86.rdpmc"]
+    fn rdpmc(a: i32) -> i64;
 }
 
 #[cfg(test)]
@@ -74,4 +99,23 @@ mod tests {
         let r = rdtsc::__rdtscp(&mut aux);
         assert_ne!(r, 0); // The chances of this being 0 are infinitesimal
     }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_rdtsc_monotonic() {
+        let a = _rdtsc();
+        let b = _rdtsc();
+        assert!(b >= a);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_rdtscp_monotonic() {
+        let mut aux_a = 0;
+        let mut aux_b = 0;
+        let a = __rdtscp(&mut aux_a);
+        let b = __rdtscp(&mut aux_b);
+        // The counter is only guaranteed to be monotonic on a single core.
+        if aux_a == aux_b {
+            assert!(b >= a);
+        }
+    }
 }
@@ -60,6 +60,16 @@ pub const _XABORT_NESTED: u32 = 1 << 5;
 /// Specifies the start of a restricted transactional memory (RTM) code region and returns a value
 /// indicating status.
 ///
+/// Returns `_XBEGIN_STARTED` when the transaction has started. If the transaction later aborts,
+/// all of its side effects are discarded and execution resumes as if `_xbegin` had returned
+/// again, this time with the abort status: a combination of the `_XABORT_*` flags describing the
+/// cause, with the code passed to [`_xabort`] in bits 31:24 when `_XABORT_EXPLICIT` is set (see
+/// [`_xabort_code`]). A status of `0` means the abort had none of the listed causes, for example
+/// because of an interrupt or an instruction that can't be executed transactionally.
+///
+/// A transaction is never guaranteed to commit, so there must always be a fallback path that
+/// doesn't use RTM.
+///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_xbegin)
 #[inline]
 #[target_feature(enable = "rtm")]
@@ -82,6 +92,9 @@ pub unsafe fn _xend() {
 
 /// Forces a restricted transactional memory (RTM) region to abort.
 ///
+/// The status returned by [`_xbegin`] then has the `_XABORT_EXPLICIT` flag set, and `IMM8` can be
+/// retrieved from it with [`_xabort_code`]. Outside of a transaction this does nothing.
+///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_xabort)
 #[inline]
 #[target_feature(enable = "rtm")]
@@ -0,0 +1,213 @@
+//! Safe wrappers over intrinsics, gated by feature tokens.
+//!
+//! Intrinsics are `unsafe` to call because executing an instruction that the
+//! CPU doesn't support is undefined behavior. A token is a zero-sized value
+//! that can only be obtained by checking at run time that a feature is
+//! available, so holding one proves that the intrinsics of that feature can be
+//! called. The methods of a token are safe wrappers around those intrinsics.
+//!
+//! This is an experiment that only covers a few AVX2 intrinsics, to find out
+//! whether the approach is worth extending to other features.
+//!
+//! ```ignore
+//! use core::arch::x86_64::safe::Avx2Token;
+//!
+//! fn count_zeros(bytes: &[u8; 32]) -> Option<u32> {
+//!     let avx2 = Avx2Token::detect()?;
+//!     let v = avx2.loadu_si256(bytes);
+//!     let zeros = avx2.cmpeq_epi8(v, avx2.setzero_si256());
+//!     Some(avx2.movemask_epi8(zeros).count_ones())
+//! }
+//! ```
+//!
+//! The wrappers are only inlined into functions that enable the feature
+//! themselves. Performance sensitive code should call them from a function
+//! compiled with `#[target_feature(enable = "avx2")]`, which is sound to call
+//! once a token has been obtained.
+
+use crate::core_arch::x86::*;
+
+/// Returns whether the `cpuid` instruction can be executed.
+///
+/// It is always available on x86_64, except in SGX enclaves, which don't
+/// allow it. On x86 it is available if bit 21 of EFLAGS, the ID flag, can be
+/// toggled.
+#[inline]
+fn has_cpuid() -> bool {
+    #[cfg(target_env = "sgx")]
+    {
+        false
+    }
+    #[cfg(all(not(target_env = "sgx"), target_arch = "x86_64"))]
+    {
+        true
+    }
+    #[cfg(all(not(target_env = "sgx"), target_arch = "x86"))]
+    unsafe {
+        let result: u32;
+        crate::arch::asm!(
+            // Read EFLAGS and keep a copy of it.
+            "pushfd",
+            "pop {result}",
+            "mov {saved}, {result}",
+            // Flip the ID flag and read EFLAGS back.
+            "xor {result}, 0x200000",
+            "push {result}",
+            "popfd",
+            "pushfd",
+            "pop {result}",
+            // Restore the original EFLAGS.
+            "push {saved}",
+            "popfd",
+            "xor {result}, {saved}",
+            result = out(reg) result,
+            saved = out(reg) _,
+            options(nomem),
+        );
+        // Other bits may have changed between `popfd` and `pushfd`, for
+        // example when single stepping in a debugger, so only look at the ID
+        // flag.
+        result & 0x200000 != 0
+    }
+}
+
+/// Proof that the CPU supports AVX2.
+///
+/// The only way to obtain an `Avx2Token` is [`Avx2Token::detect`]. It is
+/// zero-sized and `Copy`, so it can be passed around freely once detected.
+#[derive(Clone, Copy, Debug)]
+#[unstable(feature = "stdarch_x86_safe", issue = "none")]
+pub struct Avx2Token(());
+
+impl Avx2Token {
+    /// Returns a token if the CPU supports AVX2 and the operating system has
+    /// enabled the AVX register state, and `None` otherwise.
+    ///
+    /// This executes `cpuid`, which is slow and may trap into the hypervisor
+    /// in virtual machines, so detect once and keep the token.
+    #[inline]
+    #[unstable(feature = "stdarch_x86_safe", issue = "none")]
+    pub fn detect() -> Option<Self> {
+        // This makes the same checks as `is_x86_feature_detected!("avx2")`,
+        // which isn't available in `core`.
+        if !has_cpuid() {
+            return None;
+        }
+        unsafe {
+            if __get_cpuid_max(0).0 < 7 {
+                return None;
+            }
+            // OSXSAVE and AVX.
+            let ecx = __cpuid(1).ecx;
+            if ecx & (1 << 27) == 0 || ecx & (1 << 28) == 0 {
+                return None;
+            }
+            // The operating system must save both the SSE and the AVX state
+            // on context switches.
+            if _xgetbv(0) & 0b110 != 0b110 {
+                return None;
+            }
+            if __cpuid_count(7, 0).ebx & (1 << 5) == 0 {
+                return None;
+            }
+        }
+        Some(Avx2Token(()))
+    }
+
+    /// Safe version of [`_mm256_loadu_si256`], loading 32 bytes from `mem`.
+    #[inline]
+    #[unstable(feature = "stdarch_x86_safe", issue = "none")]
+    pub fn loadu_si256(self, mem: &[u8; 32]) -> __m256i {
+        unsafe { _mm256_loadu_si256(mem.as_ptr().cast()) }
+    }
+
+    /// Safe version of [`_mm256_storeu_si256`], storing 32 bytes to `mem`.
+    #[inline]
+    #[unstable(feature = "stdarch_x86_safe", issue = "none")]
+    pub fn storeu_si256(self, mem: &mut [u8; 32], a: __m256i) {
+        unsafe { _mm256_storeu_si256(mem.as_mut_ptr().cast(), a) }
+    }
+}
+
+macro_rules! wrappers {
+    ($token:ident: $($name:ident($($arg:ident: $ty:ty),*) -> $ret:ty = $intrinsic:ident;)*) => {
+        impl $token {$(
+            #[doc = concat!("Safe version of [`", stringify!($intrinsic), "`].")]
+            #[inline]
+            #[unstable(feature = "stdarch_x86_safe", issue = "none")]
+            pub fn $name(self, $($arg: $ty),*) -> $ret {
+                unsafe { $intrinsic($($arg),*) }
+            }
+        )*}
+    };
+}
+
+wrappers! {
+    Avx2Token:
+    setzero_si256() -> __m256i = _mm256_setzero_si256;
+    set1_epi32(a: i32) -> __m256i = _mm256_set1_epi32;
+    add_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_add_epi32;
+    sub_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_sub_epi32;
+    mullo_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_mullo_epi32;
+    and_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_and_si256;
+    or_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_or_si256;
+    xor_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_xor_si256;
+    cmpeq_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_cmpeq_epi8;
+    max_epu8(a: __m256i, b: __m256i) -> __m256i = _mm256_max_epu8;
+    min_epu8(a: __m256i, b: __m256i) -> __m256i = _mm256_min_epu8;
+    shuffle_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_shuffle_epi8;
+    movemask_epi8(a: __m256i) -> i32 = _mm256_movemask_epi8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Avx2Token;
+    use std::mem;
+
+    #[test]
+    fn avx2_token_detection() {
+        assert_eq!(
+            Avx2Token::detect().is_some(),
+            is_x86_feature_detected!("avx2")
+        );
+        assert_eq!(mem::size_of::<Avx2Token>(), 0);
+    }
+
+    // None of the calls below need an `unsafe` block.
+    #[test]
+    fn avx2_token_methods_are_safe() {
+        let Some(avx2) = Avx2Token::detect() else {
+            return;
+        };
+        let bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let a = avx2.loadu_si256(&bytes);
+
+        // Reverse the bytes of each 128-bit lane.
+        let reverse: [u8; 32] = core::array::from_fn(|i| 15 - i as u8 % 16);
+        let mut out = [0; 32];
+        avx2.storeu_si256(&mut out, avx2.shuffle_epi8(a, avx2.loadu_si256(&reverse)));
+        let e: [u8; 32] = core::array::from_fn(|i| (i / 16 * 16 + 15 - i % 16) as u8);
+        assert_eq!(out, e);
+
+        // (a + 3) * 2 - 6 == 2 * a for each 32-bit element.
+        let three = avx2.set1_epi32(3);
+        let r = avx2.sub_epi32(
+            avx2.mullo_epi32(avx2.add_epi32(a, three), avx2.set1_epi32(2)),
+            avx2.add_epi32(three, three),
+        );
+        let e = avx2.add_epi32(a, a);
+        assert_eq!(avx2.movemask_epi8(avx2.cmpeq_epi8(r, e)), -1);
+
+        let max = avx2.max_epu8(a, avx2.loadu_si256(&reverse));
+        let min = avx2.min_epu8(a, avx2.loadu_si256(&reverse));
+        avx2.storeu_si256(
+            &mut out,
+            avx2.xor_si256(avx2.or_si256(max, min), avx2.and_si256(max, min)),
+        );
+        let e: [u8; 32] = core::array::from_fn(|i| (i as u8) ^ reverse[i]);
+        assert_eq!(out, e);
+
+        let zero = avx2.setzero_si256();
+        assert_eq!(avx2.movemask_epi8(avx2.cmpeq_epi8(a, zero)), 1);
+    }
+}
@@ -0,0 +1,43 @@
+//! Instruction stream serialization (SERIALIZE).
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Serializes instruction execution.
+///
+/// All modifications to flags, registers and memory by previous instructions
+/// are completed, and all buffered writes are drained to memory, before the
+/// next instruction is fetched and executed. This makes it suitable to fence
+/// off code being timed, or to stop speculative execution from running past
+/// a security check.
+///
+/// Unlike [`__cpuid`](fn.__cpuid.html), which is also serializing, this
+/// doesn't clobber any registers and doesn't cause a VM exit when running
+/// under a hypervisor.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_serialize)
+#[inline]
+#[cfg_attr(test, assert_instr(serialize))]
+#[unstable(feature = "stdarch_x86_serialize", issue = "none")]
+pub unsafe fn _serialize() {
+    asm!("serialize", options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_serialize() {
+        if is_x86_feature_detected!("serialize") {
+            unsafe { _serialize() };
+        }
+    }
+}
@@ -2003,7 +2003,7 @@ pub unsafe fn _mm_stream_ps(mem_addr: *mut f32, a: __m128) {
 mod tests {
     use crate::{hint::black_box, mem::transmute, ptr};
     use std::{boxed, f32::NAN};
-    use stdarch_test::simd_test;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     use crate::core_arch::{simd::*, x86::*};
 
@@ -2736,6 +2736,27 @@ mod tests {
         let aa = &[3.0f32, 12.0, 23.0, NAN];
         let bb = &[3.0f32, 47.5, 1.5, NAN];
 
+        let ee = &[0i32, 0, 1, 0];
+
+        for i in 0..4 {
+            let a = _mm_setr_ps(aa[i], 1.0, 2.0, 3.0);
+            let b = _mm_setr_ps(bb[i], 0.0, 2.0, 4.0);
+
+            let r = _mm_comigt_ss(a, b);
+
+            assert_eq!(
+                ee[i], r,
+                "_mm_comigt_ss({:?}, {:?}) = {}, expected: {} (i={})",
+                a, b, r, ee[i], i
+            );
+        }
+    }
+
+    #[simd_test(enable = "sse")]
+    unsafe fn test_mm_comige_ss() {
+        let aa = &[3.0f32, 12.0, 23.0, NAN];
+        let bb = &[3.0f32, 47.5, 1.5, NAN];
+
         let ee = &[1i32, 0, 1, 0];
 
         for i in 0..4 {
@@ -3409,4 +3430,20 @@ mod tests {
             assert_eq!(mem.data[i], get_m128(a, i));
         }
     }
+
+    #[simd_test(enable = "sse")]
+    unsafe fn fuzz_mm_add_ps() {
+        fuzz_intrinsic!(
+            _mm_add_ps(a: [f32; 4], b: [f32; 4]) -> [f32; 4],
+            |a, b| core::array::from_fn(|i| a[i] + b[i]),
+        );
+    }
+
+    #[simd_test(enable = "sse")]
+    unsafe fn fuzz_mm_mul_ps() {
+        fuzz_intrinsic!(
+            _mm_mul_ps(a: [f32; 4], b: [f32; 4]) -> [f32; 4],
+            |a, b| core::array::from_fn(|i| a[i] * b[i]),
+        );
+    }
 }
@@ -3053,7 +3053,7 @@ mod tests {
         mem::{self, transmute},
         ptr,
     };
-    use stdarch_test::simd_test;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     #[test]
     fn test_mm_pause() {
@@ -3352,6 +3352,16 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[simd_test(enable = "sse2")]
+    unsafe fn fuzz_mm_sad_epu8() {
+        fuzz_intrinsic!(
+            _mm_sad_epu8(a: [u8; 16], b: [u8; 16]) -> [u64; 2],
+            |a, b| core::array::from_fn(|i| {
+                (8 * i..8 * i + 8).map(|j| a[j].abs_diff(b[j]) as u64).sum()
+            }),
+        );
+    }
+
     #[simd_test(enable = "sse2")]
     unsafe fn test_mm_sub_epi8() {
         let (a, b) = (_mm_set1_epi8(5), _mm_set1_epi8(6));
@@ -4659,6 +4669,43 @@ mod tests {
         assert!(_mm_ucomineq_sd(a, b) == 0);
     }
 
+    #[allow(deprecated)] // FIXME: This test uses deprecated CSR access functions
+    #[simd_test(enable = "sse2")]
+    #[cfg_attr(miri, ignore)] // Uses _mm_setcsr, which is not supported by Miri
+    unsafe fn test_mm_comi_sd_vs_ucomi_sd() {
+        // With a quiet NaN operand, every predicate but `neq` is false. The
+        // `comi` forms signal an Invalid Operation Exception, while the
+        // `ucomi` forms only do so for signalling NaNs.
+        macro_rules! check {
+            ($($comi:ident, $ucomi:ident => $e:literal;)*) => {$(
+                for (a, b) in [(NAN, 1.0), (1.0, NAN), (NAN, NAN)] {
+                    let a = _mm_setr_pd(a, 2.0);
+                    let b = _mm_setr_pd(b, 3.0);
+
+                    _MM_SET_EXCEPTION_STATE(0);
+                    let r = $comi(*black_box(&a), b);
+                    let s = _MM_GET_EXCEPTION_STATE();
+                    assert_eq!(r, $e, "{}({:?}, {:?})", stringify!($comi), a, b);
+                    assert_eq!(s, _MM_EXCEPT_INVALID, "{} flags", stringify!($comi));
+
+                    _MM_SET_EXCEPTION_STATE(0);
+                    let r = $ucomi(*black_box(&a), b);
+                    let s = _MM_GET_EXCEPTION_STATE();
+                    assert_eq!(r, $e, "{}({:?}, {:?})", stringify!($ucomi), a, b);
+                    assert_eq!(s, 0, "{} flags", stringify!($ucomi));
+                }
+            )*};
+        }
+        check!(
+            _mm_comieq_sd, _mm_ucomieq_sd => 0;
+            _mm_comilt_sd, _mm_ucomilt_sd => 0;
+            _mm_comile_sd, _mm_ucomile_sd => 0;
+            _mm_comigt_sd, _mm_ucomigt_sd => 0;
+            _mm_comige_sd, _mm_ucomige_sd => 0;
+            _mm_comineq_sd, _mm_ucomineq_sd => 1;
+        );
+    }
+
     #[simd_test(enable = "sse2")]
     unsafe fn test_mm_movemask_pd() {
         let r = _mm_movemask_pd(_mm_setr_pd(-1.0, 5.0));
@@ -5174,4 +5221,28 @@ mod tests {
         let r = _mm_castsi128_ps(a);
         assert_eq_m128(r, expected);
     }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn fuzz_mm_adds_epu8() {
+        fuzz_intrinsic!(
+            _mm_adds_epu8(a: [u8; 16], b: [u8; 16]) -> [u8; 16],
+            |a, b| core::array::from_fn(|i| a[i].saturating_add(b[i])),
+        );
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn fuzz_mm_mulhi_epi16() {
+        fuzz_intrinsic!(
+            _mm_mulhi_epi16(a: [i16; 8], b: [i16; 8]) -> [i16; 8],
+            |a, b| core::array::from_fn(|i| ((a[i] as i32 * b[i] as i32) >> 16) as i16),
+        );
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn fuzz_mm_avg_epu8() {
+        fuzz_intrinsic!(
+            _mm_avg_epu8(a: [u8; 16], b: [u8; 16]) -> [u8; 16],
+            |a, b| core::array::from_fn(|i| ((a[i] as u16 + b[i] as u16 + 1) >> 1) as u8),
+        );
+    }
 }
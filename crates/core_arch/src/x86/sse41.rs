@@ -1277,6 +1277,31 @@ mod tests {
         assert_eq!(r, 3.0);
     }
 
+    #[simd_test(enable = "sse4.1")]
+    unsafe fn test_mm_insert_extract_epi8_epi32_round_trip() {
+        let a = _mm_setr_epi8(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        macro_rules! check_epi8 {
+            ($($n:literal),*) => {$(
+                // The extracted byte is zero-extended.
+                let r = _mm_insert_epi8::<$n>(a, -$n - 100);
+                assert_eq!(_mm_extract_epi8::<$n>(r), (-$n - 100) as u8 as i32);
+                // Every other lane is left alone.
+                let mask = _mm_cmpeq_epi8(r, a);
+                assert_eq!(_mm_movemask_epi8(mask), 0xffff & !(1 << $n));
+            )*};
+        }
+        check_epi8!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        macro_rules! check_epi32 {
+            ($($n:literal),*) => {$(
+                let r = _mm_insert_epi32::<$n>(a, i32::MIN + $n);
+                assert_eq!(_mm_extract_epi32::<$n>(r), i32::MIN + $n);
+                let mask = _mm_cmpeq_epi32(r, a);
+                assert_eq!(_mm_movemask_ps(_mm_castsi128_ps(mask)), 0xf & !(1 << $n));
+            )*};
+        }
+        check_epi32!(0, 1, 2, 3);
+    }
+
     #[simd_test(enable = "sse4.1")]
     unsafe fn test_mm_extract_epi8() {
         #[rustfmt::skip]
@@ -1780,6 +1805,33 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[simd_test(enable = "sse4.1")]
+    unsafe fn test_mm_minpos_epu16_4() {
+        // Compare against a scalar argmin where the lowest index wins ties
+        let inputs: [[u16; 8]; 5] = [
+            [7, 7, 7, 7, 7, 7, 7, 7],
+            [u16::MAX, 0x8000, 0x7fff, 0x8000, u16::MAX, 0x7fff, 1, 2],
+            [9, 8, 7, 6, 5, 4, 3, 3],
+            [u16::MAX; 8],
+            [
+                0x8001, 0x8000, 0x8002, 0x8000, 0x9000, 0xa000, 0xb000, 0xc000,
+            ],
+        ];
+        for input in inputs {
+            let mut min = input[0];
+            let mut idx = 0;
+            for (i, &x) in input.iter().enumerate() {
+                if x < min {
+                    min = x;
+                    idx = i;
+                }
+            }
+            let a: __m128i = transmute(input);
+            let r: [u16; 8] = transmute(_mm_minpos_epu16(a));
+            assert_eq!(r, [min, idx as u16, 0, 0, 0, 0, 0, 0]);
+        }
+    }
+
     #[simd_test(enable = "sse4.1")]
     unsafe fn test_mm_mul_epi32() {
         {
@@ -1859,6 +1911,28 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[simd_test(enable = "sse4.1")]
+    unsafe fn test_mm_mpsadbw_epu8_every_offset() {
+        let a: [u8; 16] = core::array::from_fn(|i| (i * 97 + 13) as u8);
+        let b: [u8; 16] = core::array::from_fn(|i| (i * 61 + 200) as u8);
+        // The eight overlapping 4-byte windows of `a` starting at byte
+        // `IMM8[2] * 4` are each compared against the 4 bytes of `b` starting
+        // at byte `IMM8[1:0] * 4`.
+        let reference = |imm8: usize| -> [u16; 8] {
+            let (i, j) = ((imm8 >> 2) * 4, (imm8 & 0b11) * 4);
+            core::array::from_fn(|k| (0..4).map(|n| a[i + k + n].abs_diff(b[j + n]) as u16).sum())
+        };
+        let va = _mm_loadu_si128(a.as_ptr() as *const _);
+        let vb = _mm_loadu_si128(b.as_ptr() as *const _);
+        macro_rules! check {
+            ($($imm8:literal)*) => {$(
+                let r: [u16; 8] = mem::transmute(_mm_mpsadbw_epu8::<$imm8>(va, vb));
+                assert_eq!(r, reference($imm8), "IMM8 = {:#05b}", $imm8);
+            )*};
+        }
+        check!(0 1 2 3 4 5 6 7);
+    }
+
     #[simd_test(enable = "sse4.1")]
     unsafe fn test_mm_testz_si128() {
         let a = _mm_set1_epi8(1);
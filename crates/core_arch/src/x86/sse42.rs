@@ -719,6 +719,52 @@ mod tests {
         assert_eq!(3, i);
     }
 
+    #[simd_test(enable = "sse4.2")]
+    unsafe fn test_mm_cmpestri_substring_search() {
+        // Finds the first occurrence of `needle` in `haystack` 16 bytes at a
+        // time. A match running past the end of a chunk is reported at its
+        // start, so the search resumes from there to check it in full.
+        #[target_feature(enable = "sse4.2")]
+        unsafe fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+            let a = str_to_m128i(needle);
+            let mut offset = 0;
+            while offset < haystack.len() {
+                let chunk = &haystack[offset..haystack.len().min(offset + 16)];
+                let b = str_to_m128i(chunk);
+                let i = _mm_cmpestri::<_SIDD_CMP_EQUAL_ORDERED>(
+                    a,
+                    needle.len() as i32,
+                    b,
+                    chunk.len() as i32,
+                ) as usize;
+                if i == 16 {
+                    offset += 16;
+                } else if i + needle.len() <= 16 {
+                    return Some(offset + i);
+                } else {
+                    offset += i;
+                }
+            }
+            None
+        }
+
+        let haystack = b"the quick brown fox jumps over the lazy dog, then naps";
+        for needle in [
+            &b"the"[..],
+            b"fox",
+            b"brown fox jumps",
+            b"over the lazy",
+            b"g, then",
+            b"naps",
+            b"cat",
+            b"dog, then naps!",
+            b"t",
+        ] {
+            let expected = haystack.windows(needle.len()).position(|w| w == needle);
+            assert_eq!(find(haystack, needle), expected, "{needle:?}");
+        }
+    }
+
     #[simd_test(enable = "sse4.2")]
     unsafe fn test_mm_cmpestrz() {
         let a = str_to_m128i(b"");
@@ -333,7 +333,7 @@ extern "C" {
 
 #[cfg(test)]
 mod tests {
-    use stdarch_test::simd_test;
+    use stdarch_test::{fuzz_intrinsic, simd_test};
 
     use crate::core_arch::x86::*;
 
@@ -355,6 +355,40 @@ mod tests {
         assert_eq_m128i(r, _mm_set1_epi32(5));
     }
 
+    #[simd_test(enable = "ssse3")]
+    unsafe fn test_mm_abs_epi_min_wraps() {
+        // The absolute value of the minimum doesn't fit, and wraps around to
+        // the minimum itself, which is the same bit pattern as the unsigned
+        // result.
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            i8::MIN, -i8::MAX, -1, 0, 1, i8::MAX, i8::MIN, 7,
+            0, 0, 0, 0, 0, 0, 0, -7,
+        );
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            i8::MIN, i8::MAX, 1, 0, 1, i8::MAX, i8::MIN, 7,
+            0, 0, 0, 0, 0, 0, 0, 7,
+        );
+        assert_eq_m128i(_mm_abs_epi8(a), e);
+        let a = _mm_setr_epi16(i16::MIN, -i16::MAX, -1, 0, 1, i16::MAX, i16::MIN, -7);
+        let e = _mm_setr_epi16(i16::MIN, i16::MAX, 1, 0, 1, i16::MAX, i16::MIN, 7);
+        assert_eq_m128i(_mm_abs_epi16(a), e);
+        let r = _mm_abs_epi32(_mm_setr_epi32(i32::MIN, -i32::MAX, -1, i32::MIN));
+        let e = _mm_setr_epi32(i32::MIN, i32::MAX, 1, i32::MIN);
+        assert_eq_m128i(r, e);
+        let r: [u32; 4] = transmute(r);
+        assert_eq!(r, [1 << 31, i32::MAX as u32, 1, 1 << 31]);
+    }
+
+    #[simd_test(enable = "ssse3")]
+    unsafe fn fuzz_mm_abs_epi16() {
+        fuzz_intrinsic!(
+            _mm_abs_epi16(a: [i16; 8]) -> [u16; 8],
+            |a| a.map(i16::unsigned_abs),
+        );
+    }
+
     #[simd_test(enable = "ssse3")]
     unsafe fn test_mm_shuffle_epi8() {
         #[rustfmt::skip]
@@ -645,4 +679,17 @@ mod tests {
         let r = _mm_sign_epi32(a, b);
         assert_eq_m128i(r, expected);
     }
+
+    #[simd_test(enable = "ssse3")]
+    unsafe fn fuzz_mm_hadds_epi16() {
+        fuzz_intrinsic!(
+            _mm_hadds_epi16(a: [i16; 8], b: [i16; 8]) -> [i16; 8],
+            |a, b| {
+                core::array::from_fn(|i| {
+                    let src = if i < 4 { a } else { b };
+                    src[i % 4 * 2].saturating_add(src[i % 4 * 2 + 1])
+                })
+            },
+        );
+    }
 }
@@ -166,3 +166,21 @@ pub unsafe fn assert_eq_m512h(a: __m512h, b: __m512h) {
         panic!("{:?} != {:?}", a, b);
     }
 }
+
+/// `N` elements followed by a guard of `G` elements, to check that masked
+/// loads and stores through a pointer to `buf` don't access anything past
+/// the active elements.
+#[repr(C)]
+pub struct Guarded<T, const N: usize, const G: usize> {
+    pub buf: [T; N],
+    pub guard: [T; G],
+}
+
+impl<T: Copy, const N: usize, const G: usize> Guarded<T, N, G> {
+    pub fn new(buf: [T; N], guard: T) -> Self {
+        Self {
+            buf,
+            guard: [guard; G],
+        }
+    }
+}
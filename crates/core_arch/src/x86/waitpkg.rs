@@ -0,0 +1,98 @@
+//! User-level wait and pause instructions (WAITPKG).
+//!
+//! These instructions are available on Intel Tremont and Alder Lake or later
+//! CPUs. They let a thread wait for a store to a monitored address range, or
+//! simply pause, in an implementation-dependent optimized state until the
+//! time-stamp counter reaches a deadline.
+//!
+//! The deadline passed to [`_tpause`] and [`_umwait`] is an absolute TSC value
+//! (as returned by [`_rdtsc`](fn._rdtsc.html)), not a relative duration. The
+//! operating system may additionally cap the wait time through the
+//! `IA32_UMWAIT_CONTROL` MSR; in that case the wait ends early and the
+//! intrinsic returns `1`.
+//!
+//! Bit 0 of `ctrl` selects the optimized state: `0` requests the deeper C0.2
+//! state with lower power but slower wakeup, `1` requests the lighter C0.1
+//! state with faster wakeup. All other bits are reserved and must be zero.
+//!
+//! The 64-bit deadline is passed in EDX:EAX, so these are also available on
+//! 32-bit targets. rustc doesn't accept `waitpkg` as a target feature yet, so
+//! these are written in inline assembly and don't enable it.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Directs the processor to enter an implementation-dependent optimized state
+/// until the time-stamp counter reaches or exceeds `counter`.
+///
+/// Returns `1` if the wait ended because the OS-imposed time limit expired
+/// before the deadline was reached, and `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tpause)
+#[inline]
+#[cfg_attr(test, assert_instr(tpause))]
+#[unstable(feature = "stdarch_x86_waitpkg", issue = "none")]
+pub unsafe fn _tpause(ctrl: u32, counter: u64) -> u8 {
+    let r: u8;
+    asm!(
+        "tpause {ctrl:e}",
+        "setc {r}",
+        ctrl = in(reg) ctrl,
+        r = out(reg_byte) r,
+        in("edx") (counter >> 32) as u32,
+        in("eax") counter as u32,
+        options(nomem, nostack)
+    );
+    r
+}
+
+/// Directs the processor to enter an implementation-dependent optimized state
+/// while monitoring the address range armed by [`_umonitor`].
+///
+/// The processor wakes up when a store hits the monitored range, or when the
+/// time-stamp counter reaches or exceeds `counter`, whichever happens first.
+///
+/// Returns `1` if the wait ended because the OS-imposed time limit expired,
+/// and `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_umwait)
+#[inline]
+#[cfg_attr(test, assert_instr(umwait))]
+#[unstable(feature = "stdarch_x86_waitpkg", issue = "none")]
+pub unsafe fn _umwait(ctrl: u32, counter: u64) -> u8 {
+    let r: u8;
+    asm!(
+        "umwait {ctrl:e}",
+        "setc {r}",
+        ctrl = in(reg) ctrl,
+        r = out(reg_byte) r,
+        in("edx") (counter >> 32) as u32,
+        in("eax") counter as u32,
+        options(nostack)
+    );
+    r
+}
+
+/// Sets up a linear address range starting at `a` to be monitored by
+/// hardware, and activates the monitor for a subsequent [`_umwait`].
+///
+/// The address range should be of write-back memory caching type.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_umonitor)
+#[inline]
+#[cfg_attr(test, assert_instr(umonitor))]
+#[unstable(feature = "stdarch_x86_waitpkg", issue = "none")]
+pub unsafe fn _umonitor(a: *mut u8) {
+    asm!(
+        "umonitor {a}",
+        a = in(reg) a,
+        options(readonly, nostack, preserves_flags)
+    );
+}
@@ -42,4 +42,19 @@ mod tests {
         let e: __mmask64 = 0b11001100_00110011_01100110_10011001;
         assert_eq!(r, e);
     }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtmask64_round_trip() {
+        let ones = _mm512_set1_epi8(-1);
+        let patterns = (0..64)
+            .map(|i| 1 << i)
+            .chain((0..64).map(|i: u64| i.wrapping_mul(0x9e37_79b9_7f4a_7c15)))
+            .chain([0, u64::MAX, 0x5555_5555_5555_5555, 0xaaaa_aaaa_aaaa_aaaa]);
+        for k in patterns {
+            assert_eq!(_cvtu64_mask64(_cvtmask64_u64(k)), k);
+            // The integer selects the same elements as the mask.
+            let v = _mm512_maskz_mov_epi8(_cvtu64_mask64(k), ones);
+            assert_eq!(_cvtmask64_u64(_mm512_movepi8_mask(v)), k);
+        }
+    }
 }
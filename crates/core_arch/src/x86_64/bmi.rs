@@ -54,6 +54,8 @@ pub unsafe fn _andn_u64(a: u64, b: u64) -> u64 {
 
 /// Extracts lowest set isolated bit.
 ///
+/// Returns `0` if `x` is `0`.
+///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_blsi_u64)
 #[inline]
 #[target_feature(enable = "bmi1")]
@@ -66,6 +68,8 @@ pub unsafe fn _blsi_u64(x: u64) -> u64 {
 
 /// Gets mask up to lowest set bit.
 ///
+/// If `x` is `0`, all bits of the result are set.
+///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_blsmsk_u64)
 #[inline]
 #[target_feature(enable = "bmi1")]
@@ -78,7 +82,7 @@ pub unsafe fn _blsmsk_u64(x: u64) -> u64 {
 
 /// Resets the lowest set bit of `x`.
 ///
-/// If `x` is sets CF.
+/// Returns `0` if `x` is `0`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_blsr_u64)
 #[inline]
@@ -133,6 +137,14 @@ mod tests {
         assert_eq!(r, 0b0000_0101u64);
     }
 
+    #[simd_test(enable = "bmi1")]
+    unsafe fn test_bextr2_u64() {
+        // The start is in bits 0..8 of the control and the length in 8..16.
+        let r = _bextr2_u64(0b0101_0000u64, 4 | 4 << 8);
+        assert_eq!(r, 0b0000_0101u64);
+        assert_eq!(_bextr2_u64(u64::MAX, 0), 0);
+    }
+
     #[simd_test(enable = "bmi1")]
     unsafe fn test_andn_u64() {
         assert_eq!(_andn_u64(0, 0), 0);
@@ -159,19 +171,27 @@ mod tests {
     #[simd_test(enable = "bmi1")]
     unsafe fn test_blsi_u64() {
         assert_eq!(_blsi_u64(0b1101_0000u64), 0b0001_0000u64);
+        assert_eq!(_blsi_u64(0), 0);
+        assert_eq!(_blsi_u64(u64::MAX), 1);
+        assert_eq!(_blsi_u64(1 << 63), 1 << 63);
     }
 
     #[simd_test(enable = "bmi1")]
     unsafe fn test_blsmsk_u64() {
         let r = _blsmsk_u64(0b0011_0000u64);
         assert_eq!(r, 0b0001_1111u64);
+        assert_eq!(_blsmsk_u64(0), u64::MAX);
+        assert_eq!(_blsmsk_u64(1), 1);
+        assert_eq!(_blsmsk_u64(1 << 63), u64::MAX);
     }
 
     #[simd_test(enable = "bmi1")]
     unsafe fn test_blsr_u64() {
-        // TODO: test the behavior when the input is `0`.
         let r = _blsr_u64(0b0011_0000u64);
         assert_eq!(r, 0b0010_0000u64);
+        assert_eq!(_blsr_u64(0), 0);
+        assert_eq!(_blsr_u64(u64::MAX), u64::MAX - 1);
+        assert_eq!(_blsr_u64(1 << 63), 0);
     }
 
     #[simd_test(enable = "bmi1")]
@@ -180,4 +200,11 @@ mod tests {
         assert_eq!(_tzcnt_u64(0b0000_0000u64), 64u64);
         assert_eq!(_tzcnt_u64(0b1001_0000u64), 4u64);
     }
+
+    #[simd_test(enable = "bmi1")]
+    unsafe fn test_mm_tzcnt_64() {
+        assert_eq!(_mm_tzcnt_64(0b0000_0001u64), 0);
+        assert_eq!(_mm_tzcnt_64(0), 64);
+        assert_eq!(_mm_tzcnt_64(0b1001_0000u64), 4);
+    }
 }
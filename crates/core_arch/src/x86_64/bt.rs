@@ -1,3 +1,20 @@
+//! Bit test intrinsics.
+//!
+//! These are the `_bittest64` family of MSVC intrinsics, for code ported
+//! from or interoperating with code that uses them. `p` is the base of a bit
+//! string rather than a single integer: `b` may be any offset, including
+//! negative ones or ones past the first `i64`, and addresses bit
+//! `b.rem_euclid(64)` of the `i64` at `p.offset(b.div_euclid(64))`, which
+//! has to be valid memory.
+//!
+//! The `_interlocked` versions are atomic and can be used on memory shared
+//! with other threads. They need `p` to be aligned like an `AtomicI64`.
+//!
+//! The `bt` family of instructions with a memory operand is slow on most
+//! CPUs, so new code testing the bits of an integer should rather use shifts
+//! and masks, which the compiler turns into `bt` with a register operand when
+//! that's profitable.
+
 use crate::arch::asm;
 #[cfg(test)]
 use stdarch_test::assert_instr;
@@ -94,9 +111,51 @@ pub unsafe fn _bittestandcomplement64(p: *mut i64, b: i64) -> u8 {
     r
 }
 
+/// Atomically returns the bit in position `b` of the memory addressed by `p`, then sets the bit to `1`.
+///
+/// This uses a `lock bts` instruction, which is sequentially consistent like an atomic
+/// read-modify-write with [`Ordering::SeqCst`](crate::sync::atomic::Ordering::SeqCst).
+#[inline]
+#[cfg_attr(test, assert_instr(bts))]
+#[unstable(feature = "stdarch_x86_interlocked_bittest", issue = "none")]
+pub unsafe fn _interlockedbittestandset64(p: *mut i64, b: i64) -> u8 {
+    let r: u8;
+    asm!(
+        bt!("lock btsq"),
+        "setc {r}",
+        p = in(reg) p,
+        b = in(reg) b,
+        r = out(reg_byte) r,
+        options(nostack, att_syntax)
+    );
+    r
+}
+
+/// Atomically returns the bit in position `b` of the memory addressed by `p`, then resets that bit to `0`.
+///
+/// This uses a `lock btr` instruction, which is sequentially consistent like an atomic
+/// read-modify-write with [`Ordering::SeqCst`](crate::sync::atomic::Ordering::SeqCst).
+#[inline]
+#[cfg_attr(test, assert_instr(btr))]
+#[unstable(feature = "stdarch_x86_interlocked_bittest", issue = "none")]
+pub unsafe fn _interlockedbittestandreset64(p: *mut i64, b: i64) -> u8 {
+    let r: u8;
+    asm!(
+        bt!("lock btrq"),
+        "setc {r}",
+        p = in(reg) p,
+        b = in(reg) b,
+        r = out(reg_byte) r,
+        options(nostack, att_syntax)
+    );
+    r
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core_arch::x86_64::*;
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::thread;
 
     #[test]
     #[cfg_attr(miri, ignore)] // Uses inline assembly
@@ -144,4 +203,92 @@ mod tests {
             assert_eq!(_bittestandcomplement64(&mut a as _, 5), 1);
         }
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Uses inline assembly
+    fn test_bittest64_bit_array() {
+        unsafe {
+            let mut a = [0i64; 4];
+            let p = a.as_mut_ptr();
+            // Offsets past the first element address the following ones.
+            for b in (0..4 * 64).step_by(3) {
+                assert_eq!(_bittestandset64(p, b), 0);
+            }
+            for b in 0..4 * 64 {
+                assert_eq!(_bittest64(p, b), (b % 3 == 0) as u8);
+            }
+            assert_eq!(*p.add(1), 0x4924924924924924);
+            for b in (0..4 * 64).step_by(2) {
+                assert_eq!(_bittestandcomplement64(p, b), (b % 3 == 0) as u8);
+            }
+            for b in 0..4 * 64 {
+                let e = (b % 3 == 0) != (b % 2 == 0);
+                assert_eq!(_bittestandreset64(p, b), e as u8);
+            }
+            assert_eq!(a, [0; 4]);
+
+            // Negative offsets address the elements before `p`.
+            *p = 1 << (64 - 1);
+            assert_eq!(_bittest64(p.add(2), -65), 1);
+            assert_eq!(_bittestandreset64(p.add(2), -65), 1);
+            assert_eq!(*p, 0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Uses inline assembly
+    fn test_interlockedbittestandset64() {
+        unsafe {
+            let mut a = 0b0101_0000i64;
+            assert_eq!(_interlockedbittestandset64(&mut a as _, 4), 1);
+            assert_eq!(_interlockedbittestandset64(&mut a as _, 4), 1);
+            assert_eq!(_interlockedbittestandset64(&mut a as _, 5), 0);
+            assert_eq!(_interlockedbittestandset64(&mut a as _, 5), 1);
+            assert_eq!(a, 0b0111_0000);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Uses inline assembly
+    fn test_interlockedbittestandreset64() {
+        unsafe {
+            let mut a = 0b0101_0000i64;
+            assert_eq!(_interlockedbittestandreset64(&mut a as _, 4), 1);
+            assert_eq!(_interlockedbittestandreset64(&mut a as _, 4), 0);
+            assert_eq!(_interlockedbittestandreset64(&mut a as _, 5), 0);
+            assert_eq!(_interlockedbittestandreset64(&mut a as _, 5), 0);
+            assert_eq!(a, 0b0100_0000);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Uses inline assembly
+    fn test_interlockedbittest64_concurrent() {
+        const THREADS: i64 = 8;
+        // Thread `t` owns the bits `b` with `b % THREADS == t`, so all threads
+        // modify every element at the same time. Without `lock` they would
+        // overwrite each other's bits.
+        let bits: [AtomicI64; 4] = Default::default();
+        thread::scope(|s| {
+            for t in 0..THREADS {
+                let bits = &bits;
+                s.spawn(move || unsafe {
+                    let p = bits.as_ptr() as *mut i64;
+                    let own = (t..4 * 64).step_by(THREADS as usize);
+                    for _ in 0..10_000 {
+                        for b in own.clone() {
+                            assert_eq!(_interlockedbittestandset64(p, b), 0);
+                            assert_eq!(_interlockedbittestandreset64(p, b), 1);
+                        }
+                    }
+                    for b in own {
+                        assert_eq!(_interlockedbittestandset64(p, b), 0);
+                    }
+                });
+            }
+        });
+        for b in &bits {
+            assert_eq!(b.load(Ordering::SeqCst), -1);
+        }
+    }
 }
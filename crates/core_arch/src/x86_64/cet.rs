@@ -0,0 +1,74 @@
+//! Control-flow Enforcement Technology (CET) shadow stack instructions.
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Increments the shadow stack pointer by 8 times the low 8 bits of `a`,
+/// popping that many 8-byte entries off the shadow stack.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_incsspq)
+#[inline]
+#[cfg_attr(test, assert_instr(incsspq))]
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub unsafe fn _incsspq(a: i32) {
+    asm!(
+        "incsspq {a}",
+        a = in(reg) a as i64,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Reads the current shadow stack pointer.
+///
+/// Returns `0` if shadow stacks are not enabled.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_rdsspq_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(rdsspq))]
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub unsafe fn _rdsspq_i64() -> i64 {
+    // RDSSP leaves its operand unchanged if shadow stacks are disabled.
+    let mut r = 0;
+    asm!(
+        "rdsspq {r}",
+        r = inout(reg) r,
+        options(nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Writes `val` to the shadow stack memory at `p`.
+///
+/// This requires the operating system to allow writes to the shadow stack.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_wrssq)
+#[inline]
+#[cfg_attr(test, assert_instr(wrssq))]
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub unsafe fn _wrssq(val: i64, p: *mut i64) {
+    asm!(
+        "wrssq [{p}], {val}",
+        p = in(reg) p,
+        val = in(reg) val,
+        options(nostack, preserves_flags)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_rdsspq_i64() {
+        // This is a no-op unless shadow stacks are enabled, so it is safe to
+        // run anywhere.
+        unsafe {
+            let ssp = _rdsspq_i64();
+            assert_eq!(ssp % 8, 0);
+            assert_eq!(_rdsspd_i32(), ssp as i32);
+        }
+    }
+}
@@ -77,3 +77,11 @@ pub use self::bt::*;
 mod avx512fp16;
 #[unstable(feature = "stdarch_x86_avx512_f16", issue = "127213")]
 pub use self::avx512fp16::*;
+
+mod movdir;
+#[unstable(feature = "stdarch_x86_movdir", issue = "none")]
+pub use self::movdir::*;
+
+mod cet;
+#[unstable(feature = "stdarch_x86_shstk", issue = "none")]
+pub use self::cet::*;
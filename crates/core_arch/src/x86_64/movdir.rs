@@ -0,0 +1,43 @@
+//! Direct store instructions (MOVDIRI).
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Stores the 64-bit integer `val` to `dst` using a direct store.
+///
+/// `dst` does not need to be aligned, but the store is only guaranteed to be
+/// atomic if it is 8-byte aligned.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_directstoreu_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(movdiri))]
+#[unstable(feature = "stdarch_x86_movdir", issue = "none")]
+pub unsafe fn _directstoreu_u64(dst: *mut u64, val: u64) {
+    asm!(
+        "movdiri [{dst}], {val}",
+        dst = in(reg) dst,
+        val = in(reg) val,
+        options(nostack, preserves_flags)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_directstoreu_u64() {
+        if unsafe { __cpuid_count(7, 0) }.ecx & (1 << 27) == 0 {
+            return;
+        }
+        let mut dst = [0u64; 3];
+        unsafe {
+            _directstoreu_u64(&mut dst[1], 0x0123_4567_89ab_cdef);
+            _mm_sfence();
+        }
+        assert_eq!(dst, [0, 0x0123_4567_89ab_cdef, 0]);
+    }
+}
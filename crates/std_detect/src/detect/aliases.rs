@@ -0,0 +1,114 @@
+//! Other spellings of feature names.
+//!
+//! Compilers, `/proc/cpuinfo` and vendor documentation don't always agree on
+//! what a feature is called, e.g. LLVM's `sse4_2`, Linux's `pni` for SSE3 or
+//! `asimd` for NEON. The tables below map such aliases to the names the
+//! `is_{arch}_feature_detected!` macros accept. Only unambiguous aliases are
+//! listed: a Linux flag that covers just part of a feature, like `sha1` on
+//! AArch64, has no entry.
+//!
+//! The macros accept a few aliases themselves, the `@BIND_FEATURE_NAME`
+//! entries of `features!`. Those are taken from `Feature::BIND_NAMES` rather
+//! than repeated here.
+
+use cfg_if::cfg_if;
+
+/// Names the macros accept but that aren't unambiguous aliases.
+///
+/// `is_x86_feature_detected!("abm")` checks for LZCNT, but AMD's ABM also
+/// covers POPCNT.
+pub(crate) const AMBIGUOUS_BIND_NAMES: &[&str] = &["abm"];
+
+cfg_if! {
+    if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
+        pub(crate) const FEATURE_ALIASES: &[(&str, &str)] = &[
+            // Spellings used by LLVM and GCC.
+            ("sse4_1", "sse4.1"),
+            ("sse4_2", "sse4.2"),
+            ("sse41", "sse4.1"),
+            ("sse42", "sse4.2"),
+            ("pclmul", "pclmulqdq"),
+            ("rdrnd", "rdrand"),
+            ("bmi", "bmi1"),
+            ("cx16", "cmpxchg16b"),
+            ("fma3", "fma"),
+            // Flags in Linux's /proc/cpuinfo.
+            ("pni", "sse3"),
+            ("sha_ni", "sha"),
+            ("avx512_vbmi2", "avx512vbmi2"),
+            ("avx512_vnni", "avx512vnni"),
+            ("avx512_bitalg", "avx512bitalg"),
+            ("avx512_vpopcntdq", "avx512vpopcntdq"),
+            ("avx512_bf16", "avx512bf16"),
+            ("avx512_fp16", "avx512fp16"),
+            ("avx512_vp2intersect", "avx512vp2intersect"),
+            ("avx_vnni", "avxvnni"),
+            ("amx_tile", "amx-tile"),
+            ("amx_int8", "amx-int8"),
+            ("amx_bf16", "amx-bf16"),
+            ("amx_fp16", "amx-fp16"),
+        ];
+    } else if #[cfg(target_arch = "arm")] {
+        pub(crate) const FEATURE_ALIASES: &[(&str, &str)] = &[
+            // Flags in Linux's /proc/cpuinfo.
+            ("crc32", "crc"),
+            ("asimddp", "dotprod"),
+        ];
+    } else if #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] {
+        pub(crate) const FEATURE_ALIASES: &[(&str, &str)] = &[
+            // Spellings used by LLVM.
+            ("fp-armv8", "fp"),
+            ("fullfp16", "fp16"),
+            ("fp16fml", "fhm"),
+            ("complxnum", "fcma"),
+            ("rcpc-immo", "rcpc2"),
+            ("ccpp", "dpb"),
+            ("ccdp", "dpb2"),
+            // Flags in Linux's /proc/cpuinfo.
+            ("asimddp", "dotprod"),
+            ("asimdrdm", "rdm"),
+            ("asimdfhm", "fhm"),
+            ("atomics", "lse"),
+            ("crc32", "crc"),
+            ("dcpop", "dpb"),
+            ("dcpodp", "dpb2"),
+            ("frint", "frintts"),
+            ("ilrcpc", "rcpc2"),
+            ("jscvt", "jsconv"),
+            ("lrcpc", "rcpc"),
+            ("rng", "rand"),
+            ("sveaes", "sve2-aes"),
+            ("svebitperm", "sve2-bitperm"),
+            ("svesha3", "sve2-sha3"),
+            ("svesm4", "sve2-sm4"),
+            ("uscat", "lse2"),
+        ];
+    } else {
+        pub(crate) const FEATURE_ALIASES: &[(&str, &str)] = &[];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FEATURE_ALIASES;
+    use crate::detect::Feature;
+
+    #[test]
+    fn aliases_name_known_features() {
+        for &(alias, feature) in FEATURE_ALIASES {
+            assert!(
+                Feature::from_str(feature).is_ok(),
+                "{alias} is an alias of unknown {feature}"
+            );
+            // An alias must not shadow a feature of the same name.
+            assert!(
+                Feature::from_str(alias).is_err(),
+                "{alias} is a feature name"
+            );
+            assert!(
+                Feature::BIND_NAMES.iter().all(|&(bound, _)| bound != alias),
+                "{alias} is already accepted by the detection macro"
+            );
+        }
+    }
+}
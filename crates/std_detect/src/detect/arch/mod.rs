@@ -61,6 +61,7 @@ cfg_if! {
         pub mod __is_feature_detected {}
 
         impl Feature {
+            pub(crate) const BIND_NAMES: &'static [(&'static str, &'static str)] = &[];
             #[doc(hidden)]
             pub(crate) fn from_str(_s: &str) -> Result<Feature, ()> { Err(()) }
             #[doc(hidden)]
@@ -57,6 +57,8 @@ features! {
     ///     * Zksed: `"zksed"`
     ///     * Zksh: `"zksh"`
     ///   * Zkt: `"zkt"`
+    /// * Zvkned: `"zvkned"`
+    /// * Zvknha: `"zvknha"`
     ///
     /// There's also bases and extensions marked as standard instruction set,
     /// but they are in frozen or draft state. These instruction sets are also
@@ -190,4 +192,8 @@ features! {
     /// "Zk" Standard Extension for Standard scalar cryptography extension
     @FEATURE: #[stable(feature = "riscv_ratified", since = "1.76.0")] zkt: "zkt";
     /// "Zkt" Standard Extension for Data Independent Execution Latency
+    @FEATURE: #[unstable(feature = "stdarch_riscv_feature_detection", issue = "111192")] zvkned: "zvkned";
+    /// "Zvkned" Standard Extension for NIST Suite: Vector AES Block Cipher
+    @FEATURE: #[unstable(feature = "stdarch_riscv_feature_detection", issue = "111192")] zvknha: "zvknha";
+    /// "Zvknha" Standard Extension for NIST Suite: Vector SHA-2 Secure Hash (SHA-256)
 }
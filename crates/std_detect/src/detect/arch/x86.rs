@@ -107,6 +107,8 @@ features! {
     /// * `"rtm"`
     /// * `"movbe"`
     /// * `"ermsb"`
+    /// * `"serialize"`
+    /// * `"shstk"`
     ///
     /// [docs]: https://software.intel.com/sites/landingpage/IntrinsicsGuide
     #[stable(feature = "simd_x86", since = "1.27.0")]
@@ -247,4 +249,11 @@ features! {
     /// ERMSB, Enhanced REP MOVSB and STOSB
     @FEATURE: #[unstable(feature = "xop_target_feature", issue = "127208")] xop: "xop";
     /// XOP: eXtended Operations (AMD)
+    @FEATURE: #[unstable(feature = "stdarch_x86_serialize", issue = "none")] serialize: "serialize";
+    implied by target_features: [];
+    /// SERIALIZE (Serialize instruction execution)
+    @FEATURE: #[unstable(feature = "stdarch_x86_shstk", issue = "none")] shstk: "shstk";
+    implied by target_features: [];
+    /// CET_SS (Control-flow Enforcement Technology shadow stack). This only
+    /// reports CPU support, not whether the OS enabled shadow stacks.
 }
@@ -0,0 +1,412 @@
+//! Features implied by each feature.
+//!
+//! Enabling a target feature also enables every feature it implies, e.g.
+//! `avx2` implies `avx`, which implies `sse4.2`, and so on. The tables below
+//! list, for every feature that implies others, the full transitive set of
+//! implied features, using the same names as the `is_{arch}_feature_detected!`
+//! macros. Features that imply nothing are omitted.
+
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
+        pub(crate) const IMPLIED_FEATURES: &[(&str, &[&str])] = &[
+            ("aes", &["sse2", "sse"]),
+            ("pclmulqdq", &["sse2", "sse"]),
+            ("sse2", &["sse"]),
+            ("sse3", &["sse2", "sse"]),
+            ("ssse3", &["sse3", "sse2", "sse"]),
+            ("sse4.1", &["ssse3", "sse3", "sse2", "sse"]),
+            ("sse4.2", &["sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("sse4a", &["sse3", "sse2", "sse"]),
+            ("sha", &["sse2", "sse"]),
+            ("avx", &["sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("avx2", &["avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("sha512", &["avx2", "avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("sm3", &["avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("sm4", &["avx2", "avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            (
+                "avx512f",
+                &[
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512cd",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512er",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512pf",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512bw",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512dq",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512vl",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512ifma",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512vbmi",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512vpopcntdq",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512vbmi2",
+                &[
+                    "avx512bw",
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            ("gfni", &["sse2", "sse"]),
+            ("vaes", &["avx2", "aes", "avx", "sse2", "sse4.2", "sse", "sse4.1", "ssse3", "sse3"]),
+            (
+                "vpclmulqdq",
+                &[
+                    "avx",
+                    "pclmulqdq",
+                    "sse4.2",
+                    "sse2",
+                    "sse4.1",
+                    "sse",
+                    "ssse3",
+                    "sse3",
+                ],
+            ),
+            (
+                "avx512vnni",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512bitalg",
+                &[
+                    "avx512bw",
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512bf16",
+                &[
+                    "avx512bw",
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512vp2intersect",
+                &[
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            (
+                "avx512fp16",
+                &[
+                    "avx512bw",
+                    "avx512vl",
+                    "avx512dq",
+                    "avx512f",
+                    "avx2",
+                    "fma",
+                    "f16c",
+                    "avx",
+                    "sse4.2",
+                    "sse4.1",
+                    "ssse3",
+                    "sse3",
+                    "sse2",
+                    "sse",
+                ],
+            ),
+            ("avxifma", &["avx2", "avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("avxneconvert", &["avx2", "avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("avxvnni", &["avx2", "avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("avxvnniint16", &["avx2", "avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("avxvnniint8", &["avx2", "avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("amx-int8", &["amx-tile"]),
+            ("amx-bf16", &["amx-tile"]),
+            ("amx-fp16", &["amx-tile"]),
+            ("amx-complex", &["amx-tile"]),
+            ("f16c", &["avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("fma", &["avx", "sse4.2", "sse4.1", "ssse3", "sse3", "sse2", "sse"]),
+            ("xsaveopt", &["xsave"]),
+            ("xsaves", &["xsave"]),
+            ("xsavec", &["xsave"]),
+            ("xop", &["avx", "sse4a", "sse4.2", "sse3", "sse4.1", "sse2", "ssse3", "sse"]),
+        ];
+    } else if #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] {
+        pub(crate) const IMPLIED_FEATURES: &[(&str, &[&str])] = &[
+            ("neon", &["fp"]),
+            ("aes", &["neon", "pmull", "fp"]),
+            ("bf16", &["neon", "fp"]),
+            ("dpb2", &["dpb"]),
+            ("dotprod", &["neon", "fp"]),
+            ("f32mm", &["sve", "neon", "fp"]),
+            ("f64mm", &["sve", "neon", "fp"]),
+            ("fcma", &["neon", "fp"]),
+            ("fhm", &["fp16", "neon", "fp"]),
+            ("flagm2", &["flagm"]),
+            ("fp16", &["neon", "fp"]),
+            ("fp8", &["faminmax", "lut", "bf16", "neon", "fp"]),
+            ("fp8dot2", &["fp8dot4", "fp8fma", "fp8", "faminmax", "lut", "bf16", "neon", "fp"]),
+            ("fp8dot4", &["fp8fma", "fp8", "faminmax", "lut", "bf16", "neon", "fp"]),
+            ("fp8fma", &["fp8", "faminmax", "lut", "bf16", "neon", "fp"]),
+            ("frintts", &["neon", "fp"]),
+            ("i8mm", &["neon", "fp"]),
+            ("jsconv", &["neon", "fp"]),
+            ("lse128", &["lse"]),
+            ("rcpc2", &["rcpc"]),
+            ("rcpc3", &["rcpc2", "rcpc"]),
+            ("rdm", &["neon", "fp"]),
+            ("sha2", &["neon", "fp"]),
+            ("sha3", &["sha2", "neon", "fp"]),
+            ("sm4", &["neon", "fp"]),
+            ("sme", &["bf16", "neon", "fp"]),
+            ("sme2", &["sme", "bf16", "neon", "fp"]),
+            ("sme2p1", &["sme2", "sme", "bf16", "neon", "fp"]),
+            ("sme-f16f16", &["sme2", "sme", "bf16", "neon", "fp"]),
+            ("sme-f64f64", &["sme", "bf16", "neon", "fp"]),
+            (
+                "sme-f8f16",
+                &[
+                    "sme-f8f32",
+                    "sme2",
+                    "fp8",
+                    "sme",
+                    "faminmax",
+                    "lut",
+                    "bf16",
+                    "neon",
+                    "fp",
+                ],
+            ),
+            ("sme-f8f32", &["sme2", "fp8", "sme", "faminmax", "lut", "bf16", "neon", "fp"]),
+            ("sme-fa64", &["sme", "sve2", "bf16", "sve", "neon", "fp"]),
+            ("sme-i16i64", &["sme", "bf16", "neon", "fp"]),
+            ("sme-lutv2", &["sme", "bf16", "neon", "fp"]),
+            (
+                "ssve-fp8dot2",
+                &[
+                    "ssve-fp8dot4",
+                    "ssve-fp8fma",
+                    "sme2",
+                    "fp8",
+                    "sme",
+                    "faminmax",
+                    "lut",
+                    "bf16",
+                    "neon",
+                    "fp",
+                ],
+            ),
+            (
+                "ssve-fp8dot4",
+                &[
+                    "ssve-fp8fma",
+                    "sme2",
+                    "fp8",
+                    "sme",
+                    "faminmax",
+                    "lut",
+                    "bf16",
+                    "neon",
+                    "fp",
+                ],
+            ),
+            ("ssve-fp8fma", &["sme2", "fp8", "sme", "faminmax", "lut", "bf16", "neon", "fp"]),
+            ("sve", &["neon", "fp"]),
+            ("sve2", &["sve", "neon", "fp"]),
+            ("sve2p1", &["sve2", "sve", "neon", "fp"]),
+            ("sve2-aes", &["sve2", "aes", "sve", "neon", "pmull", "fp"]),
+            ("sve-b16b16", &["bf16", "neon", "fp"]),
+            ("sve2-bitperm", &["sve2", "sve", "neon", "fp"]),
+            ("sve2-sha3", &["sve2", "sha3", "sve", "sha2", "neon", "fp"]),
+            ("sve2-sm4", &["sve2", "sm4", "sve", "neon", "fp"]),
+        ];
+    } else {
+        pub(crate) const IMPLIED_FEATURES: &[(&str, &[&str])] = &[];
+    }
+}
@@ -11,6 +11,18 @@ macro_rules! detect_feature {
     };
 }
 
+/// Expands to whether the target features of a feature were enabled at
+/// compile time, using the same `cfg`s as [`detect_feature!`].
+#[allow(unused)]
+macro_rules! cfg_feature {
+    ($feature_lit:tt) => {
+        cfg!(target_feature = $feature_lit)
+    };
+    ($feature_lit:tt : $($target_feature_lit:tt),*) => {
+        false $(|| cfg!(target_feature = $target_feature_lit))*
+    };
+}
+
 #[allow(unused)]
 macro_rules! features {
     (
@@ -135,19 +147,33 @@ macro_rules! features {
 
         #[cfg($cfg)]
         impl Feature {
+            /// The other names the detection macro accepts, with the names of
+            /// the features they stand for.
+            pub(crate) const BIND_NAMES: &'static [(&'static str, &'static str)] =
+                &[$(($bind_feature, $feature_impl),)*];
             pub(crate) fn to_str(self) -> &'static str {
                 match self {
                     $(Feature::$feature => $feature_lit,)*
                     Feature::_last => unreachable!(),
                 }
             }
-            #[cfg(feature = "std_detect_env_override")]
             pub(crate) fn from_str(s: &str) -> Result<Feature, ()> {
                 match s {
                     $($feature_lit => Ok(Feature::$feature),)*
                     _ => Err(())
                 }
             }
+            // Some features, like `tsc` on x86, are not target features
+            // rustc knows about and are never enabled at compile time.
+            #[allow(unexpected_cfgs)]
+            pub(crate) fn compiled_with(self) -> bool {
+                match self {
+                    $(Feature::$feature => {
+                        cfg_feature!($feature_lit $(: $($target_feature_lit),*)?)
+                    })*
+                    Feature::_last => unreachable!(),
+                }
+            }
         }
 
         /// Each function performs run-time feature detection for a single
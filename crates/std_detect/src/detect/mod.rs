@@ -32,8 +32,10 @@ pub use self::arch::__is_feature_detected;
 
 pub(crate) use self::arch::Feature;
 
+mod aliases;
 mod bit;
 mod cache;
+mod implied;
 
 cfg_if! {
     if #[cfg(miri)] {
@@ -114,3 +116,55 @@ pub fn features() -> impl Iterator<Item = (&'static str, bool)> {
         }
     }
 }
+
+/// Returns the features implied by the feature `name`.
+///
+/// The result is the full transitive set, e.g. on x86 `"avx2"` yields
+/// `"avx"`, `"sse4.2"`, `"sse4.1"` and so on down to `"sse"`. Features are
+/// named as in the `is_{arch}_feature_detected!` macros. An empty slice is
+/// returned for features that imply nothing and for unknown names.
+#[unstable(feature = "stdarch_internal", issue = "none")]
+pub fn implied_features(name: &str) -> &'static [&'static str] {
+    for &(feature, implied) in implied::IMPLIED_FEATURES {
+        if feature == name {
+            return implied;
+        }
+    }
+    &[]
+}
+
+/// Returns the name the `is_{arch}_feature_detected!` macros use for the
+/// feature `name`.
+///
+/// This accepts the spellings of other tools, e.g. on x86 `"sse4_2"` from
+/// LLVM yields `"sse4.2"` and on AArch64 `"asimd"` from `/proc/cpuinfo`
+/// yields `"neon"`. Names the macros accept are returned as they are, and
+/// `None` is returned for unknown names. Names are case-sensitive.
+#[unstable(feature = "stdarch_internal", issue = "none")]
+pub fn normalize_feature_name(name: &str) -> Option<&'static str> {
+    let bind_names = Feature::BIND_NAMES
+        .iter()
+        .filter(|(alias, _)| !aliases::AMBIGUOUS_BIND_NAMES.contains(alias));
+    for &(alias, feature) in aliases::FEATURE_ALIASES.iter().chain(bind_names) {
+        if alias == name {
+            return Some(feature);
+        }
+    }
+    Feature::from_str(name).ok().map(Feature::to_str)
+}
+
+/// Returns `true` if the feature `name` was enabled at compile time.
+///
+/// This is `cfg!(target_feature = ...)` for a name only known at run time,
+/// e.g. to log the static feature set of a build. Features are named as in
+/// the `is_{arch}_feature_detected!` macros, and `false` is returned for
+/// unknown names.
+///
+/// The answer reflects how this crate was compiled. When it is built as a
+/// dependency that is the configuration of the whole build, but the
+/// prebuilt standard library is compiled for the target's baseline, so
+/// through `std` this only reports the features every CPU of the target has.
+#[unstable(feature = "stdarch_internal", issue = "none")]
+pub fn compiled_with(name: &str) -> bool {
+    Feature::from_str(name).is_ok_and(Feature::compiled_with)
+}
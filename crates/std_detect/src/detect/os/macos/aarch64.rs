@@ -51,7 +51,8 @@ pub(crate) fn detect_features() -> cache::Initializer {
     let flagm = _sysctlbyname("hw.optional.arm.FEAT_FlagM\0");
     let ssbs = _sysctlbyname("hw.optional.arm.FEAT_SSBS\0");
     let sb = _sysctlbyname("hw.optional.arm.FEAT_SB\0");
-    let paca = _sysctlbyname("hw.optional.arm.FEAT_PAuth\0");
+    // FEAT_PAuth covers both address and generic authentication.
+    let pauth = _sysctlbyname("hw.optional.arm.FEAT_PAuth\0");
     let dpb = _sysctlbyname("hw.optional.arm.FEAT_DPB\0");
     let dpb2 = _sysctlbyname("hw.optional.arm.FEAT_DPB2\0");
     let frintts = _sysctlbyname("hw.optional.arm.FEAT_FRINTTS\0");
@@ -81,7 +82,8 @@ pub(crate) fn detect_features() -> cache::Initializer {
     enable_feature(Feature::flagm, flagm);
     enable_feature(Feature::ssbs, ssbs);
     enable_feature(Feature::sb, sb);
-    enable_feature(Feature::paca, paca);
+    enable_feature(Feature::paca, pauth);
+    enable_feature(Feature::pacg, pauth);
     enable_feature(Feature::dpb, dpb);
     enable_feature(Feature::dpb2, dpb2);
     enable_feature(Feature::frintts, frintts);
@@ -133,14 +133,15 @@ pub(crate) fn detect_features() -> cache::Initializer {
         enable(extended_features_ebx, 29, Feature::sha);
 
         enable(extended_features_ecx, 8, Feature::gfni);
-        enable(extended_features_ecx, 9, Feature::vaes);
-        enable(extended_features_ecx, 10, Feature::vpclmulqdq);
 
         enable(extended_features_ebx, 3, Feature::bmi1);
         enable(extended_features_ebx, 8, Feature::bmi2);
 
         enable(extended_features_ebx, 9, Feature::ermsb);
 
+        enable(extended_features_ecx, 7, Feature::shstk);
+        enable(extended_features_edx, 14, Feature::serialize);
+
         // `XSAVE` and `AVX` support:
         let cpu_xsave = bit::test(proc_info_ecx as usize, 26);
         if cpu_xsave {
@@ -213,6 +214,21 @@ pub(crate) fn detect_features() -> cache::Initializer {
                     enable(proc_info_ecx, 28, Feature::avx);
                     enable(extended_features_ebx, 5, Feature::avx2);
 
+                    // VAES and VPCLMULQDQ are the VEX/EVEX encoded versions of
+                    // AES and PCLMULQDQ, so they need the AVX state as well as
+                    // the base feature. VAES also implies AVX2 in rustc, so it
+                    // is only enabled together with AES and AVX2, and
+                    // VPCLMULQDQ together with PCLMULQDQ and AVX.
+                    if bit::test(proc_info_ecx as usize, 25)
+                        && bit::test(extended_features_ebx as usize, 5)
+                    {
+                        enable(extended_features_ecx, 9, Feature::vaes);
+                    }
+                    if bit::test(proc_info_ecx as usize, 1) && bit::test(proc_info_ecx as usize, 28)
+                    {
+                        enable(extended_features_ecx, 10, Feature::vpclmulqdq);
+                    }
+
                     // "Short" versions of AVX512 instructions
                     enable(extended_features_eax_leaf_1, 4, Feature::avxvnni);
                     enable(extended_features_eax_leaf_1, 23, Feature::avxifma);
@@ -6,7 +6,13 @@
 #![cfg_attr(target_arch = "powerpc64", feature(stdarch_powerpc_feature_detection))]
 #![cfg_attr(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature(sha512_sm_x86, x86_amx_intrinsics, xop_target_feature)
+    feature(
+        sha512_sm_x86,
+        x86_amx_intrinsics,
+        xop_target_feature,
+        stdarch_x86_serialize,
+        stdarch_x86_shstk
+    )
 )]
 #![allow(clippy::unwrap_used, clippy::use_debug, clippy::print_stdout)]
 
@@ -31,6 +37,159 @@ fn all() {
     }
 }
 
+#[test]
+fn implied_features() {
+    use std_detect::detect::{features, implied_features};
+    assert!(implied_features("not-a-feature").is_empty());
+    let names: Vec<_> = features().map(|(f, _)| f).collect();
+    for &f in &names {
+        for implied in implied_features(f) {
+            assert!(names.contains(implied), "{f} implies unknown {implied}");
+            assert_ne!(*implied, f, "{f} implies itself");
+            // The sets are transitively closed.
+            for transitive in implied_features(implied) {
+                assert!(implied_features(f).contains(transitive));
+            }
+        }
+    }
+}
+
+#[test]
+fn normalize_feature_name() {
+    use std_detect::detect::{features, normalize_feature_name};
+    assert_eq!(normalize_feature_name("not-a-feature"), None);
+    assert_eq!(normalize_feature_name(""), None);
+    for (f, _) in features() {
+        assert_eq!(normalize_feature_name(f), Some(f));
+    }
+}
+
+#[test]
+fn compiled_with() {
+    use std_detect::detect::{compiled_with, features};
+    assert!(!compiled_with("not-a-feature"));
+    // Features enabled at compile time must be available at run time too.
+    for (f, detected) in features() {
+        assert!(
+            !compiled_with(f) || detected,
+            "{f} is enabled but not detected"
+        );
+    }
+}
+
+#[test]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn x86_normalize_feature_name() {
+    use std_detect::detect::normalize_feature_name;
+    assert_eq!(normalize_feature_name("sse4_2"), Some("sse4.2"));
+    assert_eq!(normalize_feature_name("sse4_1"), Some("sse4.1"));
+    assert_eq!(normalize_feature_name("sse42"), Some("sse4.2"));
+    assert_eq!(normalize_feature_name("pni"), Some("sse3"));
+    assert_eq!(normalize_feature_name("pclmul"), Some("pclmulqdq"));
+    assert_eq!(normalize_feature_name("avx512_vnni"), Some("avx512vnni"));
+    assert_eq!(normalize_feature_name("SSE4.2"), None);
+    // The renamed features the macro still accepts.
+    assert_eq!(normalize_feature_name("avx512gfni"), Some("gfni"));
+    assert_eq!(normalize_feature_name("avx512vaes"), Some("vaes"));
+    // ABM is more than LZCNT.
+    assert_eq!(normalize_feature_name("abm"), None);
+    // Only x86 aliases are known.
+    assert_eq!(normalize_feature_name("asimd"), None);
+}
+
+#[test]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn x86_compiled_with() {
+    use std_detect::detect::compiled_with;
+    // This test is compiled with the same flags as std_detect.
+    assert_eq!(compiled_with("sse2"), cfg!(target_feature = "sse2"));
+    assert_eq!(compiled_with("sse4.2"), cfg!(target_feature = "sse4.2"));
+    assert_eq!(compiled_with("avx2"), cfg!(target_feature = "avx2"));
+    assert_eq!(compiled_with("avx512f"), cfg!(target_feature = "avx512f"));
+    #[cfg(target_arch = "x86_64")]
+    assert!(compiled_with("sse2"));
+    // Aliases aren't accepted, and there is no target feature for `tsc`.
+    assert!(!compiled_with("sse4_2"));
+    assert!(!compiled_with("tsc"));
+}
+
+#[test]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+fn aarch64_normalize_feature_name() {
+    use std_detect::detect::normalize_feature_name;
+    assert_eq!(normalize_feature_name("asimd"), Some("neon"));
+    assert_eq!(normalize_feature_name("asimddp"), Some("dotprod"));
+    assert_eq!(normalize_feature_name("atomics"), Some("lse"));
+    assert_eq!(normalize_feature_name("fullfp16"), Some("fp16"));
+    assert_eq!(normalize_feature_name("jscvt"), Some("jsconv"));
+    assert_eq!(normalize_feature_name("NEON"), None);
+    // Only AArch64 aliases are known.
+    assert_eq!(normalize_feature_name("sse4_2"), None);
+}
+
+#[test]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn x86_implied_features() {
+    use std_detect::detect::implied_features;
+    assert!(implied_features("avx512bw").contains(&"avx512f"));
+    assert!(implied_features("avx512bw").contains(&"avx2"));
+    assert!(implied_features("avx2").contains(&"sse4.2"));
+    assert!(implied_features("sse").is_empty());
+    assert!(implied_features("popcnt").is_empty());
+    // Only x86 feature names are known.
+    assert!(implied_features("sve2").is_empty());
+}
+
+#[test]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn x86_crypto_features() {
+    use std_detect::detect::{features, implied_features};
+    let detected: [(&str, bool); 4] = [
+        ("pclmulqdq", is_x86_feature_detected!("pclmulqdq")),
+        ("gfni", is_x86_feature_detected!("gfni")),
+        ("vaes", is_x86_feature_detected!("vaes")),
+        ("vpclmulqdq", is_x86_feature_detected!("vpclmulqdq")),
+    ];
+    // A detected feature can only be used if everything it implies is
+    // detected too, e.g. `vaes` code may use AVX2 instructions.
+    let all: Vec<_> = features().collect();
+    for (f, enabled) in detected {
+        assert!(all.contains(&(f, enabled)));
+        if !enabled {
+            continue;
+        }
+        for implied in implied_features(f) {
+            assert!(all.contains(&(*implied, true)), "{f} without {implied}");
+        }
+    }
+    assert!(implied_features("vaes").contains(&"aes"));
+    assert!(implied_features("vaes").contains(&"avx"));
+    assert!(implied_features("vpclmulqdq").contains(&"pclmulqdq"));
+    assert!(implied_features("vpclmulqdq").contains(&"avx"));
+}
+
+#[test]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+fn aarch64_implied_features() {
+    use std_detect::detect::implied_features;
+    assert!(implied_features("sve2").contains(&"sve"));
+    assert!(implied_features("sve2").contains(&"neon"));
+    assert!(implied_features("neon").contains(&"fp"));
+    assert!(implied_features("fp").is_empty());
+    // Only AArch64 feature names are known.
+    assert!(implied_features("avx2").is_empty());
+}
+
+#[test]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+fn aarch64_pauth_bti() {
+    // These can be queried on every OS, even where they're never detected.
+    let paca: bool = is_aarch64_feature_detected!("paca");
+    let pacg: bool = is_aarch64_feature_detected!("pacg");
+    let bti: bool = is_aarch64_feature_detected!("bti");
+    println!("paca: {paca:?}, pacg: {pacg:?}, bti: {bti:?}");
+}
+
 #[test]
 #[cfg(all(target_arch = "arm", target_os = "freebsd"))]
 fn arm_freebsd() {
@@ -206,6 +365,7 @@ fn aarch64_macos() {
     println!("ssbs: {:?}", is_aarch64_feature_detected!("ssbs"));
     println!("sb: {:?}", is_aarch64_feature_detected!("sb"));
     println!("paca: {:?}", is_aarch64_feature_detected!("paca"));
+    println!("pacg: {:?}", is_aarch64_feature_detected!("pacg"));
     println!("dpb: {:?}", is_aarch64_feature_detected!("dpb"));
     println!("dpb2: {:?}", is_aarch64_feature_detected!("dpb2"));
     println!("frintts: {:?}", is_aarch64_feature_detected!("frintts"));
@@ -305,6 +465,8 @@ fn x86_all() {
     println!("amx-fp16: {:?}", is_x86_feature_detected!("amx-fp16"));
     println!("amx-complex: {:?}", is_x86_feature_detected!("amx-complex"));
     println!("xop: {:?}", is_x86_feature_detected!("xop"));
+    println!("serialize: {:?}", is_x86_feature_detected!("serialize"));
+    println!("shstk: {:?}", is_x86_feature_detected!("shstk"));
 }
 
 #[test]
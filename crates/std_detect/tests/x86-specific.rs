@@ -5,7 +5,9 @@
     avx512_target_feature,
     sha512_sm_x86,
     x86_amx_intrinsics,
-    xop_target_feature
+    xop_target_feature,
+    stdarch_x86_serialize,
+    stdarch_x86_shstk
 )]
 
 extern crate cupid;
@@ -94,6 +96,8 @@ fn dump() {
     println!("amx-fp16: {:?}", is_x86_feature_detected!("amx-fp16"));
     println!("amx-complex: {:?}", is_x86_feature_detected!("amx-complex"));
     println!("xop: {:?}", is_x86_feature_detected!("xop"));
+    println!("serialize: {:?}", is_x86_feature_detected!("serialize"));
+    println!("shstk: {:?}", is_x86_feature_detected!("shstk"));
 }
 
 #[cfg(feature = "std_detect_env_override")]
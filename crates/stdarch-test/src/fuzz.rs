@@ -0,0 +1,165 @@
+//! Randomized testing of intrinsics against scalar reference implementations.
+//!
+//! `#[assert_instr]` only checks that the expected mnemonic shows up in the
+//! disassembly, and the hand-written tests only check a handful of inputs.
+//! The [`fuzz_intrinsic!`] macro calls an intrinsic on many random inputs and
+//! compares each result against a scalar reference, which catches lowering
+//! bugs affecting only some lanes or some values.
+//!
+//! The macro is meant to be used inside a `#[simd_test]` function, which takes
+//! care of run-time feature detection. Vector arguments and results are
+//! written as arrays of their lanes.
+//!
+//! The run is deterministic; set `STDARCH_FUZZ_SEED` to use another seed and
+//! `STDARCH_FUZZ_ITERATIONS` to change the number of inputs tried (1000 by
+//! default).
+
+use std::env;
+
+/// A small xorshift64* pseudo-random number generator.
+///
+/// This is not suitable for anything but generating test inputs.
+pub struct Rng(u64);
+
+impl Rng {
+    /// Creates a generator seeded from `STDARCH_FUZZ_SEED`, or with a fixed
+    /// seed if that isn't set.
+    pub fn from_env() -> Self {
+        let seed = env::var("STDARCH_FUZZ_SEED")
+            .ok()
+            .map_or(0x853c_49e6_748f_ea9b, |v| v.parse().unwrap());
+        Self::new(seed)
+    }
+
+    pub fn new(seed: u64) -> Self {
+        // The state must never be zero.
+        Self(seed | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Returns a random value of type `T`.
+    pub fn gen<T: Random>(&mut self) -> T {
+        T::random(self)
+    }
+}
+
+/// Types that [`Rng`] can generate.
+pub trait Random {
+    fn random(rng: &mut Rng) -> Self;
+}
+
+macro_rules! impl_random_int {
+    ($($t:ty),*) => {$(
+        impl Random for $t {
+            /// Returns one of `0`, `1`, `-1`, `MIN` or `MAX` one time in
+            /// eight, as the edge cases are where saturation and overflow bugs
+            /// hide, and a uniformly distributed value otherwise.
+            fn random(rng: &mut Rng) -> Self {
+                let bits = rng.next_u64();
+                if bits & 7 != 0 {
+                    return (bits >> 3) as $t;
+                }
+                match (bits >> 3) % 5 {
+                    0 => 0,
+                    1 => 1,
+                    2 => <$t>::MAX,
+                    3 => <$t>::MIN,
+                    _ => <$t>::MAX.wrapping_add(<$t>::MIN),
+                }
+            }
+        }
+    )*};
+}
+
+impl_random_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+macro_rules! impl_random_float {
+    ($($t:ty),*) => {$(
+        impl Random for $t {
+            /// Returns a finite value, so that results can be compared
+            /// without having to account for NaN payloads.
+            fn random(rng: &mut Rng) -> Self {
+                let bits = rng.next_u64();
+                match bits & 15 {
+                    0 => 0.0,
+                    1 => -0.0,
+                    _ => (bits >> 12) as i32 as $t / (((bits >> 4) & 0xff) + 1) as $t,
+                }
+            }
+        }
+    )*};
+}
+
+impl_random_float!(f32, f64);
+
+impl<T: Random, const N: usize> Random for [T; N] {
+    fn random(rng: &mut Rng) -> Self {
+        std::array::from_fn(|_| rng.gen())
+    }
+}
+
+/// Number of random inputs each [`fuzz_intrinsic!`] invocation tries.
+pub fn iterations() -> usize {
+    env::var("STDARCH_FUZZ_ITERATIONS")
+        .ok()
+        .map_or(1000, |v| v.parse().unwrap())
+}
+
+/// Compares an intrinsic against a scalar reference on random inputs.
+///
+/// The intrinsic's signature is spelled out with its vector arguments and
+/// result written as arrays of lanes. Each argument is generated with
+/// [`Rng::gen`], or with the `fn(&mut Rng) -> T` strategy given after `=`.
+/// The reference is a closure taking the same arguments as arrays:
+///
+/// ```ignore
+/// #[simd_test(enable = "avx2")]
+/// unsafe fn fuzz_mm256_add_epi32() {
+///     fuzz_intrinsic!(
+///         _mm256_add_epi32(a: [i32; 8], b: [i32; 8]) -> [i32; 8],
+///         |a, b| std::array::from_fn(|i| a[i].wrapping_add(b[i])),
+///     );
+/// }
+/// ```
+///
+/// This must be used in an `unsafe` context, as it calls the intrinsic.
+#[macro_export]
+macro_rules! fuzz_intrinsic {
+    (
+        $intrinsic:ident($($arg:ident: $ty:ty $(= $strategy:expr)?),* $(,)?) -> $ret:ty,
+        $reference:expr $(,)?
+    ) => {{
+        let reference: fn($($ty),*) -> $ret = $reference;
+        let mut rng = $crate::fuzz::Rng::from_env();
+        for _ in 0..$crate::fuzz::iterations() {
+            $(
+                let $arg: $ty = $crate::fuzz_intrinsic!(@gen rng $($strategy)?);
+            )*
+            let expected = reference($($arg),*);
+            // Scalar arguments such as masks are transmuted to themselves.
+            #[allow(clippy::useless_transmute)]
+            let actual: $ret = ::std::mem::transmute(
+                $intrinsic($(::std::mem::transmute($arg)),*)
+            );
+            assert_eq!(
+                actual,
+                expected,
+                concat!(stringify!($intrinsic), " mismatch for inputs",
+                    $(" ", stringify!($arg), " = {:?}"),*),
+                $($arg),*
+            );
+        }
+    }};
+    (@gen $rng:ident) => {
+        $rng.gen()
+    };
+    (@gen $rng:ident $strategy:expr) => {
+        ($strategy)(&mut $rng)
+    };
+}
@@ -15,6 +15,8 @@ pub use assert_instr_macro::*;
 pub use simd_test_macro::*;
 use std::{cmp, collections::HashSet, env, hash, hint::black_box, str};
 
+pub mod fuzz;
+
 cfg_if! {
     if #[cfg(target_arch = "wasm32")] {
         pub mod wasm;
@@ -157,7 +159,18 @@ pub fn assert(shim_addr: usize, fnname: &str, expected: &str) {
         );
     let probably_only_one_instruction = instrs.len() < instruction_limit;
 
-    if found && probably_only_one_instruction && !inlining_failed {
+    // Crypto intrinsics must not leak their operands through timing, so the
+    // code around their instruction has to be straight-line.
+    let timing_leaks = if CONSTANT_TIME.contains(&expected) {
+        instrs
+            .iter()
+            .filter(|s| is_conditional_branch(s) || has_indexed_address(s))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    if found && probably_only_one_instruction && !inlining_failed && timing_leaks.is_empty() {
         return;
     }
 
@@ -185,6 +198,12 @@ pub fn assert(shim_addr: usize, fnname: &str, expected: &str) {
             "instruction found, but the disassembly contains subroutine \
              call instructions, which hint that inlining failed"
         );
+    } else if !timing_leaks.is_empty() {
+        panic!(
+            "instruction found, but `{expected}` must be constant-time and the \
+             disassembly contains conditional branches or indexed memory \
+             accesses: {timing_leaks:?}"
+        );
     }
 }
 
@@ -201,3 +220,251 @@ pub fn assert_skip_test_ok(name: &str, missing_features: &[&str]) {
 
 // See comment in `assert-instr-macro` crate for why this exists
 pub static mut _DONT_DEDUP: *const u8 = std::ptr::null();
+
+/// Instructions of intrinsics that are used to implement cryptography and
+/// need to execute in constant time.
+///
+/// The instructions themselves are constant-time on the hardware that
+/// implements them, but the wrapper of an intrinsic could still introduce a
+/// timing side channel, e.g. a branch on an operand or a table lookup. The
+/// disassembly of the functions asserting one of these instructions is
+/// checked for conditional branches and for memory accesses through an
+/// indexed address, which is how data-dependent accesses show up in wrappers
+/// that only ever touch their arguments and the stack.
+const CONSTANT_TIME: &[&str] = &[
+    // x86: AES-NI, VAES, SHA, PCLMULQDQ and VPCLMULQDQ.
+    "aesenc",
+    "aesenclast",
+    "aesdec",
+    "aesdeclast",
+    "aesimc",
+    "aeskeygenassist",
+    "vaesenc",
+    "vaesenclast",
+    "vaesdec",
+    "vaesdeclast",
+    "sha1msg1",
+    "sha1msg2",
+    "sha1nexte",
+    "sha1rnds4",
+    "sha256msg1",
+    "sha256msg2",
+    "sha256rnds2",
+    "pclmul",
+    "vpclmul",
+    // ARM: the AES, SHA2, SHA3, SHA512, SM3 and SM4 extensions and
+    // polynomial multiplication.
+    "aese",
+    "aesd",
+    "aesmc",
+    "sha1c",
+    "sha1h",
+    "sha1m",
+    "sha1p",
+    "sha1su0",
+    "sha1su1",
+    "sha256h",
+    "sha256h2",
+    "sha256su0",
+    "sha256su1",
+    "sha512h",
+    "sha512h2",
+    "sha512su0",
+    "sha512su1",
+    "eor3",
+    "bcax",
+    "rax1",
+    "sm3partw1",
+    "sm3partw2",
+    "sm3ss1",
+    "sm4e",
+    "sm4ekey",
+    "pmull",
+    // RISC-V: the scalar crypto extensions Zkn and Zks, and the carry-less
+    // multiplication and crossbar permutations of Zbkc and Zbkx.
+    "aes32dsi",
+    "aes32dsmi",
+    "aes32esi",
+    "aes32esmi",
+    "aes64ds",
+    "aes64dsm",
+    "aes64es",
+    "aes64esm",
+    "aes64im",
+    "aes64ks1i",
+    "aes64ks2",
+    "sha256sig0",
+    "sha256sig1",
+    "sha256sum0",
+    "sha256sum1",
+    "sha512sig0",
+    "sha512sig1",
+    "sha512sum0",
+    "sha512sum1",
+    "sha512sig0h",
+    "sha512sig0l",
+    "sha512sig1h",
+    "sha512sig1l",
+    "sha512sum0r",
+    "sha512sum1r",
+    "sm3p0",
+    "sm3p1",
+    "sm4ed",
+    "sm4ks",
+    "clmul",
+    "clmulh",
+    "xperm4",
+    "xperm8",
+];
+
+fn is_conditional_branch(instr: &str) -> bool {
+    let mnemonic = instr.split_whitespace().next().unwrap_or("");
+    if cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+        (mnemonic.starts_with('j') && !mnemonic.starts_with("jmp")) || mnemonic.starts_with("loop")
+    } else if cfg!(any(target_arch = "aarch64", target_arch = "arm64ec")) {
+        mnemonic.starts_with("b.")
+            || mnemonic.starts_with("bc.")
+            || matches!(mnemonic, "cbz" | "cbnz" | "tbz" | "tbnz")
+    } else if cfg!(target_arch = "arm") {
+        // Strip the `.w` and `.n` width qualifiers of Thumb-2.
+        let mnemonic = mnemonic.split('.').next().unwrap();
+        let condition = mnemonic.strip_prefix('b').unwrap_or("");
+        matches!(mnemonic, "cbz" | "cbnz")
+            || matches!(
+                condition,
+                "eq" | "ne"
+                    | "cs"
+                    | "hs"
+                    | "cc"
+                    | "lo"
+                    | "mi"
+                    | "pl"
+                    | "vs"
+                    | "vc"
+                    | "hi"
+                    | "ls"
+                    | "ge"
+                    | "lt"
+                    | "gt"
+                    | "le"
+            )
+    } else if cfg!(any(target_arch = "riscv32", target_arch = "riscv64")) {
+        matches!(
+            mnemonic.strip_prefix("c.").unwrap_or(mnemonic),
+            "beq"
+                | "bne"
+                | "blt"
+                | "bge"
+                | "bltu"
+                | "bgeu"
+                | "beqz"
+                | "bnez"
+                | "blez"
+                | "bgez"
+                | "bltz"
+                | "bgtz"
+                | "bgt"
+                | "ble"
+                | "bgtu"
+                | "bleu"
+        )
+    } else if cfg!(target_arch = "wasm32") {
+        matches!(mnemonic, "br_if" | "br_table" | "if")
+    } else {
+        // FIXME: Add detection for other archs
+        false
+    }
+}
+
+// Whether a memory operand of `instr` adds a register to its base register,
+// e.g. `(%rax,%rcx,4)`, `[rax+rcx*4]` or `[x0, x1, lsl #3]`.
+fn has_indexed_address(instr: &str) -> bool {
+    // Neither `lea` nor the multi-byte `nop`s used for padding access memory.
+    let mnemonic = instr.split_whitespace().next().unwrap_or("");
+    if mnemonic.starts_with("lea") || mnemonic.starts_with("nop") {
+        return false;
+    }
+    // AT&T syntax: `disp(base, index, scale)`.
+    let att = instr
+        .split('(')
+        .skip(1)
+        .any(|operand| operand.split(')').next().unwrap().contains(','));
+    // Intel and ARM syntax: `[base + index * scale + disp]` and
+    // `[base, index, extend]`.
+    let bracketed = instr.split('[').skip(1).any(|operand| {
+        operand
+            .split(']')
+            .next()
+            .unwrap()
+            .split([',', ' ', '+', '-'])
+            .filter(|term| !term.is_empty())
+            .skip(1)
+            .any(|term| term.starts_with(|c: char| c.is_ascii_alphabetic()))
+    });
+    att || bracketed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn conditional_branches() {
+        for instr in ["jne 0x1f", "jb 0x1f <foo+0x1f>", "js 0x1f", "loop 0x1f"] {
+            assert!(is_conditional_branch(instr), "{instr}");
+        }
+        for instr in [
+            "jmp 0x1f",
+            "jmpq *%rax",
+            "call 0x1f",
+            "ret",
+            "aesenc %xmm1,%xmm0",
+        ] {
+            assert!(!is_conditional_branch(instr), "{instr}");
+        }
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    fn conditional_branches() {
+        for instr in [
+            "b.ne 0x1f",
+            "bc.eq 0x1f",
+            "cbz x0, 0x1f",
+            "tbnz w0, #3, 0x1f",
+        ] {
+            assert!(is_conditional_branch(instr), "{instr}");
+        }
+        for instr in ["b 0x1f", "bl 0x1f", "br x0", "ret", "aese v0.16b, v1.16b"] {
+            assert!(!is_conditional_branch(instr), "{instr}");
+        }
+    }
+
+    #[test]
+    fn indexed_addresses() {
+        for instr in [
+            "movl (%rax,%rcx,4),%edx",
+            "movdqu 0x10(%rdi,%rsi,1),%xmm0",
+            "mov edx, dword ptr [rax+rcx*4]",
+            "ldr x0, [x1, x2, lsl #3]",
+            "ldrb w0, [x1, w2, uxtw]",
+        ] {
+            assert!(has_indexed_address(instr), "{instr}");
+        }
+        for instr in [
+            "movl 0x8(%rsp),%eax",
+            "movaps (%rdi),%xmm0",
+            "mov eax, dword ptr [rsp+0x8]",
+            "movdqa xmm0, xmmword ptr [rip+0x1234]",
+            "ldr x0, [sp, #16]",
+            "ldp x29, x30, [sp], #16",
+            "aesenc %xmm1,%xmm0",
+            // Neither computes an address that is accessed.
+            "lea (%rax,%rcx,4),%rdx",
+            "nopw 0x0(%rax,%rax,1)",
+        ] {
+            assert!(!has_indexed_address(instr), "{instr}");
+        }
+    }
+}
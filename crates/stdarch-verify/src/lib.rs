@@ -31,6 +31,74 @@ pub fn mips_functions(input: TokenStream) -> TokenStream {
     functions(input, &["core_arch/src/mips"])
 }
 
+/// The `core_arch` modules listed in the intrinsics manifest.
+const ARCHES: &[&str] = &[
+    "aarch64",
+    "arm",
+    "arm_shared",
+    "loongarch64",
+    "mips",
+    "nvptx",
+    "powerpc",
+    "powerpc64",
+    "riscv32",
+    "riscv64",
+    "riscv_shared",
+    "wasm32",
+    "x86",
+    "x86_64",
+];
+
+/// Lists every public function of the `core_arch` modules, with the
+/// `#[target_feature]` it enables and its stability attributes.
+///
+/// Stability attributes under `cfg_attr` are listed with their predicate,
+/// as the shared ARM intrinsics are only stable on some targets.
+#[proc_macro]
+pub fn intrinsics_manifest(input: TokenStream) -> TokenStream {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let root = dir.parent().expect("root-dir not found");
+
+    let mut intrinsics = Vec::new();
+    for arch in ARCHES {
+        let mut files = Vec::new();
+        walk(&root.join("core_arch/src").join(arch), &mut files);
+        assert!(!files.is_empty());
+
+        for (file, _) in &files {
+            for item in &file.items {
+                let f = match item {
+                    syn::Item::Fn(f) => f,
+                    _ => continue,
+                };
+                if !matches!(f.vis, syn::Visibility::Public(_)) {
+                    continue;
+                }
+                let name = &f.sig.ident;
+                let target_feature = if let Some(i) = find_target_feature(&f.attrs) {
+                    quote! { Some(#i) }
+                } else {
+                    quote! { None }
+                };
+                let stability = find_stability(&f.attrs);
+                intrinsics.push(quote! {
+                    Intrinsic {
+                        name: stringify!(#name),
+                        arch: #arch,
+                        target_feature: #target_feature,
+                        stability: &[#(#stability),*],
+                    }
+                });
+            }
+        }
+    }
+    assert!(!intrinsics.is_empty());
+
+    let input = proc_macro2::TokenStream::from(input);
+    let ret = quote! { #input: &[Intrinsic] = &[#(#intrinsics),*]; };
+    ret.into()
+}
+
 fn functions(input: TokenStream, dirs: &[&str]) -> TokenStream {
     let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
     let root = dir.parent().expect("root-dir not found");
@@ -515,6 +583,73 @@ fn find_target_feature(attrs: &[syn::Attribute]) -> Option<syn::Lit> {
         })
 }
 
+fn find_stability(attrs: &[syn::Attribute]) -> Vec<proc_macro2::TokenStream> {
+    fn to_stability(cfg: Option<String>, l: &syn::MetaList) -> Option<proc_macro2::TokenStream> {
+        let stable = if l.path.is_ident("stable") {
+            true
+        } else if l.path.is_ident("unstable") {
+            false
+        } else {
+            return None;
+        };
+        let feature = syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated
+            .parse2(l.tokens.clone())
+            .ok()?
+            .into_iter()
+            .find_map(|m| match m {
+                syn::Meta::NameValue(i) if i.path.is_ident("feature") => {
+                    if let syn::Expr::Lit(lit) = i.value {
+                        Some(lit.lit)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            })?;
+        let cfg = match cfg {
+            Some(cfg) => quote! { Some(#cfg) },
+            None => quote! { None },
+        };
+        Some(quote! {
+            Stability {
+                cfg: #cfg,
+                stable: #stable,
+                feature: #feature,
+            }
+        })
+    }
+
+    attrs
+        .iter()
+        .flat_map(|a| {
+            let l = match a.meta {
+                syn::Meta::List(ref l) => l,
+                _ => return Vec::new(),
+            };
+            if !l.path.is_ident("cfg_attr") {
+                return to_stability(None, l).into_iter().collect();
+            }
+            let metas = match syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated
+                .parse2(l.tokens.clone())
+            {
+                Ok(metas) => metas,
+                Err(_) => return Vec::new(),
+            };
+            let mut metas = metas.into_iter();
+            let cfg = match metas.next() {
+                Some(cfg) => quote! { #cfg }.to_string(),
+                None => return Vec::new(),
+            };
+            metas
+                .filter_map(|m| match m {
+                    syn::Meta::List(ref l) => to_stability(Some(cfg.clone()), l),
+                    _ => None,
+                })
+                .collect()
+        })
+        .collect()
+}
+
 fn find_doc(attrs: &[syn::Attribute]) -> String {
     attrs
         .iter()
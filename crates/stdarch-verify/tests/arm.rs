@@ -456,6 +456,11 @@ fn verify_all_signatures() {
                     && !rust.file.ends_with("v7.rs\"")
                     && !rust.file.ends_with("v8.rs\"")
                     && !rust.file.ends_with("tme.rs\"")
+                    && !rust.file.ends_with("bitfield.rs\"")
+                    && !rust.file.ends_with("sve.rs\"")
+                    && !rust.file.ends_with("lse.rs\"")
+                    && !rust.file.ends_with("pauth.rs\"")
+                    && !rust.file.ends_with("rand.rs\"")
                     && !rust.file.ends_with("ex.rs\"")
                     && !skip_intrinsic_verify.contains(&rust.name)
                 {
@@ -0,0 +1,142 @@
+#![allow(unused)]
+
+use std::collections::HashSet;
+
+struct Intrinsic {
+    name: &'static str,
+    arch: &'static str,
+    target_feature: Option<&'static str>,
+    stability: &'static [Stability],
+}
+
+struct Stability {
+    /// The `cfg_attr` predicate the attribute is under, if any.
+    cfg: Option<&'static str>,
+    stable: bool,
+    feature: &'static str,
+}
+
+stdarch_verify::intrinsics_manifest!(const INTRINSICS_MANIFEST);
+
+const KNOWN_ARCHES: &[&str] = &[
+    "aarch64",
+    "arm",
+    "arm_shared",
+    "loongarch64",
+    "mips",
+    "nvptx",
+    "powerpc",
+    "powerpc64",
+    "riscv32",
+    "riscv64",
+    "riscv_shared",
+    "wasm32",
+    "x86",
+    "x86_64",
+];
+
+/// Target features that are stable in rustc, per `core_arch` module. An
+/// intrinsic that is unconditionally `#[stable]` may only enable these.
+fn stable_target_features(arch: &str) -> &'static [&'static str] {
+    match arch {
+        "x86" | "x86_64" => &[
+            "adx",
+            "aes",
+            "avx",
+            "avx2",
+            "bmi1",
+            "bmi2",
+            "cmpxchg16b",
+            "f16c",
+            "fma",
+            "fxsr",
+            "lzcnt",
+            "pclmulqdq",
+            "popcnt",
+            "rdrand",
+            "rdseed",
+            "sha",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "sse4.2",
+            "sse4a",
+            "ssse3",
+            "tbm",
+            "xsave",
+            "xsavec",
+            "xsaveopt",
+            "xsaves",
+        ],
+        "aarch64" | "arm_shared" => &[
+            "aes", "crc", "fp-armv8", "neon", "rdm", "sha2", "sha3", "v7", "v8", "vfp4",
+        ],
+        "wasm32" => &["relaxed-simd", "simd128"],
+        _ => &[],
+    }
+}
+
+#[test]
+fn manifest_is_not_empty() {
+    let arches: HashSet<_> = INTRINSICS_MANIFEST.iter().map(|i| i.arch).collect();
+    for arch in ["aarch64", "arm_shared", "x86", "x86_64"] {
+        assert!(arches.contains(arch), "no intrinsics listed for {arch}");
+    }
+}
+
+#[test]
+fn every_entry_is_well_formed() {
+    for intrinsic in INTRINSICS_MANIFEST {
+        assert!(
+            KNOWN_ARCHES.contains(&intrinsic.arch),
+            "unknown arch {} for {}",
+            intrinsic.arch,
+            intrinsic.name
+        );
+        // Some intrinsics, such as `__cpuid`, need no target feature at all.
+        if let Some(feature) = intrinsic.target_feature {
+            assert!(
+                feature.split(',').all(|f| !f.is_empty()),
+                "empty target feature in {feature:?} for {}",
+                intrinsic.name
+            );
+        }
+        assert!(
+            !intrinsic.stability.is_empty(),
+            "missing stability attribute for {}",
+            intrinsic.name
+        );
+        for stability in intrinsic.stability {
+            assert!(
+                !stability.feature.is_empty(),
+                "empty stability feature for {}",
+                intrinsic.name
+            );
+        }
+    }
+}
+
+#[test]
+fn stable_intrinsics_only_enable_stable_features() {
+    for intrinsic in INTRINSICS_MANIFEST {
+        let stable = intrinsic
+            .stability
+            .iter()
+            .any(|s| s.cfg.is_none() && s.stable);
+        let Some(features) = intrinsic.target_feature else {
+            continue;
+        };
+        if !stable {
+            continue;
+        }
+        let allowed = stable_target_features(intrinsic.arch);
+        for feature in features.split(',') {
+            assert!(
+                allowed.contains(&feature),
+                "{} is stable but enables the unstable target feature {feature}",
+                intrinsic.name
+            );
+        }
+    }
+}
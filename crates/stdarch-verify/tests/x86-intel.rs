@@ -208,11 +208,31 @@ fn verify_all_signatures() {
                 // TSC
                 "_rdtsc",
                 "__rdtscp",
+                // Privileged unless CR4.PCE is set
+                "_rdpmc",
                 // TBM
                 "_t1mskc_u64",
                 // RTM
                 "_xbegin",
                 "_xend",
+                // WAITPKG, no run-time detection yet
+                "_tpause",
+                "_umwait",
+                "_umonitor",
+                // MOVDIRI and MOVDIR64B, no run-time detection yet
+                "_directstoreu_u32",
+                "_directstoreu_u64",
+                "_movdir64b",
+                // ENQCMD, needs a device to submit to
+                "_enqcmd",
+                "_enqcmds",
+                // CET shadow stack, raise #UD unless one is enabled
+                "_incsspd",
+                "_incsspq",
+                "_saveprevssp",
+                "_rstorssp",
+                "_wrssd",
+                "_wrssq",
                 // RDRAND
                 "_rdrand16_step",
                 "_rdrand32_step",
@@ -305,7 +325,18 @@ fn verify_all_signatures() {
             "__cpuid" |
             "__get_cpuid_max" |
             // Not listed with intel, but manually verified
-            "cmpxchg16b"
+            "cmpxchg16b" |
+            // MSVC intrinsics, signatures manually verified against their
+            // documentation
+            "_interlockedbittestandset" |
+            "_interlockedbittestandreset" |
+            "_interlockedbittestandset64" |
+            "_interlockedbittestandreset64" |
+            // Not defined by Intel, see the module docs of `argminmax.rs`
+            "_mm256_argmin_epi32" |
+            "_mm256_argmax_epi32" |
+            "_mm512_argmin_epi32" |
+            "_mm512_argmax_epi32"
             => continue,
             // Intel requires the mask argument for _mm_shuffle_ps to be an
             // unsigned integer, but all other _mm_shuffle_.. intrinsics
@@ -410,6 +441,9 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
     match rust.name {
         "_bswap" | "_bswap64" => {}
 
+        // RDPMC is available on every x86 CPU but has no cpuid listed
+        "_rdpmc" => {}
+
         // These don't actually have a target feature unlike their brethren with
         // the `x` inside the name which requires adx
         "_addcarry_u32" | "_addcarry_u64" | "_subborrow_u32" | "_subborrow_u64" => {}
@@ -459,6 +493,22 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
             continue;
         }
 
+        // rustc doesn't know about the `waitpkg` target feature yet, so these
+        // intrinsics are implemented with inline assembly without enabling it.
+        if *cpuid == "WAITPKG" {
+            continue;
+        }
+
+        // Same for `movdiri`, `movdir64b`, `enqcmd`, `serialize` and `shstk`.
+        if *cpuid == "MOVDIRI"
+            || *cpuid == "MOVDIR64B"
+            || *cpuid == "ENQCMD"
+            || *cpuid == "SERIALIZE"
+            || *cpuid == "CET_SS"
+        {
+            continue;
+        }
+
         // Some CPUs support VAES/GFNI/VPCLMULQDQ without AVX512, even though
         // the Intel documentation states that those instructions require
         // AVX512VL.
@@ -662,7 +712,10 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         // These return a 64-bit argument but they're assembled from other
         // 32-bit registers, so these work on 32-bit just fine. See #308 for
         // more info.
-        "_rdtsc" | "__rdtscp" => true,
+        "_rdtsc" | "__rdtscp" | "_rdpmc" => true,
+
+        // Likewise, the 64-bit deadline of these is split into EDX:EAX.
+        "_tpause" | "_umwait" => true,
 
         _ => false,
     };
@@ -0,0 +1,253 @@
+//! AES-128 and AES-256 key expansion with AES-NI.
+//!
+//! `_mm_aesenc_si128` and friends perform one AES round, but they need the
+//! round keys, which are derived from the cipher key by the key schedule of
+//! FIPS-197. `_mm_aeskeygenassist_si128` computes the expensive part of a step
+//! of the schedule, the S-box lookups and the round constant, and leaves the
+//! XOR-ing with the previous round key to shuffles and XORs:
+//!
+//! * Dword 3 of its result is `RotWord(SubWord(w)) ^ RCON`, for the last
+//!   word `w` of the previous round key. Every AES-128 step and every other
+//!   AES-256 step uses it.
+//! * Dword 2 is `SubWord(w)` without the rotation or round constant, which
+//!   the remaining AES-256 steps use.
+//!
+//! The round constant has to be an immediate, so each step is a separate
+//! instantiation of a function generic over it.
+//!
+//! You can run the program, which prints the AES-128 round keys of the
+//! FIPS-197 example key and encrypts a block with AES-128 and AES-256, via:
+//!
+//!     cargo +nightly run --release --bin aes
+
+#![allow(internal_features)]
+#![cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature(stdarch_internal)
+)]
+#![allow(
+    clippy::unwrap_used,
+    clippy::print_stdout,
+    clippy::missing_docs_in_private_items
+)]
+
+#[cfg(target_arch = "x86")]
+use {core_arch::arch::x86::*, std_detect::is_x86_feature_detected};
+#[cfg(target_arch = "x86_64")]
+use {core_arch::arch::x86_64::*, std_detect::is_x86_feature_detected};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn main() {
+    println!("aes: {}", is_x86_feature_detected!("aes"));
+    if !is_x86_feature_detected!("aes") {
+        return;
+    }
+
+    // The keys and plaintext of FIPS-197, appendices C.1 and C.3.
+    let key: [u8; 32] = std::array::from_fn(|i| i as u8);
+    let block: [u8; 16] = std::array::from_fn(|i| (i * 0x11) as u8);
+    unsafe {
+        let round_keys = aes128_key_expansion(key[..16].try_into().unwrap());
+        for (i, k) in round_keys.iter().enumerate() {
+            println!("round key {i:2}: {}", hex(&to_bytes(*k)));
+        }
+        let ciphertext = aes_encrypt_block(&round_keys, block);
+        println!("AES-128 ciphertext: {}", hex(&ciphertext));
+
+        let round_keys = aes256_key_expansion(key);
+        let ciphertext = aes_encrypt_block(&round_keys, block);
+        println!("AES-256 ciphertext: {}", hex(&ciphertext));
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn main() {
+    println!("AES-NI is only available on x86");
+}
+
+/// Returns the 11 round keys of AES-128 for `key`.
+#[target_feature(enable = "aes")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn aes128_key_expansion(key: [u8; 16]) -> [__m128i; 11] {
+    let k0 = _mm_loadu_si128(key.as_ptr().cast());
+    let k1 = aes128_step::<0x01>(k0);
+    let k2 = aes128_step::<0x02>(k1);
+    let k3 = aes128_step::<0x04>(k2);
+    let k4 = aes128_step::<0x08>(k3);
+    let k5 = aes128_step::<0x10>(k4);
+    let k6 = aes128_step::<0x20>(k5);
+    let k7 = aes128_step::<0x40>(k6);
+    let k8 = aes128_step::<0x80>(k7);
+    let k9 = aes128_step::<0x1b>(k8);
+    let k10 = aes128_step::<0x36>(k9);
+    [k0, k1, k2, k3, k4, k5, k6, k7, k8, k9, k10]
+}
+
+/// Computes the round key following `k` with the round constant `RCON`.
+#[target_feature(enable = "aes")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn aes128_step<const RCON: i32>(k: __m128i) -> __m128i {
+    let t = _mm_shuffle_epi32::<0xff>(_mm_aeskeygenassist_si128::<RCON>(k));
+    _mm_xor_si128(prefix_xor(k), t)
+}
+
+/// Returns the 15 round keys of AES-256 for `key`.
+#[target_feature(enable = "aes")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn aes256_key_expansion(key: [u8; 32]) -> [__m128i; 15] {
+    let k0 = _mm_loadu_si128(key.as_ptr().cast());
+    let k1 = _mm_loadu_si128(key.as_ptr().add(16).cast());
+    let k2 = aes256_step_even::<0x01>(k0, k1);
+    let k3 = aes256_step_odd(k1, k2);
+    let k4 = aes256_step_even::<0x02>(k2, k3);
+    let k5 = aes256_step_odd(k3, k4);
+    let k6 = aes256_step_even::<0x04>(k4, k5);
+    let k7 = aes256_step_odd(k5, k6);
+    let k8 = aes256_step_even::<0x08>(k6, k7);
+    let k9 = aes256_step_odd(k7, k8);
+    let k10 = aes256_step_even::<0x10>(k8, k9);
+    let k11 = aes256_step_odd(k9, k10);
+    let k12 = aes256_step_even::<0x20>(k10, k11);
+    let k13 = aes256_step_odd(k11, k12);
+    let k14 = aes256_step_even::<0x40>(k12, k13);
+    [
+        k0, k1, k2, k3, k4, k5, k6, k7, k8, k9, k10, k11, k12, k13, k14,
+    ]
+}
+
+/// Computes the round key following `a` and `b`, with rotation and the
+/// round constant `RCON`.
+#[target_feature(enable = "aes")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn aes256_step_even<const RCON: i32>(a: __m128i, b: __m128i) -> __m128i {
+    let t = _mm_shuffle_epi32::<0xff>(_mm_aeskeygenassist_si128::<RCON>(b));
+    _mm_xor_si128(prefix_xor(a), t)
+}
+
+/// Computes the round key following `a` and `b`, which only substitutes the
+/// last word of `b`.
+#[target_feature(enable = "aes")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn aes256_step_odd(a: __m128i, b: __m128i) -> __m128i {
+    let t = _mm_shuffle_epi32::<0xaa>(_mm_aeskeygenassist_si128::<0>(b));
+    _mm_xor_si128(prefix_xor(a), t)
+}
+
+/// XORs each 32-bit word of `k` with all the words before it, as each word
+/// of a round key depends on the previous one.
+#[target_feature(enable = "sse2")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn prefix_xor(k: __m128i) -> __m128i {
+    let k = _mm_xor_si128(k, _mm_slli_si128::<4>(k));
+    _mm_xor_si128(k, _mm_slli_si128::<8>(k))
+}
+
+/// Encrypts `block` with the round keys of AES-128, AES-192 or AES-256.
+#[target_feature(enable = "aes")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn aes_encrypt_block(round_keys: &[__m128i], block: [u8; 16]) -> [u8; 16] {
+    let (last, middle) = round_keys[1..].split_last().unwrap();
+    let mut b = _mm_xor_si128(_mm_loadu_si128(block.as_ptr().cast()), round_keys[0]);
+    for k in middle {
+        b = _mm_aesenc_si128(b, *k);
+    }
+    to_bytes(_mm_aesenclast_si128(b, *last))
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn to_bytes(a: __m128i) -> [u8; 16] {
+    let mut b = [0; 16];
+    unsafe { _mm_storeu_si128(b.as_mut_ptr().cast(), a) };
+    b
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(all(test, any(target_arch = "x86", target_arch = "x86_64")))]
+mod tests {
+    use super::*;
+
+    fn unhex(s: &str) -> [u8; 16] {
+        std::array::from_fn(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+    }
+
+    // FIPS-197, appendix A.1.
+    #[test]
+    fn aes128_expanded_key() {
+        if !is_x86_feature_detected!("aes") {
+            return;
+        }
+        let key = unhex("2b7e151628aed2a6abf7158809cf4f3c");
+        let e = [
+            "2b7e151628aed2a6abf7158809cf4f3c",
+            "a0fafe1788542cb123a339392a6c7605",
+            "f2c295f27a96b9435935807a7359f67f",
+            "3d80477d4716fe3e1e237e446d7a883b",
+            "ef44a541a8525b7fb671253bdb0bad00",
+            "d4d1c6f87c839d87caf2b8bc11f915bc",
+            "6d88a37a110b3efddbf98641ca0093fd",
+            "4e54f70e5f5fc9f384a64fb24ea6dc4f",
+            "ead27321b58dbad2312bf5607f8d292f",
+            "ac7766f319fadc2128d12941575c006e",
+            "d014f9a8c9ee2589e13f0cc8b6630ca6",
+        ];
+        let round_keys = unsafe { aes128_key_expansion(key) };
+        for (i, (k, e)) in round_keys.into_iter().zip(e).enumerate() {
+            assert_eq!(to_bytes(k), unhex(e), "round key {i}");
+        }
+    }
+
+    // FIPS-197, appendix A.3.
+    #[test]
+    fn aes256_expanded_key() {
+        if !is_x86_feature_detected!("aes") {
+            return;
+        }
+        let mut key = [0; 32];
+        key[..16].copy_from_slice(&unhex("603deb1015ca71be2b73aef0857d7781"));
+        key[16..].copy_from_slice(&unhex("1f352c073b6108d72d9810a30914dff4"));
+        let e = [
+            "603deb1015ca71be2b73aef0857d7781",
+            "1f352c073b6108d72d9810a30914dff4",
+            "9ba354118e6925afa51a8b5f2067fcde",
+            "a8b09c1a93d194cdbe49846eb75d5b9a",
+            "d59aecb85bf3c917fee94248de8ebe96",
+            "b5a9328a2678a647983122292f6c79b3",
+            "812c81addadf48ba24360af2fab8b464",
+            "98c5bfc9bebd198e268c3ba709e04214",
+            "68007bacb2df331696e939e46c518d80",
+            "c814e20476a9fb8a5025c02d59c58239",
+            "de1369676ccc5a71fa2563959674ee15",
+            "5886ca5d2e2f31d77e0af1fa27cf73c3",
+            "749c47ab18501ddae2757e4f7401905a",
+            "cafaaae3e4d59b349adf6acebd10190d",
+            "fe4890d1e6188d0b046df344706c631e",
+        ];
+        let round_keys = unsafe { aes256_key_expansion(key) };
+        for (i, (k, e)) in round_keys.into_iter().zip(e).enumerate() {
+            assert_eq!(to_bytes(k), unhex(e), "round key {i}");
+        }
+    }
+
+    // FIPS-197, appendices C.1 and C.3.
+    #[test]
+    fn encrypt_with_expanded_keys() {
+        if !is_x86_feature_detected!("aes") {
+            return;
+        }
+        let plaintext = unhex("00112233445566778899aabbccddeeff");
+        let key: [u8; 32] = std::array::from_fn(|i| i as u8);
+        unsafe {
+            let round_keys = aes128_key_expansion(key[..16].try_into().unwrap());
+            let r = aes_encrypt_block(&round_keys, plaintext);
+            assert_eq!(r, unhex("69c4e0d86a7b0430d8cdb78070b4c55a"));
+
+            let round_keys = aes256_key_expansion(key);
+            let r = aes_encrypt_block(&round_keys, plaintext);
+            assert_eq!(r, unhex("8ea2b7ca516745bfeafc49904b496089"));
+        }
+    }
+}
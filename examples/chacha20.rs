@@ -0,0 +1,373 @@
+//! The ChaCha20 stream cipher from RFC 8439, implemented with runtime
+//! dispatch like the `hex` example.
+//!
+//! ChaCha20 turns a key, a nonce and a block counter into 64 bytes of
+//! keystream by running 20 rounds over a 4x4 matrix of `u32`s. Each row of
+//! the matrix fits in a 128-bit vector, so each quarter round works on all
+//! four columns at once. For the diagonal rounds the last three rows are
+//! rotated by one, two and three lanes, which lines the diagonals up as
+//! columns, and rotated back afterwards.
+//!
+//! Neither SSE2 nor NEON has a vector rotate. SSE2 combines two shifts with
+//! an or. On AArch64, `vsriq_n_u32` shifts right and inserts into the result
+//! of the left shift, and the rotation by 16 is a swap of the 16-bit halves
+//! with `vrev32q_u16`.
+//!
+//! The three-way XOR `veor3q_u32` and `vbcaxq_u32` of the AArch64 SHA3
+//! extension don't help here, although they speed up Keccak: every XOR of
+//! the quarter round has two inputs and feeds a rotation, so there is no
+//! XOR to fuse it with.
+//!
+//! You can run the program, which encrypts a message and prints the
+//! ciphertext, via:
+//!
+//!     cargo +nightly run --release --bin chacha20
+
+#![allow(internal_features)]
+#![cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature(stdarch_internal)
+)]
+#![allow(
+    clippy::print_stdout,
+    clippy::cast_ptr_alignment,
+    clippy::missing_docs_in_private_items
+)]
+
+#[cfg(target_arch = "aarch64")]
+use {core_arch::arch::aarch64::*, std_detect::is_aarch64_feature_detected};
+#[cfg(target_arch = "x86")]
+use {core_arch::arch::x86::*, std_detect::is_x86_feature_detected};
+#[cfg(target_arch = "x86_64")]
+use {core_arch::arch::x86_64::*, std_detect::is_x86_feature_detected};
+
+fn main() {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        println!("sse2: {}", is_x86_feature_detected!("sse2"));
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        println!("neon: {}", is_aarch64_feature_detected!("neon"));
+        println!("sha3: {}", is_aarch64_feature_detected!("sha3"));
+    }
+
+    let key: [u8; 32] = std::array::from_fn(|i| i as u8);
+    let nonce = [0, 0, 0, 0, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+    let mut data = b"Hello, ChaCha20!".to_vec();
+    chacha20(&key, 1, &nonce, &mut data);
+    let hex: String = data.iter().map(|b| format!("{b:02x}")).collect();
+    println!("ciphertext: {hex}");
+}
+
+/// Encrypts or decrypts `data` in place by XOR-ing it with the keystream for
+/// `key` and `nonce`, starting at block `counter`.
+///
+/// The counter wraps around after 2^32 blocks, which must not happen for a
+/// single nonce.
+fn chacha20(key: &[u8; 32], counter: u32, nonce: &[u8; 12], data: &mut [u8]) {
+    let state = initial_state(key, counter, nonce);
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("sse2") {
+            return unsafe { chacha20_sse2(&state, data) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if is_aarch64_feature_detected!("neon") {
+            return unsafe { chacha20_neon(&state, data) };
+        }
+    }
+
+    chacha20_fallback(&state, data)
+}
+
+/// Returns the matrix for the first block: four constants, the key, the
+/// block counter and the nonce.
+fn initial_state(key: &[u8; 32], counter: u32, nonce: &[u8; 12]) -> [u32; 16] {
+    let word = |b: &[u8]| u32::from_le_bytes(b.try_into().unwrap());
+    let mut state = [0; 16];
+    state[..4].copy_from_slice(&[0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574]);
+    for (s, k) in state[4..12].iter_mut().zip(key.chunks(4)) {
+        *s = word(k);
+    }
+    state[12] = counter;
+    for (s, n) in state[13..].iter_mut().zip(nonce.chunks(4)) {
+        *s = word(n);
+    }
+    state
+}
+
+/// XORs `chunk`, which may be shorter than a block at the end of the
+/// message, with `keystream`.
+fn xor_keystream(chunk: &mut [u8], keystream: &[u8; 64]) {
+    for (c, k) in chunk.iter_mut().zip(keystream) {
+        *c ^= k;
+    }
+}
+
+#[target_feature(enable = "sse2")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn chacha20_sse2(state: &[u32; 16], data: &mut [u8]) {
+    let p = state.as_ptr() as *const __m128i;
+    let s = [
+        _mm_loadu_si128(p),
+        _mm_loadu_si128(p.add(1)),
+        _mm_loadu_si128(p.add(2)),
+    ];
+    let mut s3 = _mm_loadu_si128(p.add(3));
+    for chunk in data.chunks_mut(64) {
+        let [mut a, mut b, mut c] = s;
+        let mut d = s3;
+        for _ in 0..10 {
+            (a, b, c, d) = quarter_round_sse2(a, b, c, d);
+            b = _mm_shuffle_epi32::<0b00_11_10_01>(b);
+            c = _mm_shuffle_epi32::<0b01_00_11_10>(c);
+            d = _mm_shuffle_epi32::<0b10_01_00_11>(d);
+            (a, b, c, d) = quarter_round_sse2(a, b, c, d);
+            b = _mm_shuffle_epi32::<0b10_01_00_11>(b);
+            c = _mm_shuffle_epi32::<0b01_00_11_10>(c);
+            d = _mm_shuffle_epi32::<0b00_11_10_01>(d);
+        }
+        let keystream = [
+            _mm_add_epi32(a, s[0]),
+            _mm_add_epi32(b, s[1]),
+            _mm_add_epi32(c, s[2]),
+            _mm_add_epi32(d, s3),
+        ];
+        if chunk.len() == 64 {
+            let p = chunk.as_mut_ptr() as *mut __m128i;
+            for (i, k) in keystream.into_iter().enumerate() {
+                _mm_storeu_si128(p.add(i), _mm_xor_si128(_mm_loadu_si128(p.add(i)), k));
+            }
+        } else {
+            let mut block = [0; 64];
+            let p = block.as_mut_ptr() as *mut __m128i;
+            for (i, k) in keystream.into_iter().enumerate() {
+                _mm_storeu_si128(p.add(i), k);
+            }
+            xor_keystream(chunk, &block);
+        }
+        s3 = _mm_add_epi32(s3, _mm_setr_epi32(1, 0, 0, 0));
+    }
+}
+
+#[target_feature(enable = "sse2")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn quarter_round_sse2(
+    mut a: __m128i,
+    mut b: __m128i,
+    mut c: __m128i,
+    mut d: __m128i,
+) -> (__m128i, __m128i, __m128i, __m128i) {
+    a = _mm_add_epi32(a, b);
+    d = _mm_xor_si128(d, a);
+    d = _mm_or_si128(_mm_slli_epi32::<16>(d), _mm_srli_epi32::<16>(d));
+    c = _mm_add_epi32(c, d);
+    b = _mm_xor_si128(b, c);
+    b = _mm_or_si128(_mm_slli_epi32::<12>(b), _mm_srli_epi32::<20>(b));
+    a = _mm_add_epi32(a, b);
+    d = _mm_xor_si128(d, a);
+    d = _mm_or_si128(_mm_slli_epi32::<8>(d), _mm_srli_epi32::<24>(d));
+    c = _mm_add_epi32(c, d);
+    b = _mm_xor_si128(b, c);
+    b = _mm_or_si128(_mm_slli_epi32::<7>(b), _mm_srli_epi32::<25>(b));
+    (a, b, c, d)
+}
+
+#[target_feature(enable = "neon")]
+#[cfg(target_arch = "aarch64")]
+unsafe fn chacha20_neon(state: &[u32; 16], data: &mut [u8]) {
+    let p = state.as_ptr();
+    let s = [vld1q_u32(p), vld1q_u32(p.add(4)), vld1q_u32(p.add(8))];
+    let mut s3 = vld1q_u32(p.add(12));
+    for chunk in data.chunks_mut(64) {
+        let [mut a, mut b, mut c] = s;
+        let mut d = s3;
+        for _ in 0..10 {
+            (a, b, c, d) = quarter_round_neon(a, b, c, d);
+            b = vextq_u32::<1>(b, b);
+            c = vextq_u32::<2>(c, c);
+            d = vextq_u32::<3>(d, d);
+            (a, b, c, d) = quarter_round_neon(a, b, c, d);
+            b = vextq_u32::<3>(b, b);
+            c = vextq_u32::<2>(c, c);
+            d = vextq_u32::<1>(d, d);
+        }
+        let keystream = [
+            vaddq_u32(a, s[0]),
+            vaddq_u32(b, s[1]),
+            vaddq_u32(c, s[2]),
+            vaddq_u32(d, s3),
+        ];
+        if chunk.len() == 64 {
+            let p = chunk.as_mut_ptr();
+            for (i, k) in keystream.into_iter().enumerate() {
+                let p = p.add(16 * i);
+                vst1q_u8(p, veorq_u8(vld1q_u8(p), vreinterpretq_u8_u32(k)));
+            }
+        } else {
+            let mut block = [0; 64];
+            for (i, k) in keystream.into_iter().enumerate() {
+                vst1q_u8(block.as_mut_ptr().add(16 * i), vreinterpretq_u8_u32(k));
+            }
+            xor_keystream(chunk, &block);
+        }
+        s3 = vaddq_u32(s3, vsetq_lane_u32::<0>(1, vdupq_n_u32(0)));
+    }
+}
+
+#[target_feature(enable = "neon")]
+#[cfg(target_arch = "aarch64")]
+unsafe fn quarter_round_neon(
+    mut a: uint32x4_t,
+    mut b: uint32x4_t,
+    mut c: uint32x4_t,
+    mut d: uint32x4_t,
+) -> (uint32x4_t, uint32x4_t, uint32x4_t, uint32x4_t) {
+    a = vaddq_u32(a, b);
+    d = veorq_u32(d, a);
+    d = vreinterpretq_u32_u16(vrev32q_u16(vreinterpretq_u16_u32(d)));
+    c = vaddq_u32(c, d);
+    b = veorq_u32(b, c);
+    b = vsriq_n_u32::<20>(vshlq_n_u32::<12>(b), b);
+    a = vaddq_u32(a, b);
+    d = veorq_u32(d, a);
+    d = vsriq_n_u32::<24>(vshlq_n_u32::<8>(d), d);
+    c = vaddq_u32(c, d);
+    b = veorq_u32(b, c);
+    b = vsriq_n_u32::<25>(vshlq_n_u32::<7>(b), b);
+    (a, b, c, d)
+}
+
+fn chacha20_fallback(state: &[u32; 16], data: &mut [u8]) {
+    let mut state = *state;
+    for chunk in data.chunks_mut(64) {
+        let mut x = state;
+        for _ in 0..10 {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+        let mut block = [0; 64];
+        for ((b, x), s) in block.chunks_mut(4).zip(x).zip(state) {
+            b.copy_from_slice(&x.wrapping_add(s).to_le_bytes());
+        }
+        xor_keystream(chunk, &block);
+        state[12] = state[12].wrapping_add(1);
+    }
+}
+
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type ChaCha20 = unsafe fn(&[u32; 16], &mut [u8]);
+
+    /// Every implementation available on the host, including the fallback.
+    fn implementations() -> Vec<(&'static str, ChaCha20)> {
+        #[allow(unused_mut)]
+        let mut v: Vec<(&str, ChaCha20)> = vec![("chacha20_fallback", chacha20_fallback)];
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if is_x86_feature_detected!("sse2") {
+            v.push(("chacha20_sse2", chacha20_sse2));
+        }
+        #[cfg(target_arch = "aarch64")]
+        if is_aarch64_feature_detected!("neon") {
+            v.push(("chacha20_neon", chacha20_neon));
+        }
+        v
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        let s: String = s.split_whitespace().collect();
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const KEY: [u8; 32] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f,
+    ];
+
+    // RFC 8439, section 2.3.2.
+    #[test]
+    fn block_function_test_vector() {
+        let nonce = [0, 0, 0, 0x09, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+        let state = initial_state(&KEY, 1, &nonce);
+        let e = unhex(
+            "10f1e7e4d13b5915500fdd1fa32071c4 c7d1f4c733c068030422aa9ac3d46c4e
+             d2826446079faa0914c2d705d98b02a2 b5129cd1de164eb9cbd083e8a2503c4e",
+        );
+        for (name, f) in implementations() {
+            let mut block = [0; 64];
+            unsafe { f(&state, &mut block) };
+            assert_eq!(block[..], e[..], "{name}");
+        }
+    }
+
+    // RFC 8439, section 2.4.2.
+    #[test]
+    fn encryption_test_vector() {
+        let nonce = [0, 0, 0, 0, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+        let plaintext = b"Ladies and Gentlemen of the class of '99: \
+            If I could offer you only one tip for the future, sunscreen would be it.";
+        let e = unhex(
+            "6e2e359a2568f98041ba0728dd0d6981 e97e7aec1d4360c20a27afccfd9fae0b
+             f91b65c5524733ab8f593dabcd62b357 1639d624e65152ab8f530c359f0861d8
+             07ca0dbf500d6a6156a38e088a22b65e 52bc514d16ccf806818ce91ab7793736
+             5af90bbf74a35be6b40b8eedf2785e42 874d",
+        );
+        let state = initial_state(&KEY, 1, &nonce);
+        for (name, f) in implementations() {
+            let mut data = plaintext.to_vec();
+            unsafe { f(&state, &mut data) };
+            assert_eq!(data, e, "{name}");
+            // Decryption is the same operation.
+            unsafe { f(&state, &mut data) };
+            assert_eq!(data, plaintext, "{name}");
+        }
+
+        let mut data = plaintext.to_vec();
+        chacha20(&KEY, 1, &nonce, &mut data);
+        assert_eq!(data, e);
+    }
+
+    #[test]
+    fn matches_fallback() {
+        let nonce = [7; 12];
+        // The counter wraps around from the first to the second block.
+        let state = initial_state(&KEY, u32::MAX, &nonce);
+        let input: Vec<u8> = (0..300).map(|i| i as u8).collect();
+        for len in [0, 1, 15, 16, 63, 64, 65, 128, 200, 300] {
+            let mut e = input[..len].to_vec();
+            chacha20_fallback(&state, &mut e);
+            for (name, f) in implementations() {
+                let mut data = input[..len].to_vec();
+                unsafe { f(&state, &mut data) };
+                assert_eq!(data, e, "{name} for length {len}");
+            }
+        }
+    }
+}
@@ -0,0 +1,602 @@
+//! Common kernels implemented with runtime dispatch, next to their scalar
+//! baselines.
+//!
+//! Each kernel has a portable fallback and one or more implementations using
+//! x86 or AArch64 intrinsics, and selects the best one available at runtime
+//! like the `hex` example. The benchmarks compare each implementation with
+//! its fallback, so that codegen regressions in the intrinsics show up as a
+//! slowdown. Implementations for instruction sets the host doesn't support
+//! are skipped.
+//!
+//! The kernels are:
+//!
+//! * `dot_product`: the dot product of two `f32` slices.
+//! * `first_mismatch`: the index of the first difference between two byte
+//!   slices, as used by `memcmp`.
+//! * `validate_utf8`: UTF-8 validation, shared with the `utf8` example.
+//! * `rgba_to_gray`: conversion of RGBA pixels to 8-bit luma.
+//!
+//! You can run the program, which prints which implementations are used, via:
+//!
+//!     cargo +nightly run --release --bin kernels
+//!
+//! and the benchmarks via:
+//!
+//!     cargo +nightly bench --bin kernels -p stdarch_examples
+
+#![allow(internal_features)]
+#![cfg_attr(test, feature(test))]
+#![cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature(stdarch_internal)
+)]
+#![allow(
+    clippy::print_stdout,
+    clippy::cast_possible_wrap,
+    clippy::cast_ptr_alignment,
+    clippy::cast_sign_loss,
+    clippy::missing_docs_in_private_items
+)]
+
+#[path = "utf8_validator.rs"]
+mod utf8_validator;
+
+use utf8_validator::*;
+
+#[cfg(target_arch = "aarch64")]
+use {core_arch::arch::aarch64::*, std_detect::is_aarch64_feature_detected};
+#[cfg(target_arch = "x86")]
+use {core_arch::arch::x86::*, std_detect::is_x86_feature_detected};
+#[cfg(target_arch = "x86_64")]
+use {core_arch::arch::x86_64::*, std_detect::is_x86_feature_detected};
+
+fn main() {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        println!("sse2: {}", is_x86_feature_detected!("sse2"));
+        println!("ssse3: {}", is_x86_feature_detected!("ssse3"));
+        println!("avx2: {}", is_x86_feature_detected!("avx2"));
+        println!("fma: {}", is_x86_feature_detected!("fma"));
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        println!("neon: {}", is_aarch64_feature_detected!("neon"));
+    }
+
+    let a = (0..1000).map(|i| i as f32).collect::<Vec<_>>();
+    println!("dot_product: {}", dot_product(&a, &a));
+    println!(
+        "first_mismatch: {:?}",
+        first_mismatch(b"hello, world", b"hello, there")
+    );
+    println!("validate_utf8: {}", validate_utf8("héllo".as_bytes()));
+    let mut gray = [0; 2];
+    rgba_to_gray(&[255, 255, 255, 255, 255, 0, 0, 255], &mut gray);
+    println!("rgba_to_gray: {gray:?}");
+}
+
+/// Returns the dot product of `a` and `b`, which must have the same length.
+///
+/// The vectorized implementations sum in a different order than the
+/// fallback, so the results may differ by rounding.
+fn dot_product(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len());
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            return unsafe { dot_product_avx2(a, b) };
+        }
+        if is_x86_feature_detected!("sse") {
+            return unsafe { dot_product_sse(a, b) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if is_aarch64_feature_detected!("neon") {
+            return unsafe { dot_product_neon(a, b) };
+        }
+    }
+
+    dot_product_fallback(a, b)
+}
+
+#[target_feature(enable = "avx2,fma")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn dot_product_avx2(a: &[f32], b: &[f32]) -> f32 {
+    let n = a.len() / 8 * 8;
+    let mut acc = _mm256_setzero_ps();
+    for i in (0..n).step_by(8) {
+        let x = _mm256_loadu_ps(a.as_ptr().add(i));
+        let y = _mm256_loadu_ps(b.as_ptr().add(i));
+        acc = _mm256_fmadd_ps(x, y, acc);
+    }
+    let acc = _mm_add_ps(_mm256_castps256_ps128(acc), _mm256_extractf128_ps::<1>(acc));
+    hsum_ps(acc) + dot_product_fallback(&a[n..], &b[n..])
+}
+
+#[target_feature(enable = "sse")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn dot_product_sse(a: &[f32], b: &[f32]) -> f32 {
+    let n = a.len() / 4 * 4;
+    let mut acc = _mm_setzero_ps();
+    for i in (0..n).step_by(4) {
+        let x = _mm_loadu_ps(a.as_ptr().add(i));
+        let y = _mm_loadu_ps(b.as_ptr().add(i));
+        acc = _mm_add_ps(acc, _mm_mul_ps(x, y));
+    }
+    hsum_ps(acc) + dot_product_fallback(&a[n..], &b[n..])
+}
+
+/// Sums the four lanes of `x`.
+#[target_feature(enable = "sse")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn hsum_ps(x: __m128) -> f32 {
+    let x = _mm_add_ps(x, _mm_movehl_ps(x, x));
+    let x = _mm_add_ss(x, _mm_shuffle_ps::<0b01>(x, x));
+    _mm_cvtss_f32(x)
+}
+
+#[target_feature(enable = "neon")]
+#[cfg(target_arch = "aarch64")]
+unsafe fn dot_product_neon(a: &[f32], b: &[f32]) -> f32 {
+    let n = a.len() / 4 * 4;
+    let mut acc = vdupq_n_f32(0.0);
+    for i in (0..n).step_by(4) {
+        let x = vld1q_f32(a.as_ptr().add(i));
+        let y = vld1q_f32(b.as_ptr().add(i));
+        acc = vfmaq_f32(acc, x, y);
+    }
+    vaddvq_f32(acc) + dot_product_fallback(&a[n..], &b[n..])
+}
+
+fn dot_product_fallback(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Returns the index of the first byte that differs between `a` and `b`, or
+/// `None` if the shorter one is a prefix of the other.
+fn first_mismatch(a: &[u8], b: &[u8]) -> Option<usize> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { first_mismatch_avx2(a, b) };
+        }
+        if is_x86_feature_detected!("sse2") {
+            return unsafe { first_mismatch_sse2(a, b) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if is_aarch64_feature_detected!("neon") {
+            return unsafe { first_mismatch_neon(a, b) };
+        }
+    }
+
+    first_mismatch_fallback(a, b)
+}
+
+#[target_feature(enable = "avx2")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn first_mismatch_avx2(a: &[u8], b: &[u8]) -> Option<usize> {
+    let n = a.len().min(b.len()) / 32 * 32;
+    for i in (0..n).step_by(32) {
+        let x = _mm256_loadu_si256(a.as_ptr().add(i) as *const _);
+        let y = _mm256_loadu_si256(b.as_ptr().add(i) as *const _);
+        let ne = !(_mm256_movemask_epi8(_mm256_cmpeq_epi8(x, y)) as u32);
+        if ne != 0 {
+            return Some(i + ne.trailing_zeros() as usize);
+        }
+    }
+    first_mismatch_fallback(&a[n..], &b[n..]).map(|i| n + i)
+}
+
+#[target_feature(enable = "sse2")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn first_mismatch_sse2(a: &[u8], b: &[u8]) -> Option<usize> {
+    let n = a.len().min(b.len()) / 16 * 16;
+    for i in (0..n).step_by(16) {
+        let x = _mm_loadu_si128(a.as_ptr().add(i) as *const _);
+        let y = _mm_loadu_si128(b.as_ptr().add(i) as *const _);
+        let ne = !_mm_movemask_epi8(_mm_cmpeq_epi8(x, y)) & 0xffff;
+        if ne != 0 {
+            return Some(i + ne.trailing_zeros() as usize);
+        }
+    }
+    first_mismatch_fallback(&a[n..], &b[n..]).map(|i| n + i)
+}
+
+#[target_feature(enable = "neon")]
+#[cfg(target_arch = "aarch64")]
+unsafe fn first_mismatch_neon(a: &[u8], b: &[u8]) -> Option<usize> {
+    let n = a.len().min(b.len()) / 16 * 16;
+    for i in (0..n).step_by(16) {
+        let x = vld1q_u8(a.as_ptr().add(i));
+        let y = vld1q_u8(b.as_ptr().add(i));
+        // There is no movemask, so only look for the exact position once a
+        // chunk is known to differ.
+        if vminvq_u8(vceqq_u8(x, y)) != 0xff {
+            return first_mismatch_fallback(&a[i..i + 16], &b[i..i + 16]).map(|j| i + j);
+        }
+    }
+    first_mismatch_fallback(&a[n..], &b[n..]).map(|i| n + i)
+}
+
+fn first_mismatch_fallback(a: &[u8], b: &[u8]) -> Option<usize> {
+    a.iter().zip(b).position(|(x, y)| x != y)
+}
+
+/// Converts the RGBA pixels in `src` to 8-bit luma in `dst`, ignoring alpha.
+///
+/// This uses the BT.601 weights rounded to multiples of 1/128, which keeps the
+/// intermediate sums within 16 bits.
+fn rgba_to_gray(src: &[u8], dst: &mut [u8]) {
+    assert_eq!(src.len(), dst.len() * 4);
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("ssse3") {
+            return unsafe { rgba_to_gray_ssse3(src, dst) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if is_aarch64_feature_detected!("neon") {
+            return unsafe { rgba_to_gray_neon(src, dst) };
+        }
+    }
+
+    rgba_to_gray_fallback(src, dst)
+}
+
+const R_WEIGHT: u8 = 38;
+const G_WEIGHT: u8 = 75;
+const B_WEIGHT: u8 = 15;
+
+#[target_feature(enable = "ssse3")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn rgba_to_gray_ssse3(src: &[u8], dst: &mut [u8]) {
+    let weights = _mm_set1_epi32(i32::from_le_bytes([R_WEIGHT, G_WEIGHT, B_WEIGHT, 0]));
+    let round = _mm_set1_epi16(64);
+    let n = dst.len() / 8 * 8;
+    for i in (0..n).step_by(8) {
+        let lo = _mm_loadu_si128(src.as_ptr().add(i * 4) as *const _);
+        let hi = _mm_loadu_si128(src.as_ptr().add(i * 4 + 16) as *const _);
+        // R * wr + G * wg and B * wb + A * 0 for each pixel, then their sum.
+        let lo = _mm_maddubs_epi16(lo, weights);
+        let hi = _mm_maddubs_epi16(hi, weights);
+        let sum = _mm_hadd_epi16(lo, hi);
+        let gray = _mm_srli_epi16::<7>(_mm_add_epi16(sum, round));
+        let gray = _mm_packus_epi16(gray, gray);
+        _mm_storel_epi64(dst.as_mut_ptr().add(i) as *mut _, gray);
+    }
+    rgba_to_gray_fallback(&src[n * 4..], &mut dst[n..]);
+}
+
+#[target_feature(enable = "neon")]
+#[cfg(target_arch = "aarch64")]
+unsafe fn rgba_to_gray_neon(src: &[u8], dst: &mut [u8]) {
+    let n = dst.len() / 16 * 16;
+    for i in (0..n).step_by(16) {
+        let rgba = vld4q_u8(src.as_ptr().add(i * 4));
+        let lo = vmull_u8(vget_low_u8(rgba.0), vdup_n_u8(R_WEIGHT));
+        let lo = vmlal_u8(lo, vget_low_u8(rgba.1), vdup_n_u8(G_WEIGHT));
+        let lo = vmlal_u8(lo, vget_low_u8(rgba.2), vdup_n_u8(B_WEIGHT));
+        let hi = vmull_high_u8(rgba.0, vdupq_n_u8(R_WEIGHT));
+        let hi = vmlal_high_u8(hi, rgba.1, vdupq_n_u8(G_WEIGHT));
+        let hi = vmlal_high_u8(hi, rgba.2, vdupq_n_u8(B_WEIGHT));
+        let gray = vcombine_u8(vrshrn_n_u16::<7>(lo), vrshrn_n_u16::<7>(hi));
+        vst1q_u8(dst.as_mut_ptr().add(i), gray);
+    }
+    rgba_to_gray_fallback(&src[n * 4..], &mut dst[n..]);
+}
+
+fn rgba_to_gray_fallback(src: &[u8], dst: &mut [u8]) {
+    for (px, gray) in src.chunks_exact(4).zip(dst) {
+        let sum = px[0] as u16 * R_WEIGHT as u16
+            + px[1] as u16 * G_WEIGHT as u16
+            + px[2] as u16 * B_WEIGHT as u16;
+        *gray = ((sum + 64) >> 7) as u8;
+    }
+}
+
+// Run these with `cargo +nightly test --bin kernels -p stdarch_examples`
+#[cfg(test)]
+mod tests {
+    use std::str;
+
+    use super::*;
+
+    /// Every implementation of a kernel available on the host, including the
+    /// fallback.
+    macro_rules! implementations {
+        ($fallback:ident $(, $feature:tt $(+ $more:tt)* => $f:ident)* $(,)?) => {{
+            #[allow(unused_mut)]
+            let mut v = vec![(stringify!($fallback), $fallback as _)];
+            $(
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                if is_x86_feature_detected!($feature) $(&& is_x86_feature_detected!($more))* {
+                    v.push((stringify!($f), $f as _));
+                }
+            )*
+            v
+        }};
+    }
+
+    pub(super) type DotProduct = unsafe fn(&[f32], &[f32]) -> f32;
+    pub(super) type FirstMismatch = unsafe fn(&[u8], &[u8]) -> Option<usize>;
+    pub(super) type ValidateUtf8 = unsafe fn(&[u8]) -> bool;
+    pub(super) type RgbaToGray = unsafe fn(&[u8], &mut [u8]);
+
+    pub(super) fn dot_products() -> Vec<(&'static str, DotProduct)> {
+        #[allow(unused_mut)]
+        let mut v: Vec<(&str, DotProduct)> = implementations!(
+            dot_product_fallback,
+            "avx2" + "fma" => dot_product_avx2,
+            "sse" => dot_product_sse,
+        );
+        #[cfg(target_arch = "aarch64")]
+        if is_aarch64_feature_detected!("neon") {
+            v.push(("dot_product_neon", dot_product_neon));
+        }
+        v
+    }
+
+    pub(super) fn first_mismatches() -> Vec<(&'static str, FirstMismatch)> {
+        #[allow(unused_mut)]
+        let mut v: Vec<(&str, FirstMismatch)> = implementations!(
+            first_mismatch_fallback,
+            "avx2" => first_mismatch_avx2,
+            "sse2" => first_mismatch_sse2,
+        );
+        #[cfg(target_arch = "aarch64")]
+        if is_aarch64_feature_detected!("neon") {
+            v.push(("first_mismatch_neon", first_mismatch_neon));
+        }
+        v
+    }
+
+    pub(super) fn validate_utf8s() -> Vec<(&'static str, ValidateUtf8)> {
+        #[allow(unused_mut)]
+        let mut v: Vec<(&str, ValidateUtf8)> = implementations!(
+            validate_utf8_fallback,
+            "avx2" => validate_utf8_avx2,
+            "sse2" => validate_utf8_sse2,
+        );
+        #[cfg(target_arch = "aarch64")]
+        if is_aarch64_feature_detected!("neon") {
+            v.push(("validate_utf8_neon", validate_utf8_neon));
+        }
+        v
+    }
+
+    pub(super) fn rgba_to_grays() -> Vec<(&'static str, RgbaToGray)> {
+        #[allow(unused_mut)]
+        let mut v: Vec<(&str, RgbaToGray)> = implementations!(
+            rgba_to_gray_fallback,
+            "ssse3" => rgba_to_gray_ssse3,
+        );
+        #[cfg(target_arch = "aarch64")]
+        if is_aarch64_feature_detected!("neon") {
+            v.push(("rgba_to_gray_neon", rgba_to_gray_neon));
+        }
+        v
+    }
+
+    #[test]
+    fn dot_product_matches_fallback() {
+        for len in [0, 1, 3, 4, 7, 8, 9, 31, 100] {
+            let a = (0..len).map(|i| i as f32 * 0.5).collect::<Vec<_>>();
+            let b = (0..len).map(|i| 3.0 - i as f32).collect::<Vec<_>>();
+            // The sums of these small multiples of 1/2 are exact.
+            let e = dot_product_fallback(&a, &b);
+            for (name, f) in dot_products() {
+                assert_eq!(unsafe { f(&a, &b) }, e, "{name} for length {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn first_mismatch_every_position() {
+        let a = (0..100).map(|i| i as u8).collect::<Vec<_>>();
+        for (name, f) in first_mismatches() {
+            assert_eq!(unsafe { f(&a, &a) }, None, "{name}");
+            assert_eq!(unsafe { f(&a, &a[..50]) }, None, "{name}");
+            for i in 0..a.len() {
+                let mut b = a.clone();
+                b[i] ^= 0x80;
+                // A later difference must not hide the first one.
+                b[a.len() - 1] ^= 1;
+                assert_eq!(unsafe { f(&a, &b) }, Some(i), "{name} at {i}");
+            }
+        }
+    }
+
+    #[test]
+    fn validate_utf8_boundaries() {
+        let ascii = [b'a'; 64];
+        let mut inputs = vec![ascii.to_vec()];
+        // A multi-byte sequence, valid or not, at every offset.
+        for seq in [
+            &b"\xc3\xa9"[..],
+            b"\xe2\x82\xac",
+            b"\xf0\x9f\x98\x80",
+            b"\xc3",
+            b"\x80",
+        ] {
+            for i in 0..=ascii.len() {
+                let mut v = ascii.to_vec();
+                v.splice(i..i, seq.iter().copied());
+                inputs.push(v);
+            }
+        }
+        for input in &inputs {
+            let e = str::from_utf8(input).is_ok();
+            for (name, f) in validate_utf8s() {
+                assert_eq!(unsafe { f(input) }, e, "{name} for {input:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn rgba_to_gray_matches_fallback() {
+        let src = (0..4 * 37)
+            .map(|i| (i * 97 % 256) as u8)
+            .collect::<Vec<_>>();
+        let mut e = vec![0; 37];
+        rgba_to_gray_fallback(&src, &mut e);
+        for (name, f) in rgba_to_grays() {
+            let mut dst = vec![0; 37];
+            unsafe { f(&src, &mut dst) };
+            assert_eq!(dst, e, "{name}");
+        }
+        let mut dst = [0; 2];
+        rgba_to_gray(&[255, 255, 255, 0, 0, 0, 0, 255], &mut dst);
+        assert_eq!(dst, [255, 0]);
+    }
+
+    quickcheck::quickcheck! {
+        fn first_mismatch_equals_fallback(a: Vec<u8>, b: Vec<u8>) -> bool {
+            first_mismatch(&a, &b) == first_mismatch_fallback(&a, &b)
+        }
+
+        fn validate_utf8_equals_from_utf8(s: Vec<u8>) -> bool {
+            validate_utf8(&s) == str::from_utf8(&s).is_ok()
+        }
+
+        fn rgba_to_gray_equals_fallback(src: Vec<u8>) -> bool {
+            let src = &src[..src.len() / 4 * 4];
+            let mut a = vec![0; src.len() / 4];
+            let mut b = vec![0; src.len() / 4];
+            rgba_to_gray(src, &mut a);
+            rgba_to_gray_fallback(src, &mut b);
+            a == b
+        }
+    }
+}
+
+// Run these with `cargo +nightly bench --bin kernels -p stdarch_examples`
+#[cfg(test)]
+mod benches {
+    extern crate rand;
+    extern crate test;
+
+    use self::rand::Rng;
+
+    use super::tests::*;
+
+    const LEN: usize = 64 * 1024;
+
+    fn random_bytes(len: usize) -> Vec<u8> {
+        let mut rng = rand::thread_rng();
+        (0..len).map(|_| rng.gen()).collect()
+    }
+
+    /// Benchmarks the implementation called `name` in `impls`, if the host
+    /// supports it.
+    fn run<F: Copy, R>(
+        b: &mut test::Bencher,
+        impls: Vec<(&str, F)>,
+        name: &str,
+        mut bench: impl FnMut(F) -> R,
+    ) {
+        if let Some(&(_, f)) = impls.iter().find(|(n, _)| *n == name) {
+            b.bytes = LEN as u64;
+            b.iter(|| bench(f));
+        }
+    }
+
+    macro_rules! benches {
+        ($impls:ident, |$f:ident| $bench:expr, [$($name:ident),*]) => {$(
+            #[bench]
+            fn $name(b: &mut test::Bencher) {
+                run(b, $impls(), stringify!($name), |$f| $bench);
+            }
+        )*};
+    }
+
+    mod dot_product {
+        use super::*;
+
+        fn inputs() -> &'static (Vec<f32>, Vec<f32>) {
+            static INPUTS: std::sync::OnceLock<(Vec<f32>, Vec<f32>)> = std::sync::OnceLock::new();
+            INPUTS.get_or_init(|| {
+                let mut rng = rand::thread_rng();
+                let a = (0..LEN / 4).map(|_| rng.gen()).collect();
+                let b = (0..LEN / 4).map(|_| rng.gen()).collect();
+                (a, b)
+            })
+        }
+
+        benches!(
+            dot_products,
+            |f| unsafe { f(&inputs().0, &inputs().1) },
+            [
+                dot_product_fallback,
+                dot_product_avx2,
+                dot_product_sse,
+                dot_product_neon
+            ]
+        );
+    }
+
+    mod first_mismatch {
+        use super::*;
+
+        fn input() -> &'static Vec<u8> {
+            static INPUT: std::sync::OnceLock<Vec<u8>> = std::sync::OnceLock::new();
+            INPUT.get_or_init(|| random_bytes(LEN))
+        }
+
+        benches!(
+            first_mismatches,
+            |f| unsafe { f(input(), input()) },
+            [
+                first_mismatch_fallback,
+                first_mismatch_avx2,
+                first_mismatch_sse2,
+                first_mismatch_neon
+            ]
+        );
+    }
+
+    mod validate_utf8 {
+        use super::*;
+
+        fn input() -> &'static Vec<u8> {
+            static INPUT: std::sync::OnceLock<Vec<u8>> = std::sync::OnceLock::new();
+            // Mostly ASCII text ending in a multi-byte character.
+            INPUT.get_or_init(|| (" lorem ipsum".repeat(LEN / 12) + "é").into_bytes())
+        }
+
+        benches!(
+            validate_utf8s,
+            |f| unsafe { f(input()) },
+            [
+                validate_utf8_fallback,
+                validate_utf8_avx2,
+                validate_utf8_sse2,
+                validate_utf8_neon
+            ]
+        );
+    }
+
+    mod rgba_to_gray {
+        use super::*;
+
+        fn input() -> &'static Vec<u8> {
+            static INPUT: std::sync::OnceLock<Vec<u8>> = std::sync::OnceLock::new();
+            INPUT.get_or_init(|| random_bytes(LEN))
+        }
+
+        benches!(
+            rgba_to_grays,
+            |f| {
+                let mut dst = vec![0; LEN / 4];
+                unsafe { f(input(), &mut dst) };
+                dst
+            },
+            [rgba_to_gray_fallback, rgba_to_gray_ssse3, rgba_to_gray_neon]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! Checks whether standard input is valid UTF-8, with the validator in
+//! `utf8_validator.rs`.
+//!
+//! You can run it via:
+//!
+//!     echo hello | cargo +nightly run --release --bin utf8
+
+#![allow(internal_features)]
+#![cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature(stdarch_internal)
+)]
+#![allow(
+    clippy::print_stdout,
+    clippy::cast_ptr_alignment,
+    clippy::missing_docs_in_private_items
+)]
+
+use std::io::{self, Read};
+
+#[path = "utf8_validator.rs"]
+mod utf8_validator;
+
+use utf8_validator::*;
+
+fn main() {
+    let mut input = Vec::new();
+    io::stdin().read_to_end(&mut input).unwrap();
+    if validate_utf8(&input) {
+        println!("valid");
+    } else {
+        println!("invalid");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    #[cfg(target_arch = "aarch64")]
+    use std_detect::is_aarch64_feature_detected;
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    use std_detect::is_x86_feature_detected;
+
+    use super::*;
+
+    type ValidateUtf8 = unsafe fn(&[u8]) -> bool;
+
+    /// Every implementation available on the host, including the fallback.
+    fn implementations() -> Vec<(&'static str, ValidateUtf8)> {
+        #[allow(unused_mut)]
+        let mut v: Vec<(&str, ValidateUtf8)> =
+            vec![("validate_utf8_fallback", validate_utf8_fallback)];
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                v.push(("validate_utf8_avx2", validate_utf8_avx2));
+            }
+            if is_x86_feature_detected!("sse2") {
+                v.push(("validate_utf8_sse2", validate_utf8_sse2));
+            }
+        }
+        #[cfg(target_arch = "aarch64")]
+        if is_aarch64_feature_detected!("neon") {
+            v.push(("validate_utf8_neon", validate_utf8_neon));
+        }
+        v
+    }
+
+    fn check(bytes: &[u8]) {
+        let e = std::str::from_utf8(bytes).is_ok();
+        assert_eq!(validate_utf8(bytes), e, "{bytes:02x?}");
+        for (name, f) in implementations() {
+            assert_eq!(unsafe { f(bytes) }, e, "{name} for {bytes:02x?}");
+        }
+    }
+
+    /// Sequences around the edges of table 3-7 of the Unicode standard.
+    const SEQUENCES: &[&[u8]] = &[
+        // Valid sequences at the limits of each row.
+        b"\x7f",
+        b"\xc2\x80",
+        b"\xdf\xbf",
+        b"\xe0\xa0\x80",
+        b"\xed\x9f\xbf",
+        b"\xee\x80\x80",
+        b"\xef\xbf\xbf",
+        b"\xf0\x90\x80\x80",
+        b"\xf3\xbf\xbf\xbf",
+        b"\xf4\x8f\xbf\xbf",
+        // Overlong encodings.
+        b"\xc0\x80",
+        b"\xc1\xbf",
+        b"\xe0\x80\x80",
+        b"\xe0\x9f\xbf",
+        b"\xf0\x80\x80\x80",
+        b"\xf0\x8f\xbf\xbf",
+        // Surrogates.
+        b"\xed\xa0\x80",
+        b"\xed\xbf\xbf",
+        // Above U+10FFFF.
+        b"\xf4\x90\x80\x80",
+        b"\xf5\x80\x80\x80",
+        b"\xff",
+        // Truncated sequences.
+        b"\xc2",
+        b"\xe1\x80",
+        b"\xf1\x80\x80",
+        // Continuation bytes without a lead byte, or too many of them.
+        b"\x80",
+        b"\xbf",
+        b"\xc2\x80\x80",
+        b"\xe1\x80\x80\x80",
+        b"\xf1\x80\x80\x80\x80",
+        // Lead bytes followed by ASCII or another lead byte.
+        b"\xc2a",
+        b"\xe1\x80a",
+        b"\xf1\x80\x80a",
+        b"\xe1\xc2\x80",
+    ];
+
+    #[test]
+    fn sequences() {
+        check(b"");
+        for s in SEQUENCES {
+            check(s);
+        }
+    }
+
+    #[test]
+    fn sequences_at_every_offset() {
+        // Put each sequence at every position across the 16- and 32-byte
+        // blocks, after ASCII and after a multibyte code point, and at the
+        // very end of the input.
+        for s in SEQUENCES {
+            for prefix in [&b"a"[..], "é".as_bytes(), "€".as_bytes(), "🦀".as_bytes()] {
+                for offset in 0..70 {
+                    let mut bytes = vec![b'a'; offset];
+                    bytes.extend_from_slice(prefix);
+                    bytes.extend_from_slice(s);
+                    check(&bytes);
+                    bytes.extend_from_slice(&[b'a'; 40]);
+                    check(&bytes);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fuzz() {
+        let mut rng = StdRng::seed_from_u64(0x55_54_46_2d_38);
+        for _ in 0..10_000 {
+            // Valid text from every plane, which is then mostly corrupted.
+            let len = rng.gen_range(0..100);
+            let text: String = (0..len)
+                .map(|_| match rng.gen_range(0..4) {
+                    0 => rng.gen_range('\0'..'\u{80}'),
+                    1 => rng.gen_range('\u{80}'..'\u{800}'),
+                    2 => rng.gen_range('\u{800}'..'\u{10000}'),
+                    _ => rng.gen_range('\u{10000}'..='\u{10ffff}'),
+                })
+                .collect();
+            let mut bytes = text.into_bytes();
+            check(&bytes);
+            if bytes.is_empty() {
+                continue;
+            }
+            match rng.gen_range(0..4) {
+                0 => bytes.truncate(rng.gen_range(0..bytes.len())),
+                1 => {
+                    let i = rng.gen_range(0..bytes.len());
+                    bytes[i] = rng.gen();
+                }
+                2 => {
+                    let i = rng.gen_range(0..bytes.len());
+                    let s = SEQUENCES[rng.gen_range(0..SEQUENCES.len())];
+                    bytes.splice(i..i, s.iter().copied());
+                }
+                _ => {
+                    let i = rng.gen_range(0..bytes.len());
+                    bytes.remove(i);
+                }
+            }
+            check(&bytes);
+        }
+
+        for _ in 0..1_000 {
+            let len = rng.gen_range(0..100);
+            let bytes: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+            check(&bytes);
+        }
+    }
+}
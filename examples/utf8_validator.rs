@@ -0,0 +1,335 @@
+//! UTF-8 validation with runtime dispatch like the `hex` example, shared by
+//! the `utf8` and `kernels` examples.
+//!
+//! The AVX2 and NEON implementations use the lookup algorithm of Keiser and
+//! Lemire, "Validating UTF-8 In Less Than One Instruction Per Byte". Every
+//! invalid pair of adjacent bytes is caught by looking up the high nibble of
+//! the first byte, its low nibble and the high nibble of the second byte in
+//! three 16-entry tables with a byte shuffle (`_mm256_shuffle_epi8` or
+//! `vqtbl1q_u8`), and AND-ing the results. Each table entry is a set of error
+//! classes, so a bit only survives if all three nibbles agree on the error.
+//! The one error that needs more than two bytes, a missing or an extra third
+//! or fourth byte, is found by comparing the previous two and three bytes with
+//! the lead bytes of 3- and 4-byte sequences.
+//!
+//! SSE2 has no byte shuffle, so the SSE2 version only skips blocks of ASCII
+//! with `_mm_movemask_epi8` and validates everything else one code point at a
+//! time, like the scalar fallback.
+
+#[cfg(target_arch = "aarch64")]
+use {core_arch::arch::aarch64::*, std_detect::is_aarch64_feature_detected};
+#[cfg(target_arch = "x86")]
+use {core_arch::arch::x86::*, std_detect::is_x86_feature_detected};
+#[cfg(target_arch = "x86_64")]
+use {core_arch::arch::x86_64::*, std_detect::is_x86_feature_detected};
+
+/// Returns whether `bytes` is valid UTF-8, like `str::from_utf8(bytes).is_ok()`.
+pub(crate) fn validate_utf8(bytes: &[u8]) -> bool {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { validate_utf8_avx2(bytes) };
+        }
+        if is_x86_feature_detected!("sse2") {
+            return unsafe { validate_utf8_sse2(bytes) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if is_aarch64_feature_detected!("neon") {
+            return unsafe { validate_utf8_neon(bytes) };
+        }
+    }
+
+    validate_utf8_fallback(bytes)
+}
+
+// The error classes of a pair of adjacent bytes.
+/// A lead byte or ASCII followed by a lead byte, or a lead byte followed by
+/// ASCII.
+const TOO_SHORT: u8 = 1 << 0;
+/// ASCII followed by a continuation byte.
+const TOO_LONG: u8 = 1 << 1;
+/// `E0` followed by `80..=9F`.
+const OVERLONG_3: u8 = 1 << 2;
+/// `F4` followed by `90..=BF`, or `F5..=FF` followed by `90..=BF`.
+const TOO_LARGE: u8 = 1 << 3;
+/// `ED` followed by `A0..=BF`, which encodes `U+D800..=U+DFFF`.
+const SURROGATE: u8 = 1 << 4;
+/// `C0` or `C1` followed by a continuation byte.
+const OVERLONG_2: u8 = 1 << 5;
+/// `F5..=FF` followed by `80..=8F`. `F0` followed by `80..=8F` is an overlong
+/// 4-byte sequence, and shares the bit.
+const TOO_LARGE_1000: u8 = 1 << 6;
+const OVERLONG_4: u8 = 1 << 6;
+/// Two continuation bytes. This is only an error if the second one isn't
+/// the third or fourth byte of a sequence.
+const TWO_CONTS: u8 = 1 << 7;
+/// The classes that only depend on the high nibble of the first byte.
+const CARRY: u8 = TOO_SHORT | TOO_LONG | TWO_CONTS;
+
+/// Indexed by the high nibble of the first byte.
+const BYTE_1_HIGH: [u8; 16] = [
+    // 0_______: ASCII
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    // 10______: continuation
+    TWO_CONTS,
+    TWO_CONTS,
+    TWO_CONTS,
+    TWO_CONTS,
+    // 1100____: 2-byte lead, overlong if C0 or C1
+    TOO_SHORT | OVERLONG_2,
+    // 1101____: 2-byte lead
+    TOO_SHORT,
+    // 1110____: 3-byte lead
+    TOO_SHORT | OVERLONG_3 | SURROGATE,
+    // 1111____: 4-byte lead
+    TOO_SHORT | TOO_LARGE | TOO_LARGE_1000 | OVERLONG_4,
+];
+
+/// Indexed by the low nibble of the first byte.
+const BYTE_1_LOW: [u8; 16] = [
+    // ____0000
+    CARRY | OVERLONG_3 | OVERLONG_2 | OVERLONG_4,
+    // ____0001
+    CARRY | OVERLONG_2,
+    // ____001_
+    CARRY,
+    CARRY,
+    // ____0100
+    CARRY | TOO_LARGE,
+    // ____0101 and above
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    // ____1101
+    CARRY | TOO_LARGE | TOO_LARGE_1000 | SURROGATE,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+];
+
+/// Indexed by the high nibble of the second byte.
+const BYTE_2_HIGH: [u8; 16] = [
+    // 0_______: ASCII
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    // 1000____
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE_1000 | OVERLONG_4,
+    // 1001____
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE,
+    // 101_____
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    // 11______: lead
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+];
+
+#[target_feature(enable = "avx2")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) unsafe fn validate_utf8_avx2(bytes: &[u8]) -> bool {
+    // The shuffle looks up each 128-bit lane separately.
+    let byte_1_high =
+        _mm256_broadcastsi128_si256(_mm_loadu_si128(BYTE_1_HIGH.as_ptr() as *const _));
+    let byte_1_low = _mm256_broadcastsi128_si256(_mm_loadu_si128(BYTE_1_LOW.as_ptr() as *const _));
+    let byte_2_high =
+        _mm256_broadcastsi128_si256(_mm_loadu_si128(BYTE_2_HIGH.as_ptr() as *const _));
+    let nibble = _mm256_set1_epi8(0x0f);
+    // A sequence is cut off if the last byte is a lead byte, the second to
+    // last starts a 3- or 4-byte sequence or the third to last a 4-byte one.
+    #[rustfmt::skip]
+    let incomplete = _mm256_setr_epi8(
+        -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+        -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+        0b1110_1111_u8 as i8, 0b1101_1111_u8 as i8, 0b1011_1111_u8 as i8,
+    );
+
+    let mut error = _mm256_setzero_si256();
+    let mut prev_input = _mm256_setzero_si256();
+    let mut prev_incomplete = _mm256_setzero_si256();
+    for chunk in bytes.chunks(32) {
+        let input = if chunk.len() == 32 {
+            _mm256_loadu_si256(chunk.as_ptr() as *const _)
+        } else {
+            // Padding with ASCII catches sequences cut off by the end.
+            let mut block = [0; 32];
+            block[..chunk.len()].copy_from_slice(chunk);
+            _mm256_loadu_si256(block.as_ptr() as *const _)
+        };
+
+        if _mm256_movemask_epi8(input) == 0 {
+            // ASCII can't complete a sequence from the previous block.
+            error = _mm256_or_si256(error, prev_incomplete);
+            prev_incomplete = _mm256_setzero_si256();
+        } else {
+            // `prevN` holds the byte N positions before each byte of `input`.
+            let prev = _mm256_permute2x128_si256::<0x21>(prev_input, input);
+            let prev1 = _mm256_alignr_epi8::<15>(input, prev);
+            let prev2 = _mm256_alignr_epi8::<14>(input, prev);
+            let prev3 = _mm256_alignr_epi8::<13>(input, prev);
+
+            // There is no 8-bit shift, so the bits shifted in from the next
+            // byte are masked off.
+            let prev1_high = _mm256_and_si256(_mm256_srli_epi16::<4>(prev1), nibble);
+            let prev1_low = _mm256_and_si256(prev1, nibble);
+            let input_high = _mm256_and_si256(_mm256_srli_epi16::<4>(input), nibble);
+            let special_cases = _mm256_and_si256(
+                _mm256_and_si256(
+                    _mm256_shuffle_epi8(byte_1_high, prev1_high),
+                    _mm256_shuffle_epi8(byte_1_low, prev1_low),
+                ),
+                _mm256_shuffle_epi8(byte_2_high, input_high),
+            );
+
+            // The high bit is set where the byte must be the third or fourth
+            // byte of a sequence.
+            let third = _mm256_subs_epu8(prev2, _mm256_set1_epi8((0b1110_0000 - 0x80) as i8));
+            let fourth = _mm256_subs_epu8(prev3, _mm256_set1_epi8((0b1111_0000 - 0x80) as i8));
+            let must_be_continuation = _mm256_and_si256(
+                _mm256_or_si256(third, fourth),
+                _mm256_set1_epi8(0x80_u8 as i8),
+            );
+            error = _mm256_or_si256(error, _mm256_xor_si256(must_be_continuation, special_cases));
+            prev_incomplete = _mm256_subs_epu8(input, incomplete);
+        }
+        prev_input = input;
+    }
+    error = _mm256_or_si256(error, prev_incomplete);
+    _mm256_testz_si256(error, error) == 1
+}
+
+#[target_feature(enable = "sse2")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) unsafe fn validate_utf8_sse2(bytes: &[u8]) -> bool {
+    let mut i = 0;
+    while i < bytes.len() {
+        // `i` is always at the start of a code point, so a block of ASCII
+        // can be skipped as a whole.
+        if i + 16 <= bytes.len() {
+            let block = _mm_loadu_si128(bytes.as_ptr().add(i) as *const _);
+            if _mm_movemask_epi8(block) == 0 {
+                i += 16;
+                continue;
+            }
+        }
+        match code_point_len(&bytes[i..]) {
+            Some(len) => i += len,
+            None => return false,
+        }
+    }
+    true
+}
+
+#[target_feature(enable = "neon")]
+#[cfg(target_arch = "aarch64")]
+pub(crate) unsafe fn validate_utf8_neon(bytes: &[u8]) -> bool {
+    let byte_1_high = vld1q_u8(BYTE_1_HIGH.as_ptr());
+    let byte_1_low = vld1q_u8(BYTE_1_LOW.as_ptr());
+    let byte_2_high = vld1q_u8(BYTE_2_HIGH.as_ptr());
+    let nibble = vdupq_n_u8(0x0f);
+    // A sequence is cut off if the last byte is a lead byte, the second to
+    // last starts a 3- or 4-byte sequence or the third to last a 4-byte one.
+    #[rustfmt::skip]
+    let incomplete: [u8; 16] = [
+        255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+        0b1110_1111, 0b1101_1111, 0b1011_1111,
+    ];
+    let incomplete = vld1q_u8(incomplete.as_ptr());
+
+    let mut error = vdupq_n_u8(0);
+    let mut prev_input = vdupq_n_u8(0);
+    let mut prev_incomplete = vdupq_n_u8(0);
+    for chunk in bytes.chunks(16) {
+        let input = if chunk.len() == 16 {
+            vld1q_u8(chunk.as_ptr())
+        } else {
+            // Padding with ASCII catches sequences cut off by the end.
+            let mut block = [0; 16];
+            block[..chunk.len()].copy_from_slice(chunk);
+            vld1q_u8(block.as_ptr())
+        };
+
+        if vmaxvq_u8(input) < 0x80 {
+            // ASCII can't complete a sequence from the previous block.
+            error = vorrq_u8(error, prev_incomplete);
+            prev_incomplete = vdupq_n_u8(0);
+        } else {
+            // `prevN` holds the byte N positions before each byte of `input`.
+            let prev1 = vextq_u8::<15>(prev_input, input);
+            let prev2 = vextq_u8::<14>(prev_input, input);
+            let prev3 = vextq_u8::<13>(prev_input, input);
+
+            let special_cases = vandq_u8(
+                vandq_u8(
+                    vqtbl1q_u8(byte_1_high, vshrq_n_u8::<4>(prev1)),
+                    vqtbl1q_u8(byte_1_low, vandq_u8(prev1, nibble)),
+                ),
+                vqtbl1q_u8(byte_2_high, vshrq_n_u8::<4>(input)),
+            );
+
+            // The high bit is set where the byte must be the third or fourth
+            // byte of a sequence.
+            let third = vqsubq_u8(prev2, vdupq_n_u8(0b1110_0000 - 0x80));
+            let fourth = vqsubq_u8(prev3, vdupq_n_u8(0b1111_0000 - 0x80));
+            let must_be_continuation = vandq_u8(vorrq_u8(third, fourth), vdupq_n_u8(0x80));
+            error = vorrq_u8(error, veorq_u8(must_be_continuation, special_cases));
+            prev_incomplete = vqsubq_u8(input, incomplete);
+        }
+        prev_input = input;
+    }
+    error = vorrq_u8(error, prev_incomplete);
+    vmaxvq_u8(error) == 0
+}
+
+pub(crate) fn validate_utf8_fallback(mut bytes: &[u8]) -> bool {
+    while !bytes.is_empty() {
+        match code_point_len(bytes) {
+            Some(len) => bytes = &bytes[len..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Returns the length of the code point at the start of `bytes`, or `None` if
+/// it isn't valid UTF-8.
+///
+/// This follows table 3-7 of the Unicode standard, which lists the
+/// well-formed byte sequences.
+fn code_point_len(bytes: &[u8]) -> Option<usize> {
+    let (len, second) = match bytes[0] {
+        0x00..=0x7f => return Some(1),
+        0xc2..=0xdf => (2, 0x80..=0xbf),
+        0xe0 => (3, 0xa0..=0xbf),
+        0xe1..=0xec | 0xee..=0xef => (3, 0x80..=0xbf),
+        0xed => (3, 0x80..=0x9f),
+        0xf0 => (4, 0x90..=0xbf),
+        0xf1..=0xf3 => (4, 0x80..=0xbf),
+        0xf4 => (4, 0x80..=0x8f),
+        _ => return None,
+    };
+    let sequence = bytes.get(..len)?;
+    let valid =
+        second.contains(&sequence[1]) && sequence[2..].iter().all(|b| (0x80..=0xbf).contains(b));
+    valid.then_some(len)
+}